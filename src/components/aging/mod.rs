@@ -0,0 +1,126 @@
+// =============================================================================
+//! - Component for the aging selector for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::AGING_TICKS_INIT;
+use crate::engine::traits::Component;
+use crate::messages::inputs::Inputs;
+use crate::models::fauna::Aging;
+use com_croftsoft_lib_animation::web_sys::{
+  add_change_handler_by_id, get_window,
+};
+use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
+use core::cell::RefCell;
+use futures::channel::mpsc::{TryRecvError, UnboundedReceiver};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Document, Event, HtmlInputElement, HtmlSelectElement};
+
+pub struct AgingComponent {
+  id: String,
+  inputs: Rc<RefCell<Inputs>>,
+  unbounded_receivers: Vec<UnboundedReceiver<Event>>,
+}
+
+impl AgingComponent {
+  fn changed(&mut self) -> bool {
+    let mut changed = false;
+    for unbounded_receiver in self.unbounded_receivers.iter_mut() {
+      let result: Result<Option<Event>, TryRecvError> =
+        unbounded_receiver.try_next();
+      if let Ok(Some(_event)) = result {
+        changed = true;
+      }
+    }
+    changed
+  }
+
+  fn get_child_ids(&self) -> [String; 2] {
+    [
+      format!("{}-mode", self.id),
+      format!("{}-ticks", self.id),
+    ]
+  }
+
+  fn make_aging(&self) -> Aging {
+    let document: Document = get_window().unwrap().document().unwrap();
+    let [mode_id, ticks_id] = self.get_child_ids();
+    let html_select_element: HtmlSelectElement = document
+      .get_element_by_id(&mode_id)
+      .unwrap()
+      .dyn_into()
+      .unwrap();
+    let html_input_element: HtmlInputElement = document
+      .get_element_by_id(&ticks_id)
+      .unwrap()
+      .dyn_into()
+      .unwrap();
+    let ticks: f64 = html_input_element.value_as_number();
+    // A blank or out-of-range entry falls back to the default
+    let ticks: usize = if ticks >= 1. {
+      ticks as usize
+    } else {
+      AGING_TICKS_INIT
+    };
+    match html_select_element.value().as_str() {
+      "cost" => Aging::Cost(ticks),
+      "lifespan" => Aging::Lifespan(ticks),
+      "mortality" => Aging::Mortality(ticks),
+      _ => Aging::Off,
+    }
+  }
+
+  pub fn new(
+    id: &str,
+    inputs: Rc<RefCell<Inputs>>,
+  ) -> Self {
+    Self {
+      id: String::from(id),
+      inputs,
+      unbounded_receivers: Vec::new(),
+    }
+  }
+}
+
+impl Component for AgingComponent {
+  fn make_html(&self) -> String {
+    let [mode_id, ticks_id] = self.get_child_ids();
+    format!(
+      "Aging <select id=\"{}\">\
+      <option value=\"off\">Off</option>\
+      <option value=\"lifespan\">Maximum lifespan</option>\
+      <option value=\"mortality\">Rising death chance</option>\
+      <option value=\"cost\">Rising energy cost</option>\
+      </select>\n\
+      over <input id=\"{}\" min=\"1\" type=\"number\" value=\"{}\"> updates",
+      mode_id, ticks_id, AGING_TICKS_INIT,
+    )
+  }
+}
+
+impl InitializerMut for AgingComponent {
+  fn initialize(&mut self) {
+    self.unbounded_receivers = self
+      .get_child_ids()
+      .iter()
+      .filter_map(|id| add_change_handler_by_id(id))
+      .collect();
+  }
+}
+
+impl UpdaterMut for AgingComponent {
+  fn update(&mut self) {
+    if self.changed() {
+      self.inputs.borrow_mut().aging_change_requested = Some(self.make_aging());
+    }
+  }
+}
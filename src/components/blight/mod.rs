@@ -1,23 +1,29 @@
 // =============================================================================
-//! - Component for the blight button
+//! - Component for the blight controls
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-12-14
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+use crate::constants::BLIGHT_FRACTION_INIT;
 use crate::engine::traits::Component;
 use crate::messages::inputs::Inputs;
-use com_croftsoft_lib_animation::web_sys::add_click_handler_by_id;
+use crate::models::blight::Blight;
+use com_croftsoft_lib_animation::web_sys::{
+  add_click_handler_by_id, get_window,
+};
 use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
 use core::cell::RefCell;
 use futures::channel::mpsc::UnboundedReceiver;
 use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Document, HtmlInputElement, HtmlSelectElement};
 
 pub struct BlightComponent {
   id: String,
@@ -36,6 +42,41 @@ impl BlightComponent {
     )
   }
 
+  fn get_child_ids(&self) -> [String; 2] {
+    [
+      format!("{}-mode", self.id),
+      format!("{}-fraction", self.id),
+    ]
+  }
+
+  fn make_blight(&self) -> Blight {
+    let document: Document = get_window().unwrap().document().unwrap();
+    let [mode_id, fraction_id] = self.get_child_ids();
+    let html_select_element: HtmlSelectElement = document
+      .get_element_by_id(&mode_id)
+      .unwrap()
+      .dyn_into()
+      .unwrap();
+    match html_select_element.value().as_str() {
+      "contagion" => Blight::Contagion,
+      "fraction" => {
+        let html_input_element: HtmlInputElement = document
+          .get_element_by_id(&fraction_id)
+          .unwrap()
+          .dyn_into()
+          .unwrap();
+        // A blank or out-of-range entry falls back to the default
+        let value: f64 = html_input_element.value_as_number();
+        if (0. ..=100.).contains(&value) {
+          Blight::Fraction(value as usize)
+        } else {
+          Blight::Fraction(BLIGHT_FRACTION_INIT)
+        }
+      },
+      _ => Blight::All,
+    }
+  }
+
   pub fn new(
     id: &str,
     inputs: Rc<RefCell<Inputs>>,
@@ -50,7 +91,18 @@ impl BlightComponent {
 
 impl Component for BlightComponent {
   fn make_html(&self) -> String {
-    format!("<button id=\"{}\">Blight</button>", self.id)
+    let [mode_id, fraction_id] = self.get_child_ids();
+    format!(
+      "<button id=\"{}\">Blight</button>\n\
+      <select id=\"{}\">\
+      <option value=\"all\">all of the food</option>\
+      <option value=\"fraction\">a fraction of the food</option>\
+      <option value=\"contagion\">contagiously</option>\
+      </select>\n\
+      Fraction <input id=\"{}\" max=\"100\" min=\"0\" type=\"number\" \
+      value=\"{}\">%",
+      self.id, mode_id, fraction_id, BLIGHT_FRACTION_INIT,
+    )
   }
 }
 
@@ -63,7 +115,7 @@ impl InitializerMut for BlightComponent {
 impl UpdaterMut for BlightComponent {
   fn update(&mut self) {
     if self.clicked() {
-      self.inputs.borrow_mut().blight_requested = true;
+      self.inputs.borrow_mut().blight_requested = Some(self.make_blight());
     }
   }
 }
@@ -2,102 +2,343 @@
 //! - Component for the HTML Canvas
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-12-18
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
-use crate::constants::{SPACE_HEIGHT, SPACE_WIDTH};
+use crate::constants::{
+  CANVAS_DRAG_DISTANCE_MIN, SPACE_HEIGHT, SPACE_WIDTH, VIEWPORT_ZOOM_RATE,
+};
 use crate::engine::functions::location::to_index_from_xy;
+use crate::engine::functions::web_sys::add_event_listener;
 use crate::engine::traits::Component;
+use crate::engine::viewport::ViewTransform;
+use crate::messages::events::Events;
 use crate::messages::inputs::Inputs;
-use crate::models::options::Options;
+use crate::models::blight::Blight;
+use crate::models::garden::GardenDrag;
+use crate::models::options::{DragMode, Options};
+use crate::models::region::Region;
 use crate::models::root::Root;
 use crate::painters::root::RootPainter;
 use com_croftsoft_lib_animation::web_sys::{
-  add_mouse_down_handler_by_id, get_canvas_xy, get_html_canvas_element_by_id,
+  add_mouse_down_handler_by_id, get_html_canvas_element_by_id, get_window,
 };
 use com_croftsoft_lib_role::{InitializerMut, Painter, UpdaterMut};
 use core::cell::RefCell;
-use futures::channel::mpsc::{TryRecvError, UnboundedReceiver};
+use futures::channel::mpsc::UnboundedReceiver;
 use std::rc::Rc;
-use web_sys::{HtmlCanvasElement, MouseEvent};
+use web_sys::{
+  DomRect, EventTarget, HtmlCanvasElement, MouseEvent, WheelEvent, Window,
+};
+
+// A press on the canvas that becomes a drag once the mouse moves far enough
+struct CanvasDrag {
+  client_x: f64,
+  client_y: f64,
+  client_x_start: f64,
+  client_y_start: f64,
+  // Whether the drag pans the view or draws a region
+  drag_mode: DragMode,
+  dragging: bool,
+  // A shift click selects instead of creating a bug
+  shift_key: bool,
+}
 
 pub struct CanvasComponent {
+  drag_option: Option<CanvasDrag>,
+  events: Rc<RefCell<Events>>,
   id: String,
   inputs: Rc<RefCell<Inputs>>,
+  mouse_down_receiver_option: Option<UnboundedReceiver<MouseEvent>>,
+  mouse_move_receiver_option: Option<UnboundedReceiver<MouseEvent>>,
+  mouse_up_receiver_option: Option<UnboundedReceiver<MouseEvent>>,
   options: Rc<RefCell<Options>>,
   root_model: Rc<RefCell<Root>>,
   root_painter_option: Option<RootPainter>,
-  unbounded_receiver_option: Option<UnboundedReceiver<MouseEvent>>,
+  wheel_receiver_option: Option<UnboundedReceiver<WheelEvent>>,
 }
 
 impl CanvasComponent {
-  fn get_scale_xy(canvas_element_id: &str) -> (f64, f64) {
-    let html_canvas_element: HtmlCanvasElement =
-      get_html_canvas_element_by_id(canvas_element_id);
-    let canvas_height = html_canvas_element.height();
-    let canvas_width = html_canvas_element.width();
-    let scale_x = canvas_width as f64 / SPACE_WIDTH as f64;
-    let scale_y = canvas_height as f64 / SPACE_HEIGHT as f64;
-    (scale_x, scale_y)
+  fn get_dom_rect(&self) -> DomRect {
+    get_html_canvas_element_by_id(&self.id).get_bounding_client_rect()
   }
 
   pub fn new(
+    events: Rc<RefCell<Events>>,
     id: &str,
     inputs: Rc<RefCell<Inputs>>,
     options: Rc<RefCell<Options>>,
     root_model: Rc<RefCell<Root>>,
   ) -> Self {
     Self {
+      drag_option: None,
+      events,
       id: String::from(id),
       inputs,
+      mouse_down_receiver_option: None,
+      mouse_move_receiver_option: None,
+      mouse_up_receiver_option: None,
       options,
-      unbounded_receiver_option: None,
-      root_painter_option: None,
       root_model,
+      root_painter_option: None,
+      wheel_receiver_option: None,
     }
   }
 
-  fn poll_mouse_event(&mut self) -> Option<MouseEvent> {
-    let unbounded_receiver: &mut UnboundedReceiver<MouseEvent> =
-      self.unbounded_receiver_option.as_mut()?;
-    let result: Result<Option<MouseEvent>, TryRecvError> =
-      unbounded_receiver.try_next();
-    if let Ok(mouse_event_option) = result {
-      return mouse_event_option;
+  fn poll<E>(receiver_option: &mut Option<UnboundedReceiver<E>>) -> Option<E> {
+    receiver_option.as_mut()?.try_next().ok()?
+  }
+
+  // Matches the drawing buffer to the displayed size in device pixels.
+  // Polled because a devicePixelRatio change does not always fire a resize.
+  fn resize(&mut self) {
+    let device_pixel_ratio: f64 = get_window().unwrap().device_pixel_ratio();
+    let html_canvas_element: HtmlCanvasElement =
+      get_html_canvas_element_by_id(&self.id);
+    let height: u32 = (html_canvas_element.client_height() as f64
+      * device_pixel_ratio)
+      .round() as u32;
+    let width: u32 = (html_canvas_element.client_width() as f64
+      * device_pixel_ratio)
+      .round() as u32;
+    if height == 0 || width == 0 {
+      return;
     }
-    None
+    if height == html_canvas_element.height()
+      && width == html_canvas_element.width()
+      && self.root_painter_option.is_some()
+    {
+      return;
+    }
+    html_canvas_element.set_height(height);
+    html_canvas_element.set_width(width);
+    self.root_painter_option = Some(RootPainter::new(
+      &self.id,
+      self.options.clone(),
+      &self.root_model.borrow(),
+    ));
+    // Resizing clears the canvas so it is repainted even when paused
+    self.events.borrow_mut().updated = true;
   }
 
-  fn to_world_index_from_canvas_xy(
+  // The drag in whole locations, which can cross the edges of the space
+  fn to_world_delta_from_client_xy(
     &self,
-    canvas_x: usize,
-    canvas_y: usize,
+    client_x_a: f64,
+    client_y_a: f64,
+    client_x_b: f64,
+    client_y_b: f64,
+  ) -> (isize, isize) {
+    let (world_x_a, world_y_a) =
+      self.to_world_xy_from_client_xy(client_x_a, client_y_a);
+    let dom_rect: DomRect = self.get_dom_rect();
+    let zoom: f64 = self.root_model.borrow().viewport.borrow().zoom;
+    let world_x_b: f64 = world_x_a
+      + (client_x_b - client_x_a) * SPACE_WIDTH as f64
+        / (dom_rect.width() * zoom);
+    let world_y_b: f64 = world_y_a
+      + (client_y_b - client_y_a) * SPACE_HEIGHT as f64
+        / (dom_rect.height() * zoom);
+    (
+      world_x_b.floor() as isize - world_x_a.floor() as isize,
+      world_y_b.floor() as isize - world_y_a.floor() as isize,
+    )
+  }
+
+  fn to_world_index_from_client_xy(
+    &self,
+    client_x: f64,
+    client_y: f64,
   ) -> usize {
-    let (scale_x, scale_y) = CanvasComponent::get_scale_xy(&self.id);
-    let scaled_canvas_x: f64 = canvas_x as f64 / scale_x;
-    let scaled_canvas_y: f64 = canvas_y as f64 / scale_y;
-    let mut world_x = scaled_canvas_x as usize;
-    let mut world_y = scaled_canvas_y as usize;
-    if world_x >= SPACE_WIDTH {
-      world_x = SPACE_WIDTH - 1;
+    let (world_x, world_y) =
+      self.to_world_xy_from_client_xy(client_x, client_y);
+    let world_x: usize = (world_x as usize).min(SPACE_WIDTH - 1);
+    let world_y: usize = (world_y as usize).min(SPACE_HEIGHT - 1);
+    to_index_from_xy(world_x, world_y)
+  }
+
+  fn to_world_xy_from_client_xy(
+    &self,
+    client_x: f64,
+    client_y: f64,
+  ) -> (f64, f64) {
+    let html_canvas_element: HtmlCanvasElement =
+      get_html_canvas_element_by_id(&self.id);
+    let dom_rect: DomRect = html_canvas_element.get_bounding_client_rect();
+    let canvas_height: f64 = html_canvas_element.height() as f64;
+    let canvas_width: f64 = html_canvas_element.width() as f64;
+    // Canvas coordinates are in device pixels to match the drawing buffer
+    let canvas_x: f64 =
+      (client_x - dom_rect.left()) * canvas_width / dom_rect.width();
+    let canvas_y: f64 =
+      (client_y - dom_rect.top()) * canvas_height / dom_rect.height();
+    let view_transform = ViewTransform::new(
+      &self.root_model.borrow().viewport.borrow(),
+      canvas_width,
+      canvas_height,
+    );
+    view_transform.to_world_xy(canvas_x, canvas_y)
+  }
+
+  fn update_mouse(&mut self) {
+    while let Some(mouse_event) =
+      Self::poll(&mut self.mouse_down_receiver_option)
+    {
+      if mouse_event.button() == 0 {
+        let client_x: f64 = mouse_event.client_x() as f64;
+        let client_y: f64 = mouse_event.client_y() as f64;
+        self.drag_option = Some(CanvasDrag {
+          client_x,
+          client_y,
+          client_x_start: client_x,
+          client_y_start: client_y,
+          drag_mode: self.options.borrow().drag_mode,
+          dragging: false,
+          shift_key: mouse_event.shift_key(),
+        });
+      }
     }
-    if world_y >= SPACE_HEIGHT {
-      world_y = SPACE_HEIGHT - 1;
+    let mut pan_x: f64 = 0.;
+    let mut pan_y: f64 = 0.;
+    // The start and current client coordinates of a region being drawn
+    let mut region_corners_option: Option<(f64, f64, f64, f64)> = None;
+    // Whether the drag began since the last update
+    let mut drag_started: bool = false;
+    let mut drag_mode: DragMode = DragMode::Pan;
+    let mut drag_shift_key: bool = false;
+    while let Some(mouse_event) =
+      Self::poll(&mut self.mouse_move_receiver_option)
+    {
+      if let Some(drag) = self.drag_option.as_mut() {
+        let client_x: f64 = mouse_event.client_x() as f64;
+        let client_y: f64 = mouse_event.client_y() as f64;
+        if !drag.dragging {
+          drag.dragging = (client_x - drag.client_x_start)
+            .hypot(client_y - drag.client_y_start)
+            >= CANVAS_DRAG_DISTANCE_MIN;
+          drag_started |= drag.dragging;
+        }
+        if drag.dragging {
+          drag_mode = drag.drag_mode;
+          drag_shift_key = drag.shift_key;
+          match drag.drag_mode {
+            DragMode::Blight => {},
+            DragMode::Pan => {
+              pan_x += client_x - drag.client_x;
+              pan_y += client_y - drag.client_y;
+            },
+            DragMode::Garden | DragMode::StartRegion => {
+              region_corners_option = Some((
+                drag.client_x_start,
+                drag.client_y_start,
+                client_x,
+                client_y,
+              ));
+            },
+          }
+          drag.client_x = client_x;
+          drag.client_y = client_y;
+        }
+      }
+    }
+    if let Some((client_x_a, client_y_a, client_x_b, client_y_b)) =
+      region_corners_option
+    {
+      let location_from: usize =
+        self.to_world_index_from_client_xy(client_x_a, client_y_a);
+      let (delta_x, delta_y) = self.to_world_delta_from_client_xy(
+        client_x_a, client_y_a, client_x_b, client_y_b,
+      );
+      if drag_mode == DragMode::Garden {
+        self.inputs.borrow_mut().garden_drag_requested = Some(GardenDrag {
+          delta_x,
+          delta_y,
+          location_from,
+          resize: drag_shift_key,
+          started: drag_started,
+        });
+      } else {
+        self.inputs.borrow_mut().start_region_change_requested =
+          Some(Region::from_drag(location_from, delta_x, delta_y));
+      }
+    }
+    if pan_x != 0. || pan_y != 0. {
+      let dom_rect: DomRect = self.get_dom_rect();
+      self.inputs.borrow_mut().viewport_pan_requested =
+        Some((pan_x / dom_rect.width(), pan_y / dom_rect.height()));
+    }
+    while Self::poll(&mut self.mouse_up_receiver_option).is_some() {
+      if let Some(drag) = self.drag_option.take() {
+        if drag.dragging {
+          if drag.drag_mode == DragMode::Blight {
+            let (delta_x, delta_y) = self.to_world_delta_from_client_xy(
+              drag.client_x_start,
+              drag.client_y_start,
+              drag.client_x,
+              drag.client_y,
+            );
+            let region = Region::from_drag(
+              self.to_world_index_from_client_xy(
+                drag.client_x_start,
+                drag.client_y_start,
+              ),
+              delta_x,
+              delta_y,
+            );
+            self.inputs.borrow_mut().blight_requested =
+              Some(Blight::Region(region));
+          }
+        } else {
+          let index: usize = self.to_world_index_from_client_xy(
+            drag.client_x_start,
+            drag.client_y_start,
+          );
+          if drag.shift_key {
+            self.inputs.borrow_mut().selection_requested = Some(index);
+          } else {
+            self.inputs.borrow_mut().bug_requested = Some(index);
+          }
+        }
+      }
+    }
+  }
+
+  fn update_wheel(&mut self) {
+    let mut factor: f64 = 1.;
+    let mut client_xy_option: Option<(f64, f64)> = None;
+    while let Some(wheel_event) = Self::poll(&mut self.wheel_receiver_option) {
+      // Line and page delta modes are rare so they are treated as 16 pixels
+      let delta_pixels: f64 = if wheel_event.delta_mode() == 0 {
+        wheel_event.delta_y()
+      } else {
+        16. * wheel_event.delta_y()
+      };
+      factor *= VIEWPORT_ZOOM_RATE.powf(-delta_pixels / 100.);
+      client_xy_option =
+        Some((wheel_event.client_x() as f64, wheel_event.client_y() as f64));
+    }
+    if let Some((client_x, client_y)) = client_xy_option {
+      let dom_rect: DomRect = self.get_dom_rect();
+      self.inputs.borrow_mut().viewport_zoom_requested = Some((
+        factor,
+        (client_x - dom_rect.left()) / dom_rect.width(),
+        (client_y - dom_rect.top()) / dom_rect.height(),
+      ));
     }
-    to_index_from_xy(world_x, world_y)
   }
 }
 
 impl Component for CanvasComponent {
   fn make_html(&self) -> String {
     format!(
-      "<canvas id=\"{}\" height=\"600\" style=\"cursor: pointer\" width=\"600\"></canvas>",
+      "<canvas id=\"{}\" style=\"aspect-ratio: 1; cursor: pointer; \
+      display: block; max-width: 100vh; width: 100%\"></canvas>",
       self.id
     )
   }
@@ -105,12 +346,20 @@ impl Component for CanvasComponent {
 
 impl InitializerMut for CanvasComponent {
   fn initialize(&mut self) {
-    self.unbounded_receiver_option = add_mouse_down_handler_by_id(&self.id);
-    self.root_painter_option = Some(RootPainter::new(
-      "canvas",
-      self.options.clone(),
-      &self.root_model.borrow(),
-    ));
+    let html_canvas_element: HtmlCanvasElement =
+      get_html_canvas_element_by_id(&self.id);
+    let window: Window = get_window().unwrap();
+    let window_event_target: &EventTarget = window.as_ref();
+    self.mouse_down_receiver_option = add_mouse_down_handler_by_id(&self.id);
+    // Listening on the window keeps a drag going outside of the canvas
+    self.mouse_move_receiver_option =
+      Some(add_event_listener(window_event_target, "mousemove", false));
+    self.mouse_up_receiver_option =
+      Some(add_event_listener(window_event_target, "mouseup", false));
+    // Keeps the mouse wheel from also scrolling the page
+    self.wheel_receiver_option =
+      Some(add_event_listener(&html_canvas_element, "wheel", true));
+    self.resize();
   }
 }
 
@@ -124,11 +373,8 @@ impl Painter for CanvasComponent {
 
 impl UpdaterMut for CanvasComponent {
   fn update(&mut self) {
-    let mouse_event_option = self.poll_mouse_event();
-    if let Some(mouse_event) = mouse_event_option {
-      let (canvas_x, canvas_y) = get_canvas_xy(&mouse_event);
-      let index = self.to_world_index_from_canvas_xy(canvas_x, canvas_y);
-      self.inputs.borrow_mut().bug_requested = Some(index);
-    }
+    self.resize();
+    self.update_mouse();
+    self.update_wheel();
   }
 }
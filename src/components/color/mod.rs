@@ -0,0 +1,122 @@
+// =============================================================================
+//! - Component for the color mode and palette selectors for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::engine::traits::Component;
+use crate::messages::inputs::Inputs;
+use crate::models::options::{ColorMode, Palette};
+use com_croftsoft_lib_animation::web_sys::add_change_handler_by_id;
+use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
+use core::cell::RefCell;
+use futures::channel::mpsc::{TryRecvError, UnboundedReceiver};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, EventTarget, HtmlSelectElement};
+
+pub struct ColorComponent {
+  id: String,
+  inputs: Rc<RefCell<Inputs>>,
+  mode_unbounded_receiver_option: Option<UnboundedReceiver<Event>>,
+  palette_unbounded_receiver_option: Option<UnboundedReceiver<Event>>,
+}
+
+impl ColorComponent {
+  fn changed(
+    unbounded_receiver_option: &mut Option<UnboundedReceiver<Event>>
+  ) -> Option<String> {
+    let unbounded_receiver: &mut UnboundedReceiver<Event> =
+      unbounded_receiver_option.as_mut()?;
+    let result: Result<Option<Event>, TryRecvError> =
+      unbounded_receiver.try_next();
+    let event: Event = result.ok()??;
+    let event_target: EventTarget = event.target()?;
+    let html_select_element: HtmlSelectElement =
+      event_target.dyn_into().ok()?;
+    Some(html_select_element.value())
+  }
+
+  fn get_mode_id(&self) -> String {
+    format!("{}-mode", self.id)
+  }
+
+  fn get_palette_id(&self) -> String {
+    format!("{}-palette", self.id)
+  }
+
+  pub fn new(
+    id: &str,
+    inputs: Rc<RefCell<Inputs>>,
+  ) -> Self {
+    Self {
+      id: String::from(id),
+      inputs,
+      mode_unbounded_receiver_option: None,
+      palette_unbounded_receiver_option: None,
+    }
+  }
+}
+
+impl Component for ColorComponent {
+  fn make_html(&self) -> String {
+    format!(
+      "Color by <select id=\"{}\">\
+      <option value=\"species\">Species</option>\
+      <option value=\"energy\">Energy</option>\
+      <option value=\"genotype\">Genotype</option>\
+      <option value=\"generation\">Generation</option>\
+      <option value=\"age\">Age</option>\
+      <option value=\"lineage\">Lineage</option>\
+      </select>\n\
+      Palette <select id=\"{}\">\
+      <option value=\"standard\">Standard</option>\
+      <option value=\"color-blind\">Color-blind safe</option>\
+      </select>",
+      self.get_mode_id(),
+      self.get_palette_id(),
+    )
+  }
+}
+
+impl InitializerMut for ColorComponent {
+  fn initialize(&mut self) {
+    self.mode_unbounded_receiver_option =
+      add_change_handler_by_id(&self.get_mode_id());
+    self.palette_unbounded_receiver_option =
+      add_change_handler_by_id(&self.get_palette_id());
+  }
+}
+
+impl UpdaterMut for ColorComponent {
+  fn update(&mut self) {
+    if let Some(value) = Self::changed(&mut self.mode_unbounded_receiver_option)
+    {
+      self.inputs.borrow_mut().color_mode_change_requested =
+        Some(match value.as_str() {
+          "age" => ColorMode::Age,
+          "energy" => ColorMode::Energy,
+          "generation" => ColorMode::Generation,
+          "genotype" => ColorMode::Genotype,
+          "lineage" => ColorMode::Lineage,
+          _ => ColorMode::Species,
+        });
+    }
+    if let Some(value) =
+      Self::changed(&mut self.palette_unbounded_receiver_option)
+    {
+      self.inputs.borrow_mut().palette_change_requested =
+        Some(match value.as_str() {
+          "color-blind" => Palette::ColorBlind,
+          _ => Palette::Standard,
+        });
+    }
+  }
+}
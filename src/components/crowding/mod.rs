@@ -0,0 +1,145 @@
+// =============================================================================
+//! - Component for the crowding selector for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{
+  BUGS_MAX, CROWDING_CAPACITY_INIT, CROWDING_COST_INIT, MAX_ENERGY,
+};
+use crate::engine::traits::Component;
+use crate::messages::inputs::Inputs;
+use crate::models::fauna::Crowding;
+use com_croftsoft_lib_animation::web_sys::{
+  add_change_handler_by_id, get_window,
+};
+use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
+use core::cell::RefCell;
+use futures::channel::mpsc::{TryRecvError, UnboundedReceiver};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Document, Event, HtmlInputElement, HtmlSelectElement};
+
+pub struct CrowdingComponent {
+  id: String,
+  inputs: Rc<RefCell<Inputs>>,
+  unbounded_receivers: Vec<UnboundedReceiver<Event>>,
+}
+
+impl CrowdingComponent {
+  fn changed(&mut self) -> bool {
+    let mut changed = false;
+    for unbounded_receiver in self.unbounded_receivers.iter_mut() {
+      let result: Result<Option<Event>, TryRecvError> =
+        unbounded_receiver.try_next();
+      if let Ok(Some(_event)) = result {
+        changed = true;
+      }
+    }
+    changed
+  }
+
+  fn get_child_ids(&self) -> [String; 3] {
+    [
+      format!("{}-mode", self.id),
+      format!("{}-capacity", self.id),
+      format!("{}-cost", self.id),
+    ]
+  }
+
+  fn make_crowding(&self) -> Crowding {
+    let document: Document = get_window().unwrap().document().unwrap();
+    let [mode_id, capacity_id, cost_id] = self.get_child_ids();
+    let html_select_element: HtmlSelectElement = document
+      .get_element_by_id(&mode_id)
+      .unwrap()
+      .dyn_into()
+      .unwrap();
+    let get_value = |id: &str| -> f64 {
+      let html_input_element: HtmlInputElement =
+        document.get_element_by_id(id).unwrap().dyn_into().unwrap();
+      html_input_element.value_as_number()
+    };
+    // Blank or out-of-range entries fall back to the defaults
+    match html_select_element.value().as_str() {
+      "capacity" => {
+        let capacity: f64 = get_value(&capacity_id);
+        Crowding::Capacity(if (1. ..=BUGS_MAX as f64).contains(&capacity) {
+          capacity as usize
+        } else {
+          CROWDING_CAPACITY_INIT
+        })
+      },
+      "cost" => {
+        let cost: f64 = get_value(&cost_id);
+        Crowding::Cost(if (0. ..=MAX_ENERGY as f64).contains(&cost) {
+          cost as usize
+        } else {
+          CROWDING_COST_INIT
+        })
+      },
+      _ => Crowding::Off,
+    }
+  }
+
+  pub fn new(
+    id: &str,
+    inputs: Rc<RefCell<Inputs>>,
+  ) -> Self {
+    Self {
+      id: String::from(id),
+      inputs,
+      unbounded_receivers: Vec::new(),
+    }
+  }
+}
+
+impl Component for CrowdingComponent {
+  fn make_html(&self) -> String {
+    let [mode_id, capacity_id, cost_id] = self.get_child_ids();
+    format!(
+      "Crowding <select id=\"{}\">\
+      <option value=\"off\">Off</option>\
+      <option value=\"capacity\">Capacity</option>\
+      <option value=\"cost\">Cost</option>\
+      </select>\n\
+      Capacity <input id=\"{}\" max=\"{}\" min=\"1\" type=\"number\" \
+      value=\"{}\">\n\
+      Cost per neighbor <input id=\"{}\" max=\"{}\" min=\"0\" \
+      type=\"number\" value=\"{}\">",
+      mode_id,
+      capacity_id,
+      BUGS_MAX,
+      CROWDING_CAPACITY_INIT,
+      cost_id,
+      MAX_ENERGY,
+      CROWDING_COST_INIT,
+    )
+  }
+}
+
+impl InitializerMut for CrowdingComponent {
+  fn initialize(&mut self) {
+    self.unbounded_receivers = self
+      .get_child_ids()
+      .iter()
+      .filter_map(|id| add_change_handler_by_id(id))
+      .collect();
+  }
+}
+
+impl UpdaterMut for CrowdingComponent {
+  fn update(&mut self) {
+    if self.changed() {
+      self.inputs.borrow_mut().crowding_change_requested =
+        Some(self.make_crowding());
+    }
+  }
+}
@@ -0,0 +1,117 @@
+// =============================================================================
+//! - Component for the decomposition selector for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::engine::traits::Component;
+use crate::messages::inputs::Inputs;
+use crate::models::flora::Decomposition;
+use com_croftsoft_lib_animation::web_sys::{
+  add_change_handler_by_id, get_window,
+};
+use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
+use core::cell::RefCell;
+use futures::channel::mpsc::{TryRecvError, UnboundedReceiver};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Document, Event, HtmlInputElement, HtmlSelectElement};
+
+pub struct DecompositionComponent {
+  id: String,
+  inputs: Rc<RefCell<Inputs>>,
+  unbounded_receivers: Vec<UnboundedReceiver<Event>>,
+}
+
+impl DecompositionComponent {
+  fn changed(&mut self) -> bool {
+    let mut changed = false;
+    for unbounded_receiver in self.unbounded_receivers.iter_mut() {
+      let result: Result<Option<Event>, TryRecvError> =
+        unbounded_receiver.try_next();
+      if let Ok(Some(_event)) = result {
+        changed = true;
+      }
+    }
+    changed
+  }
+
+  fn get_child_ids(&self) -> [String; 2] {
+    [
+      format!("{}-mode", self.id),
+      format!("{}-scavenging", self.id),
+    ]
+  }
+
+  pub fn new(
+    id: &str,
+    inputs: Rc<RefCell<Inputs>>,
+  ) -> Self {
+    Self {
+      id: String::from(id),
+      inputs,
+      unbounded_receivers: Vec::new(),
+    }
+  }
+}
+
+impl Component for DecompositionComponent {
+  fn make_html(&self) -> String {
+    let [mode_id, scavenging_id] = self.get_child_ids();
+    format!(
+      "Remains <select id=\"{}\">\
+      <option value=\"off\">Vanish</option>\
+      <option value=\"corpse\">Leave a corpse</option>\
+      <option value=\"fertilize\">Fertilize</option>\
+      </select>\n\
+      Scavenging <input id=\"{}\" type=\"checkbox\">",
+      mode_id, scavenging_id,
+    )
+  }
+}
+
+impl InitializerMut for DecompositionComponent {
+  fn initialize(&mut self) {
+    self.unbounded_receivers = self
+      .get_child_ids()
+      .iter()
+      .filter_map(|id| add_change_handler_by_id(id))
+      .collect();
+  }
+}
+
+impl UpdaterMut for DecompositionComponent {
+  fn update(&mut self) {
+    if !self.changed() {
+      return;
+    }
+    let document: Document = get_window().unwrap().document().unwrap();
+    let [mode_id, scavenging_id] = self.get_child_ids();
+    let html_select_element: HtmlSelectElement = document
+      .get_element_by_id(&mode_id)
+      .unwrap()
+      .dyn_into()
+      .unwrap();
+    let decomposition: Decomposition =
+      match html_select_element.value().as_str() {
+        "corpse" => Decomposition::Corpse,
+        "fertilize" => Decomposition::Fertilize,
+        _ => Decomposition::Off,
+      };
+    let html_input_element: HtmlInputElement = document
+      .get_element_by_id(&scavenging_id)
+      .unwrap()
+      .dyn_into()
+      .unwrap();
+    let mut inputs = self.inputs.borrow_mut();
+    inputs.decomposition_change_requested = Some(decomposition);
+    inputs.scavenging_change_requested = Some(html_input_element.checked());
+  }
+}
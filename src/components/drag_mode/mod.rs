@@ -0,0 +1,88 @@
+// =============================================================================
+//! - Component for the canvas drag mode selector for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::engine::traits::Component;
+use crate::messages::inputs::Inputs;
+use crate::models::options::DragMode;
+use com_croftsoft_lib_animation::web_sys::add_change_handler_by_id;
+use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
+use core::cell::RefCell;
+use futures::channel::mpsc::{TryRecvError, UnboundedReceiver};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, EventTarget, HtmlSelectElement};
+
+pub struct DragModeComponent {
+  id: String,
+  inputs: Rc<RefCell<Inputs>>,
+  unbounded_receiver_option: Option<UnboundedReceiver<Event>>,
+}
+
+impl DragModeComponent {
+  fn changed(&mut self) -> Option<String> {
+    let unbounded_receiver: &mut UnboundedReceiver<Event> =
+      self.unbounded_receiver_option.as_mut()?;
+    let result: Result<Option<Event>, TryRecvError> =
+      unbounded_receiver.try_next();
+    let event: Event = result.ok()??;
+    let event_target: EventTarget = event.target()?;
+    let html_select_element: HtmlSelectElement =
+      event_target.dyn_into().ok()?;
+    Some(html_select_element.value())
+  }
+
+  pub fn new(
+    id: &str,
+    inputs: Rc<RefCell<Inputs>>,
+  ) -> Self {
+    Self {
+      id: String::from(id),
+      inputs,
+      unbounded_receiver_option: None,
+    }
+  }
+}
+
+impl Component for DragModeComponent {
+  fn make_html(&self) -> String {
+    format!(
+      "Drag to <select id=\"{}\">\
+      <option value=\"pan\">pan the view</option>\
+      <option value=\"blight\">blight a rectangle</option>\
+      <option value=\"garden\">draw or move a garden</option>\
+      <option value=\"start-region\">draw the start region</option>\
+      </select>",
+      self.id,
+    )
+  }
+}
+
+impl InitializerMut for DragModeComponent {
+  fn initialize(&mut self) {
+    self.unbounded_receiver_option = add_change_handler_by_id(&self.id);
+  }
+}
+
+impl UpdaterMut for DragModeComponent {
+  fn update(&mut self) {
+    if let Some(value) = self.changed() {
+      self.inputs.borrow_mut().drag_mode_change_requested =
+        Some(match value.as_str() {
+          "blight" => DragMode::Blight,
+          "garden" => DragMode::Garden,
+          "start-region" => DragMode::StartRegion,
+          _ => DragMode::Pan,
+        });
+    }
+  }
+}
@@ -0,0 +1,125 @@
+// =============================================================================
+//! - Component for the environmental cycle settings for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::ENVIRONMENT_AMPLITUDE_INIT;
+use crate::engine::traits::Component;
+use crate::messages::inputs::Inputs;
+use crate::models::environment::EnvironmentSettings;
+use com_croftsoft_lib_animation::web_sys::{
+  add_change_handler_by_id, get_window,
+};
+use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
+use core::cell::RefCell;
+use futures::channel::mpsc::{TryRecvError, UnboundedReceiver};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Document, Event, HtmlInputElement};
+
+pub struct EnvironmentComponent {
+  id: String,
+  inputs: Rc<RefCell<Inputs>>,
+  unbounded_receivers: Vec<UnboundedReceiver<Event>>,
+}
+
+impl EnvironmentComponent {
+  fn changed(&mut self) -> bool {
+    let mut changed = false;
+    for unbounded_receiver in self.unbounded_receivers.iter_mut() {
+      let result: Result<Option<Event>, TryRecvError> =
+        unbounded_receiver.try_next();
+      if let Ok(Some(_event)) = result {
+        changed = true;
+      }
+    }
+    changed
+  }
+
+  fn get_child_ids(&self) -> [String; 4] {
+    [
+      format!("{}-seasons", self.id),
+      format!("{}-days", self.id),
+      format!("{}-amplitude", self.id),
+      format!("{}-weather", self.id),
+    ]
+  }
+
+  fn make_environment_settings(&self) -> EnvironmentSettings {
+    let document: Document = get_window().unwrap().document().unwrap();
+    let [seasons_id, days_id, amplitude_id, weather_id] = self.get_child_ids();
+    let get_html_input_element = |id: &str| -> HtmlInputElement {
+      document.get_element_by_id(id).unwrap().dyn_into().unwrap()
+    };
+    // Blank or out-of-range entries fall back to the defaults
+    let get_value = |id: &str, max: f64, default: usize| -> usize {
+      let value: f64 = get_html_input_element(id).value_as_number();
+      if (0. ..=max).contains(&value) {
+        value as usize
+      } else {
+        default
+      }
+    };
+    EnvironmentSettings {
+      amplitude: get_value(&amplitude_id, 100., ENVIRONMENT_AMPLITUDE_INIT),
+      day_period: get_value(&days_id, f64::MAX, 0),
+      season_period: get_value(&seasons_id, f64::MAX, 0),
+      weather_random: get_html_input_element(&weather_id).checked(),
+    }
+  }
+
+  pub fn new(
+    id: &str,
+    inputs: Rc<RefCell<Inputs>>,
+  ) -> Self {
+    Self {
+      id: String::from(id),
+      inputs,
+      unbounded_receivers: Vec::new(),
+    }
+  }
+}
+
+impl Component for EnvironmentComponent {
+  fn make_html(&self) -> String {
+    let [seasons_id, days_id, amplitude_id, weather_id] = self.get_child_ids();
+    format!(
+      "Year <input id=\"{}\" min=\"0\" type=\"number\" value=\"0\">\n\
+      Day <input id=\"{}\" min=\"0\" type=\"number\" value=\"0\"> updates\n\
+      Swing <input id=\"{}\" max=\"100\" min=\"0\" type=\"number\" \
+      value=\"{}\">%\n\
+      Droughts and booms <input id=\"{}\" type=\"checkbox\">",
+      seasons_id, days_id, amplitude_id, ENVIRONMENT_AMPLITUDE_INIT, weather_id,
+    )
+  }
+}
+
+impl InitializerMut for EnvironmentComponent {
+  fn initialize(&mut self) {
+    self.unbounded_receivers = self
+      .get_child_ids()
+      .iter()
+      .filter_map(|id| add_change_handler_by_id(id))
+      .collect();
+  }
+}
+
+impl UpdaterMut for EnvironmentComponent {
+  fn update(&mut self) {
+    if self.changed() {
+      self
+        .inputs
+        .borrow_mut()
+        .environment_settings_change_requested =
+        Some(self.make_environment_settings());
+    }
+  }
+}
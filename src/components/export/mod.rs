@@ -0,0 +1,147 @@
+// =============================================================================
+//! - Component for the statistics export controls
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::EXPORT_URL_REVOKE_DELAY_MILLIS;
+use crate::engine::functions::export::{to_csv, to_json};
+use crate::engine::traits::Component;
+use crate::models::root::Root;
+use com_croftsoft_lib_animation::web_sys::{
+  add_click_handler_by_id, get_window,
+};
+use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
+use core::cell::{Ref, RefCell};
+use futures::channel::mpsc::UnboundedReceiver;
+use js_sys::Array;
+use std::rc::Rc;
+use wasm_bindgen::prelude::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{
+  Blob, BlobPropertyBag, Document, HtmlAnchorElement, HtmlSelectElement, Url,
+};
+
+pub struct ExportComponent {
+  id: String,
+  root_model: Rc<RefCell<Root>>,
+  unbounded_receiver: Option<UnboundedReceiver<()>>,
+}
+
+impl ExportComponent {
+  fn clicked(&mut self) -> bool {
+    if self.unbounded_receiver.is_none() {
+      return false;
+    }
+    matches!(
+      self.unbounded_receiver.as_mut().unwrap().try_next(),
+      Ok(Some(()))
+    )
+  }
+
+  fn download(
+    content: &str,
+    file_name: &str,
+    mime_type: &str,
+  ) {
+    let blob_parts = Array::new();
+    blob_parts.push(&JsValue::from_str(content));
+    let mut blob_property_bag = BlobPropertyBag::new();
+    blob_property_bag.type_(mime_type);
+    let blob: Blob =
+      Blob::new_with_str_sequence_and_options(&blob_parts, &blob_property_bag)
+        .unwrap();
+    let url: String = Url::create_object_url_with_blob(&blob).unwrap();
+    let document: Document = get_window().unwrap().document().unwrap();
+    let html_anchor_element: HtmlAnchorElement =
+      document.create_element("a").unwrap().dyn_into().unwrap();
+    html_anchor_element.set_href(&url);
+    html_anchor_element.set_download(file_name);
+    html_anchor_element.click();
+    let revoke = Closure::once_into_js(move || {
+      Url::revoke_object_url(&url).unwrap();
+    });
+    get_window()
+      .unwrap()
+      .set_timeout_with_callback_and_timeout_and_arguments_0(
+        revoke.unchecked_ref(),
+        EXPORT_URL_REVOKE_DELAY_MILLIS,
+      )
+      .unwrap();
+  }
+
+  fn export(&self) {
+    let document: Document = get_window().unwrap().document().unwrap();
+    let html_select_element: HtmlSelectElement = document
+      .get_element_by_id(&self.get_format_id())
+      .unwrap()
+      .dyn_into()
+      .unwrap();
+    let format: String = html_select_element.value();
+    let root_model: Ref<Root> = self.root_model.borrow();
+    let statistics = root_model.statistics.borrow();
+    let seed: u64 = root_model.random.borrow().seed;
+    if format == "json" {
+      Self::download(
+        &to_json(&statistics),
+        &format!("evolve-{}.json", seed),
+        "application/json",
+      );
+    } else {
+      Self::download(
+        &to_csv(&statistics),
+        &format!("evolve-{}.csv", seed),
+        "text/csv",
+      );
+    }
+  }
+
+  fn get_format_id(&self) -> String {
+    format!("{}-format", self.id)
+  }
+
+  pub fn new(
+    id: &str,
+    root_model: Rc<RefCell<Root>>,
+  ) -> Self {
+    Self {
+      id: String::from(id),
+      root_model,
+      unbounded_receiver: None,
+    }
+  }
+}
+
+impl Component for ExportComponent {
+  fn make_html(&self) -> String {
+    format!(
+      "<select id=\"{}\">\
+      <option value=\"csv\">CSV</option>\
+      <option value=\"json\">JSON</option>\
+      </select><button id=\"{}\">Export</button>",
+      self.get_format_id(),
+      self.id,
+    )
+  }
+}
+
+impl InitializerMut for ExportComponent {
+  fn initialize(&mut self) {
+    self.unbounded_receiver = add_click_handler_by_id(&self.id);
+  }
+}
+
+impl UpdaterMut for ExportComponent {
+  fn update(&mut self) {
+    if self.clicked() {
+      self.export();
+    }
+  }
+}
@@ -1,83 +1,217 @@
 // =============================================================================
-//! - Component for the Garden of Eden button
+//! - Component for the garden controls
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-12-16
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+use crate::constants::{GARDEN_PERIOD_INIT, GARDEN_REPLENISH_RATE_INIT};
 use crate::engine::traits::Component;
 use crate::messages::inputs::Inputs;
-use com_croftsoft_lib_animation::web_sys::add_change_handler_by_id;
+use crate::models::flora::Flora;
+use crate::models::garden::{GardenMotion, GardenSettings};
+use com_croftsoft_lib_animation::web_sys::{
+  add_change_handler_by_id, add_click_handler_by_id, get_window,
+};
 use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
-use core::cell::RefCell;
+use core::cell::{Ref, RefCell};
 use futures::channel::mpsc::{TryRecvError, UnboundedReceiver};
 use std::rc::Rc;
 use wasm_bindgen::JsCast;
-use web_sys::{Event, EventTarget, HtmlInputElement};
+use web_sys::{Document, Event, HtmlInputElement, HtmlSelectElement};
 
 pub struct GardenComponent {
-  event_unbounded_receiver_option: Option<UnboundedReceiver<Event>>,
+  clear_unbounded_receiver_option: Option<UnboundedReceiver<()>>,
+  flora: Rc<RefCell<Flora>>,
   id: String,
   inputs: Rc<RefCell<Inputs>>,
+  // The replenish rate of the selected garden last shown in its field
+  replenish_rate_shown: Option<usize>,
+  unbounded_receivers: Vec<UnboundedReceiver<Event>>,
 }
 
 impl GardenComponent {
-  fn changed(&mut self) -> Option<Event> {
-    let unbounded_receiver: &mut UnboundedReceiver<Event> =
-      self.event_unbounded_receiver_option.as_mut()?;
-    let result: Result<Option<Event>, TryRecvError> =
-      unbounded_receiver.try_next();
-    if let Ok(event_option) = result {
-      return event_option;
+  fn changed(&mut self) -> bool {
+    let mut changed = false;
+    for unbounded_receiver in self.unbounded_receivers.iter_mut() {
+      let result: Result<Option<Event>, TryRecvError> =
+        unbounded_receiver.try_next();
+      if let Ok(Some(_event)) = result {
+        changed = true;
+      }
+    }
+    changed
+  }
+
+  fn clicked(&mut self) -> bool {
+    if self.clear_unbounded_receiver_option.is_none() {
+      return false;
+    }
+    matches!(
+      self
+        .clear_unbounded_receiver_option
+        .as_mut()
+        .unwrap()
+        .try_next(),
+      Ok(Some(()))
+    )
+  }
+
+  fn get_child_ids(&self) -> [String; 4] {
+    [
+      self.id.clone(),
+      format!("{}-replenish", self.id),
+      format!("{}-motion", self.id),
+      format!("{}-period", self.id),
+    ]
+  }
+
+  fn get_clear_id(&self) -> String {
+    format!("{}-clear", self.id)
+  }
+
+  fn get_enabled(&self) -> bool {
+    let document: Document = get_window().unwrap().document().unwrap();
+    let html_input_element: HtmlInputElement = document
+      .get_element_by_id(&self.id)
+      .unwrap()
+      .dyn_into()
+      .unwrap();
+    html_input_element.checked()
+  }
+
+  fn make_garden_settings(&self) -> GardenSettings {
+    let document: Document = get_window().unwrap().document().unwrap();
+    let [_enabled_id, replenish_id, motion_id, period_id] =
+      self.get_child_ids();
+    // Blank or out-of-range entries fall back to the defaults
+    let get_value = |id: &str, max: f64, default: usize| -> usize {
+      let html_input_element: HtmlInputElement =
+        document.get_element_by_id(id).unwrap().dyn_into().unwrap();
+      let value: f64 = html_input_element.value_as_number();
+      if (0. ..=max).contains(&value) {
+        value as usize
+      } else {
+        default
+      }
+    };
+    let html_select_element: HtmlSelectElement = document
+      .get_element_by_id(&motion_id)
+      .unwrap()
+      .dyn_into()
+      .unwrap();
+    let motion: GardenMotion = match html_select_element.value().as_str() {
+      "drift" => GardenMotion::Drift,
+      "jump" => GardenMotion::Jump,
+      _ => GardenMotion::Still,
+    };
+    GardenSettings {
+      motion,
+      period: get_value(&period_id, f64::MAX, GARDEN_PERIOD_INIT),
+      replenish_rate: get_value(
+        &replenish_id,
+        100.,
+        GARDEN_REPLENISH_RATE_INIT,
+      ),
     }
-    None
   }
 
   pub fn new(
+    flora: Rc<RefCell<Flora>>,
     id: &str,
     inputs: Rc<RefCell<Inputs>>,
   ) -> Self {
     Self {
+      clear_unbounded_receiver_option: None,
+      flora,
       id: String::from(id),
       inputs,
-      event_unbounded_receiver_option: None,
+      replenish_rate_shown: None,
+      unbounded_receivers: Vec::new(),
+    }
+  }
+
+  // Shows the replenish rate of the garden selected by the last drag
+  fn update_replenish_rate(&mut self) {
+    let replenish_rate_option: Option<usize> = {
+      let flora: Ref<Flora> = self.flora.borrow();
+      flora
+        .garden_selected
+        .and_then(|index| flora.gardens.get(index))
+        .map(|garden| garden.replenish_rate)
+    };
+    if replenish_rate_option == self.replenish_rate_shown {
+      return;
+    }
+    self.replenish_rate_shown = replenish_rate_option;
+    if let Some(replenish_rate) = replenish_rate_option {
+      let document: Document = get_window().unwrap().document().unwrap();
+      let [_enabled_id, replenish_id, _motion_id, _period_id] =
+        self.get_child_ids();
+      let html_input_element: HtmlInputElement = document
+        .get_element_by_id(&replenish_id)
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+      html_input_element.set_value_as_number(replenish_rate as f64);
     }
   }
 }
 
 impl Component for GardenComponent {
   fn make_html(&self) -> String {
+    let [enabled_id, replenish_id, motion_id, period_id] = self.get_child_ids();
     format!(
-      "Garden of Eden <input id=\"{}\" type=\"checkbox\" checked>",
-      self.id
+      "Gardens <input id=\"{}\" type=\"checkbox\" checked>\n\
+      Replenish <input id=\"{}\" max=\"100\" min=\"0\" type=\"number\" \
+      value=\"{}\">%\n\
+      <select id=\"{}\">\
+      <option value=\"still\">Still</option>\
+      <option value=\"drift\">Drift</option>\
+      <option value=\"jump\">Jump</option>\
+      </select>\n\
+      every <input id=\"{}\" min=\"0\" type=\"number\" value=\"{}\"> updates\n\
+      <button id=\"{}\">Clear gardens</button>",
+      enabled_id,
+      replenish_id,
+      GARDEN_REPLENISH_RATE_INIT,
+      motion_id,
+      period_id,
+      GARDEN_PERIOD_INIT,
+      self.get_clear_id(),
     )
   }
 }
 
 impl InitializerMut for GardenComponent {
   fn initialize(&mut self) {
-    self.event_unbounded_receiver_option = add_change_handler_by_id(&self.id);
+    self.clear_unbounded_receiver_option =
+      add_click_handler_by_id(&self.get_clear_id());
+    self.unbounded_receivers = self
+      .get_child_ids()
+      .iter()
+      .filter_map(|id| add_change_handler_by_id(id))
+      .collect();
   }
 }
 
 impl UpdaterMut for GardenComponent {
   fn update(&mut self) {
-    let event_option = self.changed();
-    if let Some(event) = event_option {
-      let event_target_option: Option<EventTarget> = event.target();
-      if let Some(event_target) = event_target_option {
-        let result: Result<HtmlInputElement, EventTarget> =
-          event_target.dyn_into::<HtmlInputElement>();
-        let html_input_element: HtmlInputElement = result.unwrap();
-        self.inputs.borrow_mut().garden_change_requested =
-          Some(html_input_element.checked());
-      }
+    if self.changed() {
+      let mut inputs = self.inputs.borrow_mut();
+      inputs.garden_change_requested = Some(self.get_enabled());
+      inputs.garden_settings_change_requested =
+        Some(self.make_garden_settings());
+    }
+    if self.clicked() {
+      self.inputs.borrow_mut().gardens_clear_requested = true;
     }
+    self.update_replenish_rate();
   }
 }
@@ -0,0 +1,177 @@
+// =============================================================================
+//! - Genome Component for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::engine::functions::genes::{parse_genome, parse_genomes};
+use crate::engine::traits::Component;
+use crate::messages::inputs::Inputs;
+use crate::models::genome::Genome;
+use com_croftsoft_lib_animation::web_sys::{
+  add_change_handler_by_id, get_window,
+};
+use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
+use core::cell::RefCell;
+use futures::channel::mpsc::{
+  unbounded, TryRecvError, UnboundedReceiver, UnboundedSender,
+};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{Event, EventTarget, File, FileList, HtmlInputElement};
+
+pub struct GenomeComponent {
+  event_unbounded_receiver_option: Option<UnboundedReceiver<Event>>,
+  file_event_unbounded_receiver_option: Option<UnboundedReceiver<Event>>,
+  file_text_unbounded_receiver: UnboundedReceiver<String>,
+  file_text_unbounded_sender: UnboundedSender<String>,
+  id: String,
+  inputs: Rc<RefCell<Inputs>>,
+}
+
+impl GenomeComponent {
+  fn changed(
+    unbounded_receiver_option: &mut Option<UnboundedReceiver<Event>>
+  ) -> Option<HtmlInputElement> {
+    let unbounded_receiver: &mut UnboundedReceiver<Event> =
+      unbounded_receiver_option.as_mut()?;
+    let result: Result<Option<Event>, TryRecvError> =
+      unbounded_receiver.try_next();
+    let event: Event = result.ok()??;
+    let event_target: EventTarget = event.target()?;
+    event_target.dyn_into::<HtmlInputElement>().ok()
+  }
+
+  fn get_file_id(&self) -> String {
+    format!("{}-file", self.id)
+  }
+
+  pub fn new(
+    id: &str,
+    inputs: Rc<RefCell<Inputs>>,
+  ) -> Self {
+    let (file_text_unbounded_sender, file_text_unbounded_receiver) =
+      unbounded::<String>();
+    Self {
+      event_unbounded_receiver_option: None,
+      file_event_unbounded_receiver_option: None,
+      file_text_unbounded_receiver,
+      file_text_unbounded_sender,
+      id: String::from(id),
+      inputs,
+    }
+  }
+
+  // The file is read asynchronously and the text is polled in a later update
+  fn read_file(
+    &self,
+    html_input_element: &HtmlInputElement,
+  ) {
+    let file_list_option: Option<FileList> = html_input_element.files();
+    let file: File = match file_list_option.and_then(|f| f.get(0)) {
+      Some(file) => file,
+      None => return,
+    };
+    // Allows the same file to be imported again after editing it
+    html_input_element.set_value("");
+    let sender: UnboundedSender<String> =
+      self.file_text_unbounded_sender.clone();
+    spawn_local(async move {
+      if let Ok(js_value) = JsFuture::from(file.text()).await {
+        if let Some(text) = js_value.as_string() {
+          let _ = sender.unbounded_send(text);
+        }
+      }
+    });
+  }
+
+  fn update_genome(
+    &self,
+    html_input_element: &HtmlInputElement,
+  ) {
+    let text: String = html_input_element.value();
+    let genome_option: Option<Genome> = if text.trim().is_empty() {
+      html_input_element.set_custom_validity("");
+      None
+    } else {
+      match parse_genome(&text) {
+        Ok(genome) => {
+          html_input_element.set_custom_validity("");
+          Some(genome)
+        },
+        Err(message) => {
+          html_input_element.set_custom_validity(&message);
+          html_input_element.report_validity();
+          None
+        },
+      }
+    };
+    self.inputs.borrow_mut().genome_change_requested = Some(genome_option);
+  }
+
+  fn update_genomes(
+    &self,
+    text: &str,
+  ) {
+    match parse_genomes(text) {
+      Ok(genomes) if !genomes.is_empty() => {
+        self.inputs.borrow_mut().genomes_import_requested = Some(genomes);
+      },
+      Ok(_) => {
+        let _ = get_window()
+          .unwrap()
+          .alert_with_message("The genome file contains no genomes");
+      },
+      Err(message) => {
+        let _ = get_window().unwrap().alert_with_message(&message);
+      },
+    }
+  }
+}
+
+impl Component for GenomeComponent {
+  fn make_html(&self) -> String {
+    format!(
+      "Genome <input id=\"{}\" placeholder=\"X:10110010 Y:00011101\" \
+      size=\"22\" type=\"text\"> \
+      Import genomes <input accept=\".txt,text/plain\" id=\"{}\" \
+      type=\"file\">",
+      self.id,
+      self.get_file_id(),
+    )
+  }
+}
+
+impl InitializerMut for GenomeComponent {
+  fn initialize(&mut self) {
+    self.event_unbounded_receiver_option = add_change_handler_by_id(&self.id);
+    self.file_event_unbounded_receiver_option =
+      add_change_handler_by_id(&self.get_file_id());
+  }
+}
+
+impl UpdaterMut for GenomeComponent {
+  fn update(&mut self) {
+    if let Some(html_input_element) =
+      Self::changed(&mut self.event_unbounded_receiver_option)
+    {
+      self.update_genome(&html_input_element);
+    }
+    if let Some(html_input_element) =
+      Self::changed(&mut self.file_event_unbounded_receiver_option)
+    {
+      self.read_file(&html_input_element);
+    }
+    if let Ok(Some(text)) = self.file_text_unbounded_receiver.try_next() {
+      self.update_genomes(&text);
+    }
+  }
+}
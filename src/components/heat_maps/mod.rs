@@ -0,0 +1,149 @@
+// =============================================================================
+//! - Heat Maps Component for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{HEAT_MAP_WINDOW_INIT, HEAT_MAP_WINDOW_MAX};
+use crate::engine::traits::Component;
+use crate::messages::inputs::Inputs;
+use com_croftsoft_lib_animation::web_sys::add_change_handler_by_id;
+use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
+use core::cell::RefCell;
+use futures::channel::mpsc::{TryRecvError, UnboundedReceiver};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, EventTarget, HtmlInputElement};
+
+pub struct HeatMapsComponent {
+  density_unbounded_receiver_option: Option<UnboundedReceiver<Event>>,
+  density_window_unbounded_receiver_option: Option<UnboundedReceiver<Event>>,
+  grazing_unbounded_receiver_option: Option<UnboundedReceiver<Event>>,
+  grazing_window_unbounded_receiver_option: Option<UnboundedReceiver<Event>>,
+  id: String,
+  inputs: Rc<RefCell<Inputs>>,
+}
+
+impl HeatMapsComponent {
+  fn changed(
+    unbounded_receiver_option: &mut Option<UnboundedReceiver<Event>>
+  ) -> Option<HtmlInputElement> {
+    let unbounded_receiver: &mut UnboundedReceiver<Event> =
+      unbounded_receiver_option.as_mut()?;
+    let result: Result<Option<Event>, TryRecvError> =
+      unbounded_receiver.try_next();
+    let event: Event = result.ok()??;
+    let event_target: EventTarget = event.target()?;
+    event_target.dyn_into().ok()
+  }
+
+  fn get_density_id(&self) -> String {
+    format!("{}-density", self.id)
+  }
+
+  fn get_density_window_id(&self) -> String {
+    format!("{}-density-window", self.id)
+  }
+
+  fn get_grazing_id(&self) -> String {
+    format!("{}-grazing", self.id)
+  }
+
+  fn get_grazing_window_id(&self) -> String {
+    format!("{}-grazing-window", self.id)
+  }
+
+  // Ignores a window that is not a positive whole number
+  fn to_window(html_input_element: &HtmlInputElement) -> Option<usize> {
+    let window: usize = html_input_element.value().parse().ok()?;
+    if window == 0 {
+      return None;
+    }
+    Some(window)
+  }
+
+  pub fn new(
+    id: &str,
+    inputs: Rc<RefCell<Inputs>>,
+  ) -> Self {
+    Self {
+      density_unbounded_receiver_option: None,
+      density_window_unbounded_receiver_option: None,
+      grazing_unbounded_receiver_option: None,
+      grazing_window_unbounded_receiver_option: None,
+      id: String::from(id),
+      inputs,
+    }
+  }
+}
+
+impl Component for HeatMapsComponent {
+  fn make_html(&self) -> String {
+    format!(
+      "Display density <input id=\"{}\" type=\"checkbox\">\n\
+      window <input id=\"{}\" max=\"{}\" min=\"1\" style=\"width: 5em\" \
+      type=\"number\" value=\"{}\">\n\
+      Display grazing <input id=\"{}\" type=\"checkbox\">\n\
+      window <input id=\"{}\" max=\"{}\" min=\"1\" style=\"width: 5em\" \
+      type=\"number\" value=\"{}\">",
+      self.get_density_id(),
+      self.get_density_window_id(),
+      HEAT_MAP_WINDOW_MAX,
+      HEAT_MAP_WINDOW_INIT,
+      self.get_grazing_id(),
+      self.get_grazing_window_id(),
+      HEAT_MAP_WINDOW_MAX,
+      HEAT_MAP_WINDOW_INIT,
+    )
+  }
+}
+
+impl InitializerMut for HeatMapsComponent {
+  fn initialize(&mut self) {
+    self.density_unbounded_receiver_option =
+      add_change_handler_by_id(&self.get_density_id());
+    self.density_window_unbounded_receiver_option =
+      add_change_handler_by_id(&self.get_density_window_id());
+    self.grazing_unbounded_receiver_option =
+      add_change_handler_by_id(&self.get_grazing_id());
+    self.grazing_window_unbounded_receiver_option =
+      add_change_handler_by_id(&self.get_grazing_window_id());
+  }
+}
+
+impl UpdaterMut for HeatMapsComponent {
+  fn update(&mut self) {
+    let mut inputs = self.inputs.borrow_mut();
+    if let Some(html_input_element) =
+      Self::changed(&mut self.density_unbounded_receiver_option)
+    {
+      inputs.density_heat_map_display_change_requested =
+        Some(html_input_element.checked());
+    }
+    if let Some(html_input_element) =
+      Self::changed(&mut self.density_window_unbounded_receiver_option)
+    {
+      inputs.density_heat_map_window_change_requested =
+        Self::to_window(&html_input_element);
+    }
+    if let Some(html_input_element) =
+      Self::changed(&mut self.grazing_unbounded_receiver_option)
+    {
+      inputs.grazing_heat_map_display_change_requested =
+        Some(html_input_element.checked());
+    }
+    if let Some(html_input_element) =
+      Self::changed(&mut self.grazing_window_unbounded_receiver_option)
+    {
+      inputs.grazing_heat_map_window_change_requested =
+        Self::to_window(&html_input_element);
+    }
+  }
+}
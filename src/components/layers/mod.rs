@@ -0,0 +1,117 @@
+// =============================================================================
+//! - Layers Component for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::engine::traits::Component;
+use crate::messages::inputs::Inputs;
+use crate::models::options::Options;
+use crate::painters::layers::{LayerDescriptor, LAYERS};
+use com_croftsoft_lib_animation::web_sys::add_change_handler_by_id;
+use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
+use core::cell::RefCell;
+use futures::channel::mpsc::{TryRecvError, UnboundedReceiver};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, EventTarget, HtmlInputElement};
+
+// A visibility checkbox for each registered layer with a label
+pub struct LayersComponent {
+  id: String,
+  inputs: Rc<RefCell<Inputs>>,
+  unbounded_receiver_options:
+    Vec<(&'static str, Option<UnboundedReceiver<Event>>)>,
+}
+
+impl LayersComponent {
+  fn changed(
+    unbounded_receiver_option: &mut Option<UnboundedReceiver<Event>>
+  ) -> Option<bool> {
+    let unbounded_receiver: &mut UnboundedReceiver<Event> =
+      unbounded_receiver_option.as_mut()?;
+    let result: Result<Option<Event>, TryRecvError> =
+      unbounded_receiver.try_next();
+    let event: Event = result.ok()??;
+    let event_target: EventTarget = event.target()?;
+    let html_input_element: HtmlInputElement = event_target.dyn_into().ok()?;
+    Some(html_input_element.checked())
+  }
+
+  fn get_layer_id(
+    &self,
+    layer_descriptor: &LayerDescriptor,
+  ) -> String {
+    format!("{}-{}", self.id, layer_descriptor.id)
+  }
+
+  pub fn new(
+    id: &str,
+    inputs: Rc<RefCell<Inputs>>,
+  ) -> Self {
+    Self {
+      id: String::from(id),
+      inputs,
+      unbounded_receiver_options: Vec::new(),
+    }
+  }
+}
+
+impl Component for LayersComponent {
+  fn make_html(&self) -> String {
+    let options = Options::default();
+    let checkboxes: Vec<String> = LAYERS
+      .iter()
+      .filter_map(|layer_descriptor| {
+        let label: &str = layer_descriptor.label?;
+        let checked: &str = if layer_descriptor.is_visible(&options) {
+          " checked"
+        } else {
+          ""
+        };
+        Some(format!(
+          "{} <input id=\"{}\" type=\"checkbox\"{}>",
+          label,
+          self.get_layer_id(layer_descriptor),
+          checked,
+        ))
+      })
+      .collect();
+    format!("Layers: {}", checkboxes.join("\n"))
+  }
+}
+
+impl InitializerMut for LayersComponent {
+  fn initialize(&mut self) {
+    self.unbounded_receiver_options = LAYERS
+      .iter()
+      .filter(|layer_descriptor| layer_descriptor.label.is_some())
+      .map(|layer_descriptor| {
+        (
+          layer_descriptor.id,
+          add_change_handler_by_id(&self.get_layer_id(layer_descriptor)),
+        )
+      })
+      .collect();
+  }
+}
+
+impl UpdaterMut for LayersComponent {
+  fn update(&mut self) {
+    for (layer_id, unbounded_receiver_option) in
+      self.unbounded_receiver_options.iter_mut()
+    {
+      if let Some(visible) = Self::changed(unbounded_receiver_option) {
+        self.inputs.borrow_mut().layer_visibility_change_requested =
+          Some((layer_id, visible));
+      }
+    }
+  }
+}
@@ -1,10 +1,33 @@
+pub mod aging;
 pub mod blight;
 pub mod canvas;
+pub mod color;
+pub mod crowding;
+pub mod decomposition;
+pub mod diversity;
+pub mod drag_mode;
+pub mod environment;
+pub mod export;
 pub mod flora;
+pub mod flora_mode;
 pub mod frame_rate;
 pub mod garden;
+pub mod gene_heat_map;
+pub mod genome;
+pub mod heading;
+pub mod heat_maps;
+pub mod heritable;
+pub mod interpolation;
+pub mod layers;
+pub mod life_history;
 pub mod pause;
+pub mod profiler;
 pub mod reset;
 pub mod root;
+pub mod schedule;
+pub mod species;
 pub mod speed;
 pub mod time;
+pub mod trail;
+pub mod traits;
+pub mod viewport;
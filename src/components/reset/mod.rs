@@ -1,36 +1,104 @@
 // =============================================================================
-//! - Component for the reset button
+//! - Component for the reset button and the reset settings
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-12-17
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+use crate::constants::BUGS_MAX;
 use crate::engine::traits::Component;
 use crate::messages::inputs::Inputs;
-use com_croftsoft_lib_animation::web_sys::add_click_handler_by_id;
+use crate::models::reset::{GenomeSource, Placement, ResetSettings};
+use com_croftsoft_lib_animation::web_sys::{
+  add_change_handler_by_id, add_click_handler_by_id, get_window,
+};
 use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
 use core::cell::RefCell;
-use futures::channel::mpsc::UnboundedReceiver;
+use futures::channel::mpsc::{TryRecvError, UnboundedReceiver};
 use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Document, Event, HtmlInputElement, HtmlSelectElement};
 
 pub struct ResetComponent {
+  change_receivers: Vec<UnboundedReceiver<Event>>,
   id: String,
   inputs: Rc<RefCell<Inputs>>,
   unbounded_receiver: Option<UnboundedReceiver<()>>,
 }
 
 impl ResetComponent {
+  fn changed(&mut self) -> bool {
+    let mut changed = false;
+    for change_receiver in self.change_receivers.iter_mut() {
+      let result: Result<Option<Event>, TryRecvError> =
+        change_receiver.try_next();
+      if let Ok(Some(_event)) = result {
+        changed = true;
+      }
+    }
+    changed
+  }
+
+  fn get_child_ids(&self) -> [String; 3] {
+    [
+      format!("{}-population", self.id),
+      format!("{}-placement", self.id),
+      format!("{}-genomes", self.id),
+    ]
+  }
+
+  fn make_reset_settings(&self) -> ResetSettings {
+    let document: Document = get_window().unwrap().document().unwrap();
+    let [population_id, placement_id, genomes_id] = self.get_child_ids();
+    let get_select_value = |id: &str| -> String {
+      let html_select_element: HtmlSelectElement =
+        document.get_element_by_id(id).unwrap().dyn_into().unwrap();
+      html_select_element.value()
+    };
+    let html_input_element: HtmlInputElement = document
+      .get_element_by_id(&population_id)
+      .unwrap()
+      .dyn_into()
+      .unwrap();
+    let population: f64 = html_input_element.value_as_number();
+    // A blank or out-of-range population falls back to the maximum
+    let population: usize = if (0. ..=BUGS_MAX as f64).contains(&population) {
+      population as usize
+    } else {
+      BUGS_MAX
+    };
+    let placement: Placement = match get_select_value(&placement_id).as_str() {
+      "gardens" => Placement::Gardens,
+      "region" => Placement::Region,
+      "uniform" => Placement::Uniform,
+      _ => Placement::Center,
+    };
+    let genome_source: GenomeSource =
+      match get_select_value(&genomes_id).as_str() {
+        "single" => GenomeSource::Single,
+        "supplied" => GenomeSource::Supplied,
+        "survivors" => GenomeSource::Survivors,
+        _ => GenomeSource::Random,
+      };
+    ResetSettings {
+      genome_source,
+      placement,
+      population,
+    }
+  }
+
   pub fn new(
     id: &str,
     inputs: Rc<RefCell<Inputs>>,
   ) -> Self {
     Self {
+      change_receivers: Vec::new(),
       id: String::from(id),
       inputs,
       unbounded_receiver: None,
@@ -50,18 +118,45 @@ impl ResetComponent {
 
 impl Component for ResetComponent {
   fn make_html(&self) -> String {
-    format!("<button id=\"{}\">Reset</button>", self.id)
+    let [population_id, placement_id, genomes_id] = self.get_child_ids();
+    format!(
+      "<button id=\"{}\">Reset</button>\n\
+      with <input id=\"{}\" max=\"{}\" min=\"0\" type=\"number\" \
+      value=\"{}\"> critters\n\
+      <select id=\"{}\">\
+      <option value=\"center\">at the center</option>\
+      <option value=\"gardens\">in the gardens</option>\
+      <option value=\"region\">in the drawn region</option>\
+      <option value=\"uniform\">scattered everywhere</option>\
+      </select>\n\
+      <select id=\"{}\">\
+      <option value=\"random\">with random genomes</option>\
+      <option value=\"single\">with one genome</option>\
+      <option value=\"supplied\">with the imported genomes</option>\
+      <option value=\"survivors\">with the survivors' genomes</option>\
+      </select>",
+      self.id, population_id, BUGS_MAX, BUGS_MAX, placement_id, genomes_id,
+    )
   }
 }
 
 impl InitializerMut for ResetComponent {
   fn initialize(&mut self) {
+    self.change_receivers = self
+      .get_child_ids()
+      .iter()
+      .filter_map(|id| add_change_handler_by_id(id))
+      .collect();
     self.unbounded_receiver = add_click_handler_by_id(&self.id);
   }
 }
 
 impl UpdaterMut for ResetComponent {
   fn update(&mut self) {
+    if self.changed() {
+      self.inputs.borrow_mut().reset_settings_change_requested =
+        Some(self.make_reset_settings());
+    }
     if self.pressed() {
       self.inputs.borrow_mut().reset_requested = true;
     }
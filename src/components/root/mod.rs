@@ -2,28 +2,53 @@
 //! - Root Component for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-12-17
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+use super::aging::AgingComponent;
 use super::blight::BlightComponent;
 use super::canvas::CanvasComponent;
+use super::color::ColorComponent;
+use super::crowding::CrowdingComponent;
+use super::decomposition::DecompositionComponent;
+use super::diversity::DiversityComponent;
+use super::drag_mode::DragModeComponent;
+use super::environment::EnvironmentComponent;
+use super::export::ExportComponent;
 use super::flora::FloraComponent;
+use super::flora_mode::FloraModeComponent;
 use super::frame_rate::FrameRateComponent;
 use super::garden::GardenComponent;
+use super::gene_heat_map::GeneHeatMapComponent;
+use super::genome::GenomeComponent;
+use super::heading::HeadingComponent;
+use super::heat_maps::HeatMapsComponent;
+use super::heritable::HeritableComponent;
+use super::interpolation::InterpolationComponent;
+use super::layers::LayersComponent;
+use super::life_history::LifeHistoryComponent;
 use super::pause::PauseComponent;
+use super::profiler::ProfilerComponent;
 use super::reset::ResetComponent;
+use super::schedule::ScheduleComponent;
+use super::species::SpeciesComponent;
 use super::speed::SpeedComponent;
 use super::time::TimeComponent;
+use super::trail::TrailComponent;
+use super::traits::TraitsComponent;
+use super::viewport::ViewportComponent;
+use crate::engine::functions::web_sys::get_performance_now;
 use crate::engine::traits::Component;
 use crate::messages::events::Events;
 use crate::messages::inputs::Inputs;
 use crate::models::options::Options;
+use crate::models::profiler::Profiler;
 use crate::models::root::Root;
 use com_croftsoft_lib_animation::web_sys::get_window;
 use com_croftsoft_lib_role::{InitializerMut, Painter, UpdaterMut};
@@ -32,17 +57,42 @@ use std::rc::Rc;
 use web_sys::{Document, HtmlCollection};
 
 pub struct RootComponent {
+  aging_component: Rc<RefCell<AgingComponent>>,
   blight_component: Rc<RefCell<BlightComponent>>,
   canvas_component: Rc<RefCell<CanvasComponent>>,
-  components: [Rc<RefCell<dyn Component>>; 9],
+  color_component: Rc<RefCell<ColorComponent>>,
+  components: [Rc<RefCell<dyn Component>>; 32],
+  crowding_component: Rc<RefCell<CrowdingComponent>>,
+  decomposition_component: Rc<RefCell<DecompositionComponent>>,
+  diversity_component: Rc<RefCell<DiversityComponent>>,
+  drag_mode_component: Rc<RefCell<DragModeComponent>>,
+  environment_component: Rc<RefCell<EnvironmentComponent>>,
   events: Rc<RefCell<Events>>,
+  export_component: Rc<RefCell<ExportComponent>>,
   flora_component: Rc<RefCell<FloraComponent>>,
+  flora_mode_component: Rc<RefCell<FloraModeComponent>>,
   frame_rate_component: Rc<RefCell<FrameRateComponent>>,
   garden_component: Rc<RefCell<GardenComponent>>,
+  gene_heat_map_component: Rc<RefCell<GeneHeatMapComponent>>,
+  genome_component: Rc<RefCell<GenomeComponent>>,
+  heading_component: Rc<RefCell<HeadingComponent>>,
+  heat_maps_component: Rc<RefCell<HeatMapsComponent>>,
+  heritable_component: Rc<RefCell<HeritableComponent>>,
+  interpolation_component: Rc<RefCell<InterpolationComponent>>,
+  layers_component: Rc<RefCell<LayersComponent>>,
+  life_history_component: Rc<RefCell<LifeHistoryComponent>>,
+  options: Rc<RefCell<Options>>,
   pause_component: Rc<RefCell<PauseComponent>>,
+  profiler: Rc<RefCell<Profiler>>,
+  profiler_component: Rc<RefCell<ProfilerComponent>>,
   reset_component: Rc<RefCell<ResetComponent>>,
+  schedule_component: Rc<RefCell<ScheduleComponent>>,
+  species_component: Rc<RefCell<SpeciesComponent>>,
   speed_component: Rc<RefCell<SpeedComponent>>,
   time_component: Rc<RefCell<TimeComponent>>,
+  trail_component: Rc<RefCell<TrailComponent>>,
+  traits_component: Rc<RefCell<TraitsComponent>>,
+  viewport_component: Rc<RefCell<ViewportComponent>>,
 }
 
 impl RootComponent {
@@ -54,83 +104,270 @@ impl RootComponent {
     options: Rc<RefCell<Options>>,
     root_model: Rc<RefCell<Root>>,
   ) -> Self {
+    let profiler: Rc<RefCell<Profiler>> = root_model.borrow().profiler.clone();
+    let aging_component =
+      Rc::new(RefCell::new(AgingComponent::new("aging", inputs.clone())));
     let blight_component =
       Rc::new(RefCell::new(BlightComponent::new("blight", inputs.clone())));
     let canvas_component = Rc::new(RefCell::new(CanvasComponent::new(
+      events.clone(),
       "canvas",
       inputs.clone(),
-      options,
-      root_model,
+      options.clone(),
+      root_model.clone(),
+    )));
+    let color_component =
+      Rc::new(RefCell::new(ColorComponent::new("color", inputs.clone())));
+    let crowding_component = Rc::new(RefCell::new(CrowdingComponent::new(
+      "crowding",
+      inputs.clone(),
+    )));
+    let decomposition_component = Rc::new(RefCell::new(
+      DecompositionComponent::new("decomposition", inputs.clone()),
+    ));
+    let diversity_component = Rc::new(RefCell::new(DiversityComponent::new(
+      "diversity",
+      inputs.clone(),
+    )));
+    let drag_mode_component = Rc::new(RefCell::new(DragModeComponent::new(
+      "drag-mode",
+      inputs.clone(),
+    )));
+    let environment_component = Rc::new(RefCell::new(
+      EnvironmentComponent::new("environment", inputs.clone()),
+    ));
+    let export_component = Rc::new(RefCell::new(ExportComponent::new(
+      "export",
+      root_model.clone(),
     )));
     let flora_component =
       Rc::new(RefCell::new(FloraComponent::new("flora", inputs.clone())));
+    let flora_mode_component = Rc::new(RefCell::new(FloraModeComponent::new(
+      "flora-mode",
+      inputs.clone(),
+    )));
     let frame_rate_component = Rc::new(RefCell::new(FrameRateComponent::new(
       "frame-rate",
       inputs.clone(),
     )));
-    let garden_component =
-      Rc::new(RefCell::new(GardenComponent::new("garden", inputs.clone())));
+    let garden_component = Rc::new(RefCell::new(GardenComponent::new(
+      root_model.borrow().flora.clone(),
+      "garden",
+      inputs.clone(),
+    )));
+    let gene_heat_map_component = Rc::new(RefCell::new(
+      GeneHeatMapComponent::new("gene-heat-map", inputs.clone()),
+    ));
+    let genome_component =
+      Rc::new(RefCell::new(GenomeComponent::new("genome", inputs.clone())));
+    let heading_component = Rc::new(RefCell::new(HeadingComponent::new(
+      "heading",
+      inputs.clone(),
+    )));
+    let heat_maps_component = Rc::new(RefCell::new(HeatMapsComponent::new(
+      "heat-maps",
+      inputs.clone(),
+    )));
+    let heritable_component = Rc::new(RefCell::new(HeritableComponent::new(
+      "heritable",
+      inputs.clone(),
+    )));
+    let interpolation_component = Rc::new(RefCell::new(
+      InterpolationComponent::new("interpolation", inputs.clone()),
+    ));
+    let layers_component =
+      Rc::new(RefCell::new(LayersComponent::new("layer", inputs.clone())));
+    let life_history_component = Rc::new(RefCell::new(
+      LifeHistoryComponent::new("life-history", inputs.clone()),
+    ));
     let pause_component =
       Rc::new(RefCell::new(PauseComponent::new("pause", inputs.clone())));
+    let profiler_component = Rc::new(RefCell::new(ProfilerComponent::new(
+      "profiler",
+      inputs.clone(),
+    )));
     let reset_component =
       Rc::new(RefCell::new(ResetComponent::new("reset", inputs.clone())));
+    let schedule_component = Rc::new(RefCell::new(ScheduleComponent::new(
+      "schedule",
+      inputs.clone(),
+    )));
+    let species_component = Rc::new(RefCell::new(SpeciesComponent::new(
+      "species",
+      inputs.clone(),
+    )));
     let speed_component =
       Rc::new(RefCell::new(SpeedComponent::new("speed", inputs.clone())));
     let time_component =
-      Rc::new(RefCell::new(TimeComponent::new("time", inputs)));
-    let components: [Rc<RefCell<dyn Component>>; 9] = [
+      Rc::new(RefCell::new(TimeComponent::new("time", inputs.clone())));
+    let trail_component =
+      Rc::new(RefCell::new(TrailComponent::new("trail", inputs.clone())));
+    let traits_component =
+      Rc::new(RefCell::new(TraitsComponent::new("traits", inputs.clone())));
+    let viewport_component =
+      Rc::new(RefCell::new(ViewportComponent::new("viewport", inputs)));
+    let components: [Rc<RefCell<dyn Component>>; 32] = [
+      aging_component.clone(),
       blight_component.clone(),
       canvas_component.clone(),
+      color_component.clone(),
+      crowding_component.clone(),
+      decomposition_component.clone(),
+      diversity_component.clone(),
+      drag_mode_component.clone(),
+      environment_component.clone(),
+      export_component.clone(),
       flora_component.clone(),
+      flora_mode_component.clone(),
       frame_rate_component.clone(),
       garden_component.clone(),
+      gene_heat_map_component.clone(),
+      genome_component.clone(),
+      heading_component.clone(),
+      heat_maps_component.clone(),
+      heritable_component.clone(),
+      interpolation_component.clone(),
+      layers_component.clone(),
+      life_history_component.clone(),
       pause_component.clone(),
+      profiler_component.clone(),
       reset_component.clone(),
+      schedule_component.clone(),
+      species_component.clone(),
       speed_component.clone(),
       time_component.clone(),
+      trail_component.clone(),
+      traits_component.clone(),
+      viewport_component.clone(),
     ];
     Self {
+      aging_component,
       blight_component,
       canvas_component,
+      color_component,
       components,
+      crowding_component,
+      decomposition_component,
+      diversity_component,
+      drag_mode_component,
+      environment_component,
       events,
+      export_component,
       flora_component,
+      flora_mode_component,
       frame_rate_component,
       garden_component,
+      gene_heat_map_component,
+      genome_component,
+      heading_component,
+      heat_maps_component,
+      heritable_component,
+      interpolation_component,
+      layers_component,
+      life_history_component,
+      options,
       pause_component,
+      profiler,
+      profiler_component,
       reset_component,
+      schedule_component,
+      species_component,
       speed_component,
       time_component,
+      trail_component,
+      traits_component,
+      viewport_component,
     }
   }
 }
 
 impl Component for RootComponent {
   fn make_html(&self) -> String {
+    let aging_html: String = self.aging_component.borrow().make_html();
     let blight_html: String = self.blight_component.borrow().make_html();
     let canvas_html: String = self.canvas_component.borrow().make_html();
+    let color_html: String = self.color_component.borrow().make_html();
+    let crowding_html: String = self.crowding_component.borrow().make_html();
+    let decomposition_html: String =
+      self.decomposition_component.borrow().make_html();
+    let diversity_html: String = self.diversity_component.borrow().make_html();
+    let drag_mode_html: String = self.drag_mode_component.borrow().make_html();
+    let environment_html: String =
+      self.environment_component.borrow().make_html();
+    let export_html: String = self.export_component.borrow().make_html();
     let flora_html: String = self.flora_component.borrow().make_html();
+    let flora_mode_html: String =
+      self.flora_mode_component.borrow().make_html();
     let frame_rate_html: String =
       self.frame_rate_component.borrow().make_html();
     let garden_html: String = self.garden_component.borrow().make_html();
+    let gene_heat_map_html: String =
+      self.gene_heat_map_component.borrow().make_html();
+    let genome_html: String = self.genome_component.borrow().make_html();
+    let heading_html: String = self.heading_component.borrow().make_html();
+    let heat_maps_html: String = self.heat_maps_component.borrow().make_html();
+    let heritable_html: String = self.heritable_component.borrow().make_html();
+    let interpolation_html: String =
+      self.interpolation_component.borrow().make_html();
+    let layers_html: String = self.layers_component.borrow().make_html();
+    let life_history_html: String =
+      self.life_history_component.borrow().make_html();
     let pause_html: String = self.pause_component.borrow().make_html();
+    let profiler_html: String = self.profiler_component.borrow().make_html();
     let reset_html: String = self.reset_component.borrow().make_html();
+    let schedule_html: String = self.schedule_component.borrow().make_html();
+    let species_html: String = self.species_component.borrow().make_html();
     let speed_html: String = self.speed_component.borrow().make_html();
     let time_html: String = self.time_component.borrow().make_html();
+    let trail_html: String = self.trail_component.borrow().make_html();
+    let traits_html: String = self.traits_component.borrow().make_html();
+    let viewport_html: String = self.viewport_component.borrow().make_html();
     // TODO: Assemble this from an HTML template
     [
       String::from("<div id=\"root\">"),
       canvas_html,
       String::from("<br>"),
       reset_html,
+      String::from("<br>"),
+      viewport_html,
+      drag_mode_html,
       blight_html,
       flora_html,
+      flora_mode_html,
       garden_html,
+      schedule_html,
+      String::from("<br>"),
+      environment_html,
+      String::from("<br>"),
+      crowding_html,
+      aging_html,
+      heritable_html,
+      decomposition_html,
       String::from("<br>"),
       speed_html,
       frame_rate_html,
       time_html,
+      gene_heat_map_html,
+      diversity_html,
+      life_history_html,
+      traits_html,
+      profiler_html,
       pause_html,
+      String::from("<br>"),
+      species_html,
+      String::from("<br>"),
+      color_html,
+      trail_html,
+      heading_html,
+      interpolation_html,
+      String::from("<br>"),
+      heat_maps_html,
+      String::from("<br>"),
+      layers_html,
+      String::from("<br>"),
+      genome_html,
+      String::from("<br>"),
+      export_html,
       String::from("</div>"),
     ]
     .join("\n")
@@ -159,7 +396,15 @@ impl Painter for RootComponent {
     if !self.events.borrow().updated {
       return;
     }
+    if !self.options.borrow().profile_display {
+      self.canvas_component.borrow_mut().paint();
+      return;
+    }
+    let start_millis: f64 = get_performance_now();
+    self.profiler.borrow_mut().render_rate.push(start_millis);
     self.canvas_component.borrow_mut().paint();
+    let duration_millis: f64 = get_performance_now() - start_millis;
+    self.profiler.borrow_mut().paint.push(duration_millis);
   }
 }
 
@@ -0,0 +1,158 @@
+// =============================================================================
+//! - Component for the species classifier controls
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{
+  CLASSIFIER_CLUSTER_RADIUS_INIT, CLASSIFIER_CRUISER_SPEED_MIN_INIT,
+  CLASSIFIER_TWIRLIE_SPEED_MAX_INIT, LOCUS_COUNT,
+};
+use crate::engine::traits::Component;
+use crate::messages::inputs::Inputs;
+use crate::models::classifier::{ClassifierMode, ClassifierSettings};
+use com_croftsoft_lib_animation::web_sys::{
+  add_change_handler_by_id, get_window,
+};
+use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
+use core::cell::RefCell;
+use futures::channel::mpsc::{TryRecvError, UnboundedReceiver};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Document, Event, HtmlInputElement, HtmlSelectElement};
+
+pub struct SpeciesComponent {
+  id: String,
+  inputs: Rc<RefCell<Inputs>>,
+  unbounded_receivers: Vec<UnboundedReceiver<Event>>,
+}
+
+impl SpeciesComponent {
+  fn changed(&mut self) -> bool {
+    let mut changed = false;
+    for unbounded_receiver in self.unbounded_receivers.iter_mut() {
+      let result: Result<Option<Event>, TryRecvError> =
+        unbounded_receiver.try_next();
+      if let Ok(Some(_event)) = result {
+        changed = true;
+      }
+    }
+    changed
+  }
+
+  fn get_child_ids(&self) -> [String; 4] {
+    [
+      format!("{}-mode", self.id),
+      format!("{}-twirlie", self.id),
+      format!("{}-cruiser", self.id),
+      format!("{}-radius", self.id),
+    ]
+  }
+
+  fn make_settings(&self) -> ClassifierSettings {
+    let document: Document = get_window().unwrap().document().unwrap();
+    let [mode_id, twirlie_id, cruiser_id, radius_id] = self.get_child_ids();
+    let html_select_element: HtmlSelectElement = document
+      .get_element_by_id(&mode_id)
+      .unwrap()
+      .dyn_into()
+      .unwrap();
+    let mode = match html_select_element.value().as_str() {
+      "cluster" => ClassifierMode::Cluster,
+      "heading" => ClassifierMode::SpeedHeading,
+      _ => ClassifierMode::Speed,
+    };
+    let get_value = |id: &str| -> f64 {
+      let html_input_element: HtmlInputElement =
+        document.get_element_by_id(id).unwrap().dyn_into().unwrap();
+      html_input_element.value_as_number()
+    };
+    let defaults = ClassifierSettings::default();
+    let twirlie_speed_max: f64 = get_value(&twirlie_id);
+    let cruiser_speed_min: f64 = get_value(&cruiser_id);
+    let cluster_radius: f64 = get_value(&radius_id);
+    // Blank or out-of-range entries fall back to the defaults
+    ClassifierSettings {
+      cluster_radius: if (0. ..=LOCUS_COUNT as f64).contains(&cluster_radius) {
+        cluster_radius as u32
+      } else {
+        defaults.cluster_radius
+      },
+      cruiser_speed_min: if (0. ..=1.).contains(&cruiser_speed_min) {
+        cruiser_speed_min
+      } else {
+        defaults.cruiser_speed_min
+      },
+      mode,
+      twirlie_speed_max: if (0. ..=1.).contains(&twirlie_speed_max) {
+        twirlie_speed_max
+      } else {
+        defaults.twirlie_speed_max
+      },
+    }
+  }
+
+  pub fn new(
+    id: &str,
+    inputs: Rc<RefCell<Inputs>>,
+  ) -> Self {
+    Self {
+      id: String::from(id),
+      inputs,
+      unbounded_receivers: Vec::new(),
+    }
+  }
+}
+
+impl Component for SpeciesComponent {
+  fn make_html(&self) -> String {
+    let [mode_id, twirlie_id, cruiser_id, radius_id] = self.get_child_ids();
+    format!(
+      "Species <select id=\"{}\">\
+      <option value=\"speed\">Speed</option>\
+      <option value=\"heading\">Speed and heading</option>\
+      <option value=\"cluster\">Genome clusters</option>\
+      </select>\n\
+      Twirlie max <input id=\"{}\" max=\"1\" min=\"0\" step=\"0.05\" \
+      type=\"number\" value=\"{}\">\n\
+      Cruiser min <input id=\"{}\" max=\"1\" min=\"0\" step=\"0.05\" \
+      type=\"number\" value=\"{}\">\n\
+      Cluster radius <input id=\"{}\" max=\"{}\" min=\"0\" \
+      type=\"number\" value=\"{}\">",
+      mode_id,
+      twirlie_id,
+      CLASSIFIER_TWIRLIE_SPEED_MAX_INIT,
+      cruiser_id,
+      CLASSIFIER_CRUISER_SPEED_MIN_INIT,
+      radius_id,
+      LOCUS_COUNT,
+      CLASSIFIER_CLUSTER_RADIUS_INIT,
+    )
+  }
+}
+
+impl InitializerMut for SpeciesComponent {
+  fn initialize(&mut self) {
+    self.unbounded_receivers = self
+      .get_child_ids()
+      .iter()
+      .filter_map(|id| add_change_handler_by_id(id))
+      .collect();
+  }
+}
+
+impl UpdaterMut for SpeciesComponent {
+  fn update(&mut self) {
+    if self.changed() {
+      self.inputs.borrow_mut().classifier_change_requested =
+        Some(self.make_settings());
+    }
+  }
+}
@@ -0,0 +1,69 @@
+// =============================================================================
+//! - Component for the fit world button
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::engine::traits::Component;
+use crate::messages::inputs::Inputs;
+use com_croftsoft_lib_animation::web_sys::add_click_handler_by_id;
+use com_croftsoft_lib_role::{InitializerMut, UpdaterMut};
+use core::cell::RefCell;
+use futures::channel::mpsc::UnboundedReceiver;
+use std::rc::Rc;
+
+pub struct ViewportComponent {
+  id: String,
+  inputs: Rc<RefCell<Inputs>>,
+  unbounded_receiver: Option<UnboundedReceiver<()>>,
+}
+
+impl ViewportComponent {
+  pub fn new(
+    id: &str,
+    inputs: Rc<RefCell<Inputs>>,
+  ) -> Self {
+    Self {
+      id: String::from(id),
+      inputs,
+      unbounded_receiver: None,
+    }
+  }
+
+  fn pressed(&mut self) -> bool {
+    if self.unbounded_receiver.is_none() {
+      return false;
+    }
+    matches!(
+      self.unbounded_receiver.as_mut().unwrap().try_next(),
+      Ok(Some(()))
+    )
+  }
+}
+
+impl Component for ViewportComponent {
+  fn make_html(&self) -> String {
+    format!("<button id=\"{}\">Fit world</button>", self.id)
+  }
+}
+
+impl InitializerMut for ViewportComponent {
+  fn initialize(&mut self) {
+    self.unbounded_receiver = add_click_handler_by_id(&self.id);
+  }
+}
+
+impl UpdaterMut for ViewportComponent {
+  fn update(&mut self) {
+    if self.pressed() {
+      self.inputs.borrow_mut().viewport_fit_requested = true;
+    }
+  }
+}
@@ -2,10 +2,10 @@
 //! - Constants for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-11-27
-//! - Updated: 2024-02-23
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -16,32 +16,143 @@ use crate::engine::configuration::Configuration;
 pub static INFO: &str =
   "CroftSoft Evolve v0.11.2-SNAPSHOT Copyright 2022-2024 CroftSoft Inc";
 
+pub const AGING_TICKS_INIT: usize = 300;
 pub const BABY_ENERGY: usize = 10;
 pub const BIRTH_ENERGY: usize = 30;
 pub const BIRTH_ENERGY_COST: usize = 20;
+// Energy lost by a parent beyond the energy given to the offspring
+pub const BIRTH_ENERGY_OVERHEAD: usize = BIRTH_ENERGY_COST - BABY_ENERGY;
+// Most biomass that a location can hold in the biomass food model
+pub const BIOMASS_CAPACITY: usize = 2 * FLORA_ENERGY;
+// A half-full location regrows by the capacity divided by four times this
+pub const BIOMASS_REGROWTH_DIVISOR: usize = 5;
+// Biomass added where food is planted at random or by fertile soil
+pub const BIOMASS_SEED: usize = FLORA_ENERGY / 2;
+// Updates that a contagious blight keeps spreading before it burns out
+pub const BLIGHT_CONTAGION_TICKS: usize = 50;
+pub const BLIGHT_FRACTION_INIT: usize = 50;
+// Percent chance that a contagious blight spreads to each neighboring food
+pub const BLIGHT_SPREAD_RATE: usize = 80;
 pub const BUGS_MAX: usize = LOCATION_COUNT;
+// Mouse movement in CSS pixels that turns a click into a drag
+pub const CANVAS_DRAG_DISTANCE_MIN: f64 = 4.;
+// Canvas width in CSS pixels for which the overlay text sizes were chosen
+pub const CANVAS_WIDTH_NOMINAL: f64 = 600.;
+pub const CLASSIFIER_CLUSTER_COUNT_MAX: usize = 8;
+pub const CLASSIFIER_CLUSTER_PERIOD: usize = 10;
+pub const CLASSIFIER_CLUSTER_RADIUS_INIT: u32 = 2;
+pub const CLASSIFIER_CRUISER_SPEED_MIN_INIT: f64 = 0.70;
+pub const CLASSIFIER_TWIRLIE_SPEED_MAX_INIT: f64 = 0.30;
+// Energy gained by a scavenger from eating a corpse
+pub const CORPSE_ENERGY: usize = 10;
+// Updates until an uneaten corpse rots into fertile soil
+pub const CORPSE_TICKS: usize = 100;
+pub const CROWDING_CAPACITY_INIT: usize = 4;
+pub const CROWDING_COST_INIT: usize = 1;
 pub const EDEN_HEIGHT: usize = 2;
 pub const EDEN_WIDTH: usize = 2;
 pub const EDEN_X0: usize = (SPACE_WIDTH - EDEN_WIDTH) / 2;
-pub const EDEN_X1: usize = EDEN_X0 + EDEN_WIDTH - 1;
 pub const EDEN_Y0: usize = (SPACE_WIDTH - EDEN_WIDTH) / 2;
-pub const EDEN_Y1: usize = EDEN_Y0 + EDEN_HEIGHT - 1;
-pub const FILL_STYLE_BACKGROUND: &str = "black";
+// Percentage by which the environmental cycles swing the food growth rate
+pub const ENVIRONMENT_AMPLITUDE_INIT: usize = 50;
+// Delay before the URL of a download is released so the download can start
+pub const EXPORT_URL_REVOKE_DELAY_MILLIS: i32 = 10_000;
+// Chance per update, one in this many, that bare fertile soil grows food
+pub const FERTILITY_REGROWTH_ODDS: usize = 10;
+// Updates that soil stays fertile after a bug decomposes on or next to it
+pub const FERTILITY_TICKS: usize = 100;
+// Opacity of the food at a location holding the least biomass
+pub const FLORA_ALPHA_MIN: u8 = 48;
 pub const FLORA_ENERGY: usize = 20;
+// Updates between moves of the gardens when they drift or jump
+pub const GARDEN_PERIOD_INIT: usize = 20;
+// Percent chance per update that each location of a new garden is replenished
+pub const GARDEN_REPLENISH_RATE_INIT: usize = 100;
 pub const GENES_MAX: usize = 8;
 pub const FLORA_GROWTH_RATE_INIT: usize = 10;
 pub const FLORA_GROWTH_RATE_MAX: usize = 20;
+pub const GENE_HEAT_MAP_BUCKET_SIZE: usize = 10;
+pub const GENE_HEAT_MAP_COLUMNS_MAX: usize = 100;
+// Canvas pixels per cell above which the grid has a line at every cell
+pub const GRID_CELL_PIXELS_MIN: f64 = 8.;
+// Cells between grid lines when zoomed out
+pub const GRID_SPACING: usize = 10;
+// Width of the heading glyph in world cells
+pub const HEADING_SCALE: f64 = 0.2;
+// Opacity of the tint at the hottest location of a heat map
+pub const HEAT_MAP_ALPHA_MAX: u8 = 192;
+// Initial and maximum time constants of the heat map decay in updates
+pub const HEAT_MAP_WINDOW_INIT: usize = 50;
+pub const HEAT_MAP_WINDOW_MAX: usize = 10_000;
+// Most colors listed in the legend for a categorical color mode
+pub const LEGEND_ENTRIES_MAX: usize = 8;
+// Width in updates of the bins of the lifespan distributions
+pub const LIFESPAN_BIN_WIDTH: usize = 10;
 pub const LOCATION_COUNT: usize = SPACE_HEIGHT * SPACE_WIDTH;
+pub const LOCUS_COUNT: usize = 2 * GENES_MAX;
 pub const MAX_ENERGY: usize = 60;
 pub const MILLIS_PER_SECOND: f64 = 1_000.;
 pub const MOVE_COST: usize = 1;
+pub const MOVE_COST_MAX: usize = 4;
+pub const OVERLAY_LINE_LENGTH_MAX: usize = 56;
 pub const OVERLAY_REFRESH_PERIOD_MILLIS: f64 = 1_000.;
 pub const PAINT_OFFSET: f64 = (1. - PAINT_SCALE) / 2.;
 pub const PAINT_SCALE: f64 = 0.5;
+// Number of timings kept for the rolling minimum, mean, and maximum
+pub const PROFILER_SAMPLES_MAX: usize = 60;
+pub const RGBA_BACKGROUND: [u8; 4] = [
+  0, 0, 0, 255,
+];
+pub const RGBA_CORPSE: [u8; 4] = [
+  160, 82, 45, 255,
+];
+// Okabe-Ito orange and sky blue so that the heat maps suit either palette
+pub const RGBA_DENSITY: [u8; 4] = [
+  230, 159, 0, 255,
+];
+pub const RGBA_FERTILE: [u8; 4] = [
+  96, 64, 32, 128,
+];
+pub const RGBA_FLORA: [u8; 4] = [
+  0, 128, 0, 255,
+];
+pub const RGBA_GARDEN: [u8; 4] = [
+  255, 255, 0, 192,
+];
+pub const RGBA_GRAZING: [u8; 4] = [
+  86, 180, 233, 255,
+];
+pub const RGBA_GRID: [u8; 4] = [
+  255, 255, 255, 48,
+];
+pub const RGBA_HEADING: [u8; 4] = [
+  255, 255, 255, 255,
+];
+pub const RGBA_REGION: [u8; 4] = [
+  0, 255, 255, 192,
+];
+pub const RGBA_SELECTION: [u8; 4] = [
+  255, 255, 255, 255,
+];
 pub const SPACE_HEIGHT: usize = 100;
 pub const SPACE_WIDTH: usize = 100;
+pub const STATISTICS_SAMPLES_MAX: usize = 100_000;
+// Number of previous positions remembered for drawing the trail of a bug
+pub const TRAIL_LENGTH: usize = 8;
+// Width of the trail in world cells
+pub const TRAIL_SCALE: f64 = 0.2;
 pub const UPDATES_PER_SECOND: f64 = 1.;
 pub const UPDATE_PERIOD_MILLIS: f64 = MILLIS_PER_SECOND / UPDATES_PER_SECOND;
+pub const VIEWPORT_ZOOM_MAX: f64 = 20.;
+// Zoom factor per 100 pixels of mouse wheel scrolling
+pub const VIEWPORT_ZOOM_RATE: f64 = 1.25;
+// Growth rate multipliers during a boom and during a drought
+pub const WEATHER_BOOM_FACTOR: f64 = 2.;
+pub const WEATHER_DROUGHT_FACTOR: f64 = 0.;
+// Chance per update, one in this many, that a drought or boom starts
+pub const WEATHER_ODDS: usize = 200;
+// Updates that a drought or boom lasts
+pub const WEATHER_TICKS: usize = 50;
 
 pub const CONFIGURATION: Configuration = Configuration {
   update_period_millis_initial: UPDATE_PERIOD_MILLIS,
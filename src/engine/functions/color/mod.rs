@@ -0,0 +1,133 @@
+// =============================================================================
+//! - Color functions for CroftSoft Evolve for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::models::options::Palette;
+
+// Okabe-Ito with light gray in place of black to show on the background
+const OKABE_ITO: [[u8; 4]; 8] = [
+  [
+    230, 159, 0, 255,
+  ],
+  [
+    86, 180, 233, 255,
+  ],
+  [
+    0, 158, 115, 255,
+  ],
+  [
+    240, 228, 66, 255,
+  ],
+  [
+    0, 114, 178, 255,
+  ],
+  [
+    213, 94, 0, 255,
+  ],
+  [
+    204, 121, 167, 255,
+  ],
+  [
+    187, 187, 187, 255,
+  ],
+];
+
+const VIRIDIS: [[f64; 3]; 5] = [
+  [
+    68., 1., 84.,
+  ],
+  [
+    59., 82., 139.,
+  ],
+  [
+    33., 145., 140.,
+  ],
+  [
+    94., 201., 98.,
+  ],
+  [
+    253., 231., 37.,
+  ],
+];
+
+// A stable color for an identifier such as a genome key or a lineage
+pub fn to_categorical_rgba(
+  palette: Palette,
+  key: usize,
+) -> [u8; 4] {
+  match palette {
+    Palette::ColorBlind => OKABE_ITO[key % OKABE_ITO.len()],
+    Palette::Standard => {
+      // Golden ratio steps keep nearby identifiers far apart in hue
+      let hue: f64 = (key as f64 * 0.618_033_988_75).fract() * 360.;
+      to_rgba_from_hsl(hue, 1., 0.6)
+    },
+  }
+}
+
+// Hue in degrees, saturation and lightness from 0 to 1, as in CSS hsl()
+pub fn to_rgba_from_hsl(
+  hue: f64,
+  saturation: f64,
+  lightness: f64,
+) -> [u8; 4] {
+  let chroma: f64 = (1. - (2. * lightness - 1.).abs()) * saturation;
+  let sector: f64 = hue.rem_euclid(360.) / 60.;
+  let second: f64 = chroma * (1. - (sector % 2. - 1.).abs());
+  let (red, green, blue) = match sector as usize {
+    0 => (chroma, second, 0.),
+    1 => (second, chroma, 0.),
+    2 => (0., chroma, second),
+    3 => (0., second, chroma),
+    4 => (second, 0., chroma),
+    _ => (chroma, 0., second),
+  };
+  let offset: f64 = lightness - chroma / 2.;
+  let to_byte = |value: f64| -> u8 { (255. * (value + offset)).round() as u8 };
+  [
+    to_byte(red),
+    to_byte(green),
+    to_byte(blue),
+    255,
+  ]
+}
+
+// A level from 0 to 1 shown from dim to bright
+pub fn to_sequential_rgba(
+  palette: Palette,
+  level: f64,
+) -> [u8; 4] {
+  let level: f64 = level.clamp(0., 1.);
+  match palette {
+    Palette::ColorBlind => {
+      let position: f64 = level * (VIRIDIS.len() - 1) as f64;
+      let index: usize = (position as usize).min(VIRIDIS.len() - 2);
+      let fraction: f64 = position - index as f64;
+      let mut rgba: [u8; 4] = [
+        0, 0, 0, 255,
+      ];
+      for (channel, value) in rgba.iter_mut().take(3).enumerate() {
+        let low: f64 = VIRIDIS[index][channel];
+        let high: f64 = VIRIDIS[index + 1][channel];
+        *value = (low + (high - low) * fraction).round() as u8;
+      }
+      rgba
+    },
+    Palette::Standard => {
+      // Never fully dark so that the dimmest bugs remain visible
+      let value: u8 = (64. + 191. * level).round() as u8;
+      [
+        value, value, value, 255,
+      ]
+    },
+  }
+}
@@ -0,0 +1,146 @@
+// =============================================================================
+//! - Genetic diversity functions for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::genes::{to_gene_frequencies, to_genome_key};
+use crate::constants::LOCUS_COUNT;
+use crate::models::fauna::Fauna;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Default)]
+pub struct Diversity {
+  pub genotype_count: usize,
+  pub hamming_distance_mean: f64,
+  // Gene diversity 2p(1 - p) of each locus, ordered X0..X7 then Y0..Y7
+  pub heterozygosities: [f64; LOCUS_COUNT],
+  pub shannon_index: f64,
+  pub simpson_index: f64,
+}
+
+impl Diversity {
+  pub fn get_heterozygosity_mean(&self) -> f64 {
+    self.heterozygosities.iter().sum::<f64>() / LOCUS_COUNT as f64
+  }
+}
+
+// The mean pairwise Hamming distance is computed exactly from the per-locus
+// allele counts in linear time so no sampling of pairs is needed.  Sampling
+// would also draw from the seeded simulation random number generator.
+pub fn to_diversity(fauna: &Fauna) -> Diversity {
+  let mut genotype_counts = HashMap::<u16, usize>::new();
+  for index in 0..fauna.len() {
+    if fauna.energies[index] > 0 {
+      *genotype_counts
+        .entry(to_genome_key(&fauna.genes_x[index], &fauna.genes_y[index]))
+        .or_insert(0) += 1;
+    }
+  }
+  let population: usize = genotype_counts.values().sum();
+  if population == 0 {
+    return Diversity::default();
+  }
+  let mut shannon_index: f64 = 0.;
+  let mut simpson_sum: f64 = 0.;
+  for count in genotype_counts.values() {
+    let proportion: f64 = *count as f64 / population as f64;
+    shannon_index -= proportion * proportion.ln();
+    simpson_sum += proportion * proportion;
+  }
+  let gene_frequencies: [f64; LOCUS_COUNT] = to_gene_frequencies(fauna);
+  let mut heterozygosities: [f64; LOCUS_COUNT] = [0.; LOCUS_COUNT];
+  for (heterozygosity, frequency) in
+    heterozygosities.iter_mut().zip(gene_frequencies.iter())
+  {
+    *heterozygosity = 2. * frequency * (1. - frequency);
+  }
+  // Pairs differing at a locus number n1 * n0 out of n(n - 1) / 2 pairs
+  let hamming_distance_mean: f64 = if population < 2 {
+    0.
+  } else {
+    let n: f64 = population as f64;
+    heterozygosities.iter().sum::<f64>() * n / (n - 1.)
+  };
+  Diversity {
+    genotype_count: genotype_counts.len(),
+    hamming_distance_mean,
+    heterozygosities,
+    shannon_index,
+    simpson_index: 1. - simpson_sum,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{to_diversity, Diversity};
+  use crate::constants::{
+    BABY_ENERGY, BIRTH_ENERGY, GENES_MAX, LOCUS_COUNT, MOVE_COST,
+  };
+  use crate::models::bug::Bug;
+  use crate::models::fauna::Fauna;
+
+  const TOLERANCE: f64 = 1e-12;
+
+  fn make_fauna(genomes: &[([bool; GENES_MAX], [bool; GENES_MAX])]) -> Fauna {
+    let mut fauna = Fauna::default();
+    for (position, (genes_x, genes_y)) in genomes.iter().enumerate() {
+      fauna.push(Bug {
+        baby_energy: BABY_ENERGY,
+        birth_energy: BIRTH_ENERGY,
+        birth_tick: 0,
+        energy: BIRTH_ENERGY,
+        genes_x: *genes_x,
+        genes_y: *genes_y,
+        generation: 0,
+        lineage: 0,
+        move_cost: MOVE_COST,
+        position,
+        species: 0,
+      });
+    }
+    fauna
+  }
+
+  #[test]
+  fn test_single_genome() {
+    let genome = ([true; GENES_MAX], [false; GENES_MAX]);
+    let diversity: Diversity = to_diversity(&make_fauna(&[genome; 4]));
+    assert_eq!(diversity.genotype_count, 1);
+    assert!(diversity.shannon_index.abs() < TOLERANCE);
+    assert!(diversity.simpson_index.abs() < TOLERANCE);
+    assert!(diversity.hamming_distance_mean.abs() < TOLERANCE);
+    assert!(diversity.get_heterozygosity_mean().abs() < TOLERANCE);
+  }
+
+  #[test]
+  fn test_two_equal_species() {
+    let genome_0 = ([false; GENES_MAX], [false; GENES_MAX]);
+    let mut genome_1 = genome_0;
+    genome_1.0[0] = true;
+    let mut fauna: Fauna = make_fauna(&[
+      genome_0, genome_1, genome_0, genome_1,
+    ]);
+    let diversity: Diversity = to_diversity(&fauna);
+    assert_eq!(diversity.genotype_count, 2);
+    assert!((diversity.shannon_index - 2_f64.ln()).abs() < TOLERANCE);
+    assert!((diversity.simpson_index - 0.5).abs() < TOLERANCE);
+    // Four of the six pairs differ at the one locus
+    assert!((diversity.hamming_distance_mean - 4. / 6.).abs() < TOLERANCE);
+    assert!((diversity.heterozygosities[0] - 0.5).abs() < TOLERANCE);
+    assert!(diversity.heterozygosities[1..LOCUS_COUNT]
+      .iter()
+      .all(|heterozygosity| heterozygosity.abs() < TOLERANCE));
+    // The dead are not counted
+    fauna.energies[1] = 0;
+    fauna.energies[3] = 0;
+    assert_eq!(to_diversity(&fauna).genotype_count, 1);
+  }
+}
@@ -0,0 +1,497 @@
+// =============================================================================
+//! - Statistics export functions for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::diversity::Diversity;
+use super::genes::to_locus_label;
+use crate::constants::{
+  BABY_ENERGY, BIOMASS_CAPACITY, BIOMASS_REGROWTH_DIVISOR, BIOMASS_SEED,
+  BIRTH_ENERGY, BIRTH_ENERGY_COST, BIRTH_ENERGY_OVERHEAD,
+  BLIGHT_CONTAGION_TICKS, BLIGHT_SPREAD_RATE, BUGS_MAX, CORPSE_ENERGY,
+  CORPSE_TICKS, EDEN_HEIGHT, EDEN_WIDTH, FERTILITY_REGROWTH_ODDS,
+  FERTILITY_TICKS, FLORA_ENERGY, GENES_MAX, LIFESPAN_BIN_WIDTH, MAX_ENERGY,
+  MOVE_COST, MOVE_COST_MAX, SPACE_HEIGHT, SPACE_WIDTH, WEATHER_BOOM_FACTOR,
+  WEATHER_DROUGHT_FACTOR, WEATHER_ODDS, WEATHER_TICKS,
+};
+use crate::models::environment::Environment;
+use crate::models::fauna::{Aging, Crowding, Fauna};
+use crate::models::flora::{Decomposition, Flora, FloraMode};
+use crate::models::garden::GardenMotion;
+use crate::models::random::Random;
+use crate::models::statistics::{LifeHistory, Statistics, StatisticsSample};
+
+// Sums rather than means so that a species without parents needs no blank
+fn make_life_history_values(
+  life_history: &LifeHistory
+) -> Vec<(&'static str, String)> {
+  vec![
+    ("deaths", life_history.deaths.to_string()),
+    ("lifespan_sum", life_history.lifespan_sum.to_string()),
+    ("parents", life_history.parents.to_string()),
+    (
+      "first_spawn_age_sum",
+      life_history.first_spawn_age_sum.to_string(),
+    ),
+    ("offspring_sum", life_history.offspring_sum.to_string()),
+  ]
+}
+
+// Parameter values are all numbers or booleans so they need no quoting
+// A crowding, aging, or cycle period value of zero means that it is off
+pub fn make_parameters(
+  environment: &Environment,
+  fauna: &Fauna,
+  flora: &Flora,
+  random: &Random,
+) -> Vec<(&'static str, String)> {
+  let (aging_cost, aging_lifespan, aging_mortality) = match fauna.aging {
+    Aging::Cost(ticks) => (ticks, 0, 0),
+    Aging::Lifespan(ticks) => (0, ticks, 0),
+    Aging::Mortality(ticks) => (0, 0, ticks),
+    Aging::Off => (0, 0, 0),
+  };
+  let (crowding_capacity, crowding_cost) = match fauna.crowding {
+    Crowding::Capacity(capacity) => (capacity, 0),
+    Crowding::Cost(cost) => (0, cost),
+    Crowding::Off => (0, 0),
+  };
+  vec![
+    ("seed", random.seed.to_string()),
+    ("aging_cost", aging_cost.to_string()),
+    ("aging_lifespan", aging_lifespan.to_string()),
+    ("aging_mortality", aging_mortality.to_string()),
+    ("baby_energy", BABY_ENERGY.to_string()),
+    ("biomass_capacity", BIOMASS_CAPACITY.to_string()),
+    (
+      "biomass_regrowth_divisor",
+      BIOMASS_REGROWTH_DIVISOR.to_string(),
+    ),
+    ("biomass_seed", BIOMASS_SEED.to_string()),
+    ("birth_energy", BIRTH_ENERGY.to_string()),
+    ("blight_contagion_ticks", BLIGHT_CONTAGION_TICKS.to_string()),
+    ("blight_spread_rate", BLIGHT_SPREAD_RATE.to_string()),
+    ("birth_energy_cost", BIRTH_ENERGY_COST.to_string()),
+    ("birth_energy_overhead", BIRTH_ENERGY_OVERHEAD.to_string()),
+    ("bugs_max", BUGS_MAX.to_string()),
+    ("corpse_energy", CORPSE_ENERGY.to_string()),
+    ("corpse_ticks", CORPSE_TICKS.to_string()),
+    ("crowding_capacity", crowding_capacity.to_string()),
+    ("crowding_cost", crowding_cost.to_string()),
+    (
+      "decomposition_corpse",
+      (flora.decomposition == Decomposition::Corpse).to_string(),
+    ),
+    (
+      "decomposition_fertilize",
+      (flora.decomposition == Decomposition::Fertilize).to_string(),
+    ),
+    ("day_period", environment.settings.day_period.to_string()),
+    ("eden_height", EDEN_HEIGHT.to_string()),
+    ("eden_width", EDEN_WIDTH.to_string()),
+    (
+      "environment_amplitude",
+      environment.settings.amplitude.to_string(),
+    ),
+    (
+      "fertility_regrowth_odds",
+      FERTILITY_REGROWTH_ODDS.to_string(),
+    ),
+    ("fertility_ticks", FERTILITY_TICKS.to_string()),
+    (
+      "flora_biomass_mode",
+      (flora.flora_mode == FloraMode::Biomass).to_string(),
+    ),
+    ("flora_energy", FLORA_ENERGY.to_string()),
+    ("flora_growth_rate", flora.flora_growth_rate.to_string()),
+    ("garden_count", flora.gardens.len().to_string()),
+    ("garden_enabled", flora.enabled_garden.to_string()),
+    (
+      "garden_motion_drift",
+      (flora.garden_settings.motion == GardenMotion::Drift).to_string(),
+    ),
+    (
+      "garden_motion_jump",
+      (flora.garden_settings.motion == GardenMotion::Jump).to_string(),
+    ),
+    ("garden_period", flora.garden_settings.period.to_string()),
+    (
+      "garden_replenish_rate",
+      flora.garden_settings.replenish_rate.to_string(),
+    ),
+    ("genes_max", GENES_MAX.to_string()),
+    ("lifespan_bin_width", LIFESPAN_BIN_WIDTH.to_string()),
+    ("max_energy", MAX_ENERGY.to_string()),
+    ("move_cost", MOVE_COST.to_string()),
+    ("move_cost_max", MOVE_COST_MAX.to_string()),
+    ("scavenging", flora.scavenging.to_string()),
+    (
+      "season_period",
+      environment.settings.season_period.to_string(),
+    ),
+    ("space_height", SPACE_HEIGHT.to_string()),
+    ("space_width", SPACE_WIDTH.to_string()),
+    ("traits_heritable", fauna.traits_heritable.to_string()),
+    ("weather_boom_factor", WEATHER_BOOM_FACTOR.to_string()),
+    ("weather_drought_factor", WEATHER_DROUGHT_FACTOR.to_string()),
+    ("weather_odds", WEATHER_ODDS.to_string()),
+    (
+      "weather_random",
+      environment.settings.weather_random.to_string(),
+    ),
+    ("weather_ticks", WEATHER_TICKS.to_string()),
+  ]
+}
+
+fn make_sample_values(
+  sample: &StatisticsSample,
+  species_names: &[String],
+) -> Vec<(String, String)> {
+  let mut values: Vec<(String, String)> = vec![
+    (String::from("tick"), sample.tick.to_string()),
+    (String::from("population"), sample.population.to_string()),
+  ];
+  // Blank for a species first seen after the sample
+  for (index, species_name) in species_names.iter().enumerate() {
+    let count_option: Option<&usize> = sample.species_counts.get(index);
+    values.push((
+      format!("species_{}", species_name.to_lowercase().replace(' ', "_")),
+      count_option.map(usize::to_string).unwrap_or_default(),
+    ));
+  }
+  values.push((String::from("energy_min"), sample.energy_min.to_string()));
+  values.push((String::from("energy_mean"), sample.energy_mean.to_string()));
+  values.push((String::from("energy_max"), sample.energy_max.to_string()));
+  values.push((
+    String::from("baby_energy_mean"),
+    sample.trait_means.baby_energy.to_string(),
+  ));
+  values.push((
+    String::from("birth_energy_mean"),
+    sample.trait_means.birth_energy.to_string(),
+  ));
+  values.push((
+    String::from("move_cost_mean"),
+    sample.trait_means.move_cost.to_string(),
+  ));
+  values.push((
+    String::from("flora_biomass"),
+    sample.flora_biomass.to_string(),
+  ));
+  values.push((
+    String::from("flora_coverage"),
+    sample.flora_coverage.to_string(),
+  ));
+  values.push((
+    String::from("flora_growth_rate"),
+    sample.flora_growth_rate.to_string(),
+  ));
+  values.push((
+    String::from("garden_enabled"),
+    sample.garden_enabled.to_string(),
+  ));
+  values.push((
+    String::from("growth_factor"),
+    sample.growth_factor.to_string(),
+  ));
+  for (locus, frequency) in sample.gene_frequencies.iter().enumerate() {
+    values.push((
+      format!("gene_{}", to_locus_label(locus).to_lowercase()),
+      frequency.to_string(),
+    ));
+  }
+  let diversity: &Diversity = &sample.diversity;
+  values.push((
+    String::from("genotype_count"),
+    diversity.genotype_count.to_string(),
+  ));
+  values.push((
+    String::from("shannon_index"),
+    diversity.shannon_index.to_string(),
+  ));
+  values.push((
+    String::from("simpson_index"),
+    diversity.simpson_index.to_string(),
+  ));
+  values.push((
+    String::from("hamming_distance_mean"),
+    diversity.hamming_distance_mean.to_string(),
+  ));
+  for (locus, heterozygosity) in diversity.heterozygosities.iter().enumerate() {
+    values.push((
+      format!("heterozygosity_{}", to_locus_label(locus).to_lowercase()),
+      heterozygosity.to_string(),
+    ));
+  }
+  values
+}
+
+// Parameters as of the reset are written as leading comment lines, one per
+// parameter, followed by a comment line per later parameter change and a
+// comment line per species life history
+pub fn to_csv(statistics: &Statistics) -> String {
+  let mut lines: Vec<String> = statistics
+    .parameters
+    .iter()
+    .map(|(name, value)| format!("# {}={}", name, value))
+    .collect();
+  for parameter_change in &statistics.parameter_changes {
+    lines.push(format!(
+      "# parameter_change tick={} {}={}",
+      parameter_change.tick, parameter_change.name, parameter_change.value,
+    ));
+  }
+  for (species_name, life_history) in statistics
+    .species_names
+    .iter()
+    .zip(&statistics.life_histories)
+  {
+    let values: Vec<String> = make_life_history_values(life_history)
+      .iter()
+      .map(|(name, value)| format!("{}={}", name, value))
+      .collect();
+    let histogram: Vec<String> = life_history
+      .lifespan_histogram
+      .iter()
+      .map(|count| count.to_string())
+      .collect();
+    lines.push(format!(
+      "# life_history species={} {} lifespan_histogram={}",
+      to_csv_field(&species_name.replace(' ', "_")),
+      values.join(" "),
+      histogram.join(";"),
+    ));
+  }
+  for (index, sample) in statistics.samples.iter().enumerate() {
+    let values = make_sample_values(sample, &statistics.species_names);
+    if index == 0 {
+      let header: Vec<String> =
+        values.iter().map(|(name, _)| to_csv_field(name)).collect();
+      lines.push(header.join(","));
+    }
+    let row: Vec<String> = values
+      .iter()
+      .map(|(_, value)| to_csv_field(value))
+      .collect();
+    lines.push(row.join(","));
+  }
+  lines.push(String::new());
+  lines.join("\n")
+}
+
+pub fn to_json(statistics: &Statistics) -> String {
+  let parameters_json: Vec<String> = statistics
+    .parameters
+    .iter()
+    .map(|(name, value)| to_json_member(name, value))
+    .collect();
+  let parameter_changes_json: Vec<String> = statistics
+    .parameter_changes
+    .iter()
+    .map(|parameter_change| {
+      format!(
+        "{{\"tick\":{},\"name\":{},\"value\":{}}}",
+        parameter_change.tick,
+        to_json_string(parameter_change.name),
+        to_json_value(&parameter_change.value),
+      )
+    })
+    .collect();
+  let samples_json: Vec<String> = statistics
+    .samples
+    .iter()
+    .map(|sample| {
+      let values: Vec<String> =
+        make_sample_values(sample, &statistics.species_names)
+          .iter()
+          .map(|(name, value)| to_json_member(name, value))
+          .collect();
+      format!("{{{}}}", values.join(","))
+    })
+    .collect();
+  let life_histories_json: Vec<String> = statistics
+    .species_names
+    .iter()
+    .zip(&statistics.life_histories)
+    .map(|(species_name, life_history)| {
+      let values: Vec<String> = make_life_history_values(life_history)
+        .iter()
+        .map(|(name, value)| to_json_member(name, value))
+        .collect();
+      let histogram: Vec<String> = life_history
+        .lifespan_histogram
+        .iter()
+        .map(|count| count.to_string())
+        .collect();
+      format!(
+        "{{\"species\":{},{},\"lifespan_histogram\":[{}]}}",
+        to_json_string(species_name),
+        values.join(","),
+        histogram.join(","),
+      )
+    })
+    .collect();
+  format!(
+    concat!(
+      "{{\"parameters\":{{{}}},\"parameter_changes\":[{}],",
+      "\"life_histories\":[{}],\"samples\":[\n{}\n]}}\n",
+    ),
+    parameters_json.join(","),
+    parameter_changes_json.join(","),
+    life_histories_json.join(","),
+    samples_json.join(",\n"),
+  )
+}
+
+// Quoted with any quotes doubled when holding a separator, quote, or newline
+fn to_csv_field(text: &str) -> String {
+  if text.contains([
+    ',', '"', '\n', '\r',
+  ]) {
+    format!("\"{}\"", text.replace('"', "\"\""))
+  } else {
+    String::from(text)
+  }
+}
+
+fn to_json_member(
+  name: &str,
+  value: &str,
+) -> String {
+  format!("{}:{}", to_json_string(name), to_json_value(value))
+}
+
+fn to_json_string(text: &str) -> String {
+  let mut json: String = String::from("\"");
+  for c in text.chars() {
+    match c {
+      '"' => json.push_str("\\\""),
+      '\\' => json.push_str("\\\\"),
+      '\n' => json.push_str("\\n"),
+      '\r' => json.push_str("\\r"),
+      '\t' => json.push_str("\\t"),
+      c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+      c => json.push(c),
+    }
+  }
+  json.push('"');
+  json
+}
+
+// A number or boolean value with a null for a blank, an infinity, or NaN
+fn to_json_value(value: &str) -> &str {
+  match value.parse::<f64>() {
+    Ok(number) if !number.is_finite() => "null",
+    _ if value.is_empty() => "null",
+    _ => value,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{to_csv, to_json, to_json_string};
+  use crate::engine::functions::diversity::Diversity;
+  use crate::engine::functions::traits::TraitMeans;
+  use crate::models::statistics::{Statistics, StatisticsSample};
+  use std::collections::VecDeque;
+
+  fn make_statistics(energy_mean: f64) -> Statistics {
+    let sample = StatisticsSample {
+      diversity: Diversity::default(),
+      energy_max: 0,
+      energy_mean,
+      energy_min: 0,
+      flora_biomass: 0,
+      flora_coverage: 0.,
+      flora_growth_rate: 0,
+      garden_enabled: true,
+      gene_frequencies: Default::default(),
+      growth_factor: 1.,
+      population: 0,
+      species_counts: vec![3],
+      tick: 1,
+      trait_means: TraitMeans::default(),
+    };
+    Statistics {
+      samples: VecDeque::from(vec![sample]),
+      species_names: vec![
+        String::from("Twirlie, \"spun\""),
+        String::from("Cruiser"),
+      ],
+      ..Statistics::default()
+    }
+  }
+
+  #[test]
+  fn test_to_csv_quotes_species_names() {
+    let csv: String = to_csv(&make_statistics(0.));
+    let header: &str = csv.lines().next().unwrap();
+    assert!(header.starts_with(
+      "tick,population,\"species_twirlie,_\"\"spun\"\"\",species_cruiser,"
+    ));
+    let row: &str = csv.lines().nth(1).unwrap();
+    // The species first seen after the sample is blank
+    assert!(row.starts_with("1,0,3,,"));
+  }
+
+  #[test]
+  fn test_parameter_changes() {
+    let mut statistics: Statistics = make_statistics(0.);
+    let make_parameters = |rate: &str| {
+      vec![
+        ("seed", String::from("7")),
+        ("flora_growth_rate", String::from(rate)),
+      ]
+    };
+    statistics.record_parameters(make_parameters("10"), 0);
+    statistics.record_parameters(make_parameters("10"), 1);
+    statistics.record_parameters(make_parameters("20"), 2);
+    statistics.record_parameters(make_parameters("20"), 3);
+    let csv: String = to_csv(&statistics);
+    let lines: Vec<&str> = csv.lines().collect();
+    // The parameters as of the reset rather than as of the export
+    assert_eq!(lines[0], "# seed=7");
+    assert_eq!(lines[1], "# flora_growth_rate=10");
+    assert_eq!(lines[2], "# parameter_change tick=2 flora_growth_rate=20");
+    assert!(!lines[3].starts_with("# parameter_change"));
+    let json: String = to_json(&statistics);
+    assert!(json.contains(concat!(
+      "\"parameters\":{\"seed\":7,\"flora_growth_rate\":10},",
+      "\"parameter_changes\":[",
+      "{\"tick\":2,\"name\":\"flora_growth_rate\",\"value\":20}],"
+    )));
+    statistics.clear();
+    statistics.record_parameters(make_parameters("30"), 0);
+    assert!(statistics.parameter_changes.is_empty());
+    assert_eq!(statistics.parameters[1].1, "30");
+  }
+
+  #[test]
+  fn test_to_json_nulls() {
+    let mut statistics: Statistics = make_statistics(f64::NAN);
+    statistics.record_parameters(vec![("seed", String::from("7"))], 1);
+    let json: String = to_json(&statistics);
+    assert!(json.starts_with("{\"parameters\":{\"seed\":7}"));
+    assert!(json.contains("\"species_twirlie,_\\\"spun\\\"\":3"));
+    assert!(json.contains("\"species_cruiser\":null"));
+    assert!(json.contains("\"energy_mean\":null"));
+    assert!(!json.contains("NaN"));
+  }
+
+  #[test]
+  fn test_to_json_string() {
+    assert_eq!(to_json_string("plain"), "\"plain\"");
+    assert_eq!(to_json_string("a \"b\""), "\"a \\\"b\\\"\"");
+    assert_eq!(to_json_string("a\\b"), "\"a\\\\b\"");
+    assert_eq!(to_json_string("a\nb\tc"), "\"a\\nb\\tc\"");
+    assert_eq!(to_json_string("\u{1}"), "\"\\u0001\"");
+  }
+}
@@ -0,0 +1,188 @@
+// =============================================================================
+//! - Gene statistics functions for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{GENES_MAX, LOCUS_COUNT};
+use crate::models::fauna::Fauna;
+use crate::models::genome::Genome;
+
+fn parse_genes(
+  bits: &str,
+  label: char,
+) -> Result<[bool; GENES_MAX], String> {
+  let mut genes: [bool; GENES_MAX] = [false; GENES_MAX];
+  if bits.chars().count() != GENES_MAX {
+    return Err(format!("{} needs {} genes: {}", label, GENES_MAX, bits));
+  }
+  for (gene, bit) in genes.iter_mut().zip(bits.chars()) {
+    *gene = match bit {
+      '0' => false,
+      '1' => true,
+      _ => return Err(format!("{} genes must be 0 or 1: {}", label, bits)),
+    };
+  }
+  Ok(genes)
+}
+
+// Parses the text form "X:10110010 Y:00011101" written by to_genome_string
+pub fn parse_genome(text: &str) -> Result<Genome, String> {
+  let mut genes_x_option: Option<[bool; GENES_MAX]> = None;
+  let mut genes_y_option: Option<[bool; GENES_MAX]> = None;
+  for token in text.split_whitespace() {
+    let (label, bits) = token
+      .split_once(':')
+      .ok_or_else(|| format!("expected X:genes or Y:genes: {}", token))?;
+    match label {
+      "X" | "x" => genes_x_option = Some(parse_genes(bits, 'X')?),
+      "Y" | "y" => genes_y_option = Some(parse_genes(bits, 'Y')?),
+      _ => return Err(format!("unknown gene label: {}", label)),
+    }
+  }
+  match (genes_x_option, genes_y_option) {
+    (Some(genes_x), Some(genes_y)) => Ok(Genome {
+      genes_x,
+      genes_y,
+    }),
+    _ => Err(format!("expected X and Y genes: {}", text.trim())),
+  }
+}
+
+// One genome per line; blank lines and lines starting with # are skipped
+pub fn parse_genomes(text: &str) -> Result<Vec<Genome>, String> {
+  let mut genomes = Vec::<Genome>::new();
+  for (index, line) in text.lines().enumerate() {
+    let line: &str = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+    let genome: Genome = parse_genome(line)
+      .map_err(|error| format!("line {}: {}", index + 1, error))?;
+    genomes.push(genome);
+  }
+  Ok(genomes)
+}
+
+// Loci X0..X7 are at indices 0..GENES_MAX and Y0..Y7 follow
+pub fn to_gene_frequencies(fauna: &Fauna) -> [f64; LOCUS_COUNT] {
+  let mut sums: [usize; LOCUS_COUNT] = [0; LOCUS_COUNT];
+  let mut bugs_alive: usize = 0;
+  for index in 0..fauna.len() {
+    if fauna.energies[index] == 0 {
+      continue;
+    }
+    bugs_alive += 1;
+    let genes_x: &[bool; GENES_MAX] = &fauna.genes_x[index];
+    let genes_y: &[bool; GENES_MAX] = &fauna.genes_y[index];
+    for i in 0..GENES_MAX {
+      if genes_x[i] {
+        sums[i] += 1;
+      }
+      if genes_y[i] {
+        sums[GENES_MAX + i] += 1;
+      }
+    }
+  }
+  let mut frequencies: [f64; LOCUS_COUNT] = [0.; LOCUS_COUNT];
+  if bugs_alive == 0 {
+    return frequencies;
+  }
+  for (frequency, sum) in frequencies.iter_mut().zip(sums.iter()) {
+    *frequency = *sum as f64 / bugs_alive as f64;
+  }
+  frequencies
+}
+
+// The X genes are the high byte, most significant bit first, then the Y genes
+pub fn to_genome_key(
+  genes_x: &[bool; GENES_MAX],
+  genes_y: &[bool; GENES_MAX],
+) -> u16 {
+  let mut genome_key: u16 = 0;
+  for gene in genes_x.iter().chain(genes_y.iter()) {
+    genome_key = (genome_key << 1) | *gene as u16;
+  }
+  genome_key
+}
+
+pub fn to_genome_string(
+  genes_x: &[bool; GENES_MAX],
+  genes_y: &[bool; GENES_MAX],
+) -> String {
+  let to_bits = |genes: &[bool; GENES_MAX]| -> String {
+    genes
+      .iter()
+      .map(|gene| {
+        if *gene {
+          '1'
+        } else {
+          '0'
+        }
+      })
+      .collect()
+  };
+  format!("X:{} Y:{}", to_bits(genes_x), to_bits(genes_y))
+}
+
+pub fn to_locus_label(locus: usize) -> String {
+  if locus < GENES_MAX {
+    format!("X{}", locus)
+  } else {
+    format!("Y{}", locus - GENES_MAX)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{parse_genome, parse_genomes, to_genome_string};
+  use crate::models::genome::Genome;
+
+  #[test]
+  fn test_parse_genome_bad_digit() {
+    assert!(parse_genome("X:10110012 Y:00011101").is_err());
+  }
+
+  #[test]
+  fn test_parse_genome_missing_axis() {
+    assert!(parse_genome("X:10110010").is_err());
+    assert!(parse_genome("Y:00011101").is_err());
+  }
+
+  #[test]
+  fn test_parse_genome_round_trip() {
+    let text = "X:10110010 Y:00011101";
+    let genome: Genome = parse_genome(text).unwrap();
+    assert_eq!(to_genome_string(&genome.genes_x, &genome.genes_y), text);
+  }
+
+  #[test]
+  fn test_parse_genome_wrong_length() {
+    assert!(parse_genome("X:1011001 Y:00011101").is_err());
+    assert!(parse_genome("X:10110010 Y:000111010").is_err());
+  }
+
+  #[test]
+  fn test_parse_genomes_skips_comments_and_blank_lines() {
+    let text = "# founders\n\nX:10110010 Y:00011101\n  \n# more\n\
+      X:00000000 Y:11111111\n";
+    let genomes: Vec<Genome> = parse_genomes(text).unwrap();
+    assert_eq!(genomes.len(), 2);
+    assert_eq!(
+      to_genome_string(&genomes[0].genes_x, &genomes[0].genes_y),
+      "X:10110010 Y:00011101"
+    );
+    assert_eq!(
+      to_genome_string(&genomes[1].genes_x, &genomes[1].genes_y),
+      "X:00000000 Y:11111111"
+    );
+    assert!(parse_genomes("X:10110010 Y:00011101\nX:2").is_err());
+  }
+}
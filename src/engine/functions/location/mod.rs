@@ -2,9 +2,9 @@
 //! - Location coordinate conversion functions for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 1996-2022 [`CroftSoft Inc`]
+//! - Copyright: &copy; 1996-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
-//! - Rust version: 2022-12-18
+//! - Rust version: 2026-10-19
 //! - Rust since: 2022-12-10
 //! - Java version: 2008-04-19
 //! - Java since: 1996-09-01
@@ -18,7 +18,7 @@
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
-use crate::constants::SPACE_WIDTH;
+use crate::constants::{SPACE_HEIGHT, SPACE_WIDTH};
 
 pub fn to_index_from_xy(
   x: usize,
@@ -27,6 +27,46 @@ pub fn to_index_from_xy(
   SPACE_WIDTH * y + x
 }
 
+// Part of the way from one location to another taking the short way around
+// the torus.  The result can be outside of the world by less than one cell.
+pub fn to_interpolated_xy(
+  index_from: usize,
+  index_to: usize,
+  fraction: f64,
+) -> (f64, f64) {
+  let delta_x: isize = to_wrapped_delta(
+    to_x_from_index(index_from),
+    to_x_from_index(index_to),
+    SPACE_WIDTH,
+  );
+  let delta_y: isize = to_wrapped_delta(
+    to_y_from_index(index_from),
+    to_y_from_index(index_to),
+    SPACE_HEIGHT,
+  );
+  (
+    to_x_from_index(index_from) as f64 + fraction * delta_x as f64,
+    to_y_from_index(index_from) as f64 + fraction * delta_y as f64,
+  )
+}
+
+// The shortest signed distance between two coordinates on a wrapped axis
+pub fn to_wrapped_delta(
+  from: usize,
+  to: usize,
+  size: usize,
+) -> isize {
+  let delta: isize = to as isize - from as isize;
+  let size: isize = size as isize;
+  if delta > size / 2 {
+    delta - size
+  } else if delta < -size / 2 {
+    delta + size
+  } else {
+    delta
+  }
+}
+
 pub fn to_x_from_index(index: usize) -> usize {
   index % SPACE_WIDTH
 }
@@ -34,3 +74,25 @@ pub fn to_x_from_index(index: usize) -> usize {
 pub fn to_y_from_index(index: usize) -> usize {
   index / SPACE_WIDTH
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_to_interpolated_xy_wraps() {
+    let index_from: usize = to_index_from_xy(SPACE_WIDTH - 1, 0);
+    let index_to: usize = to_index_from_xy(0, SPACE_HEIGHT - 1);
+    let (x, y) = to_interpolated_xy(index_from, index_to, 0.5);
+    assert_eq!(x, SPACE_WIDTH as f64 - 0.5);
+    assert_eq!(y, -0.5);
+  }
+
+  #[test]
+  fn test_to_wrapped_delta() {
+    assert_eq!(to_wrapped_delta(3, 4, 100), 1);
+    assert_eq!(to_wrapped_delta(4, 3, 100), -1);
+    assert_eq!(to_wrapped_delta(99, 0, 100), 1);
+    assert_eq!(to_wrapped_delta(0, 99, 100), -1);
+  }
+}
@@ -1 +1,8 @@
+pub mod color;
+pub mod diversity;
+pub mod export;
+pub mod genes;
 pub mod location;
+pub mod species;
+pub mod traits;
+pub mod web_sys;
@@ -0,0 +1,366 @@
+// =============================================================================
+//! - Species classification functions for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::color::to_rgba_from_hsl;
+use super::genes::to_genome_key;
+use crate::constants::{CLASSIFIER_CLUSTER_COUNT_MAX, GENES_MAX};
+use crate::models::classifier::{
+  Classifier, ClassifierMode, ClassifierSettings, SpeciesClass,
+};
+use crate::models::fauna::Fauna;
+use core::f64::consts::FRAC_PI_4;
+use std::collections::HashMap;
+
+const CLASS_INDEX_CRUISER: usize = 2;
+const CLASS_INDEX_NORMAL: usize = 1;
+const CLASS_INDEX_TWIRLIE: usize = 0;
+
+// Compass sectors counter-clockwise from east; screen y increases southward
+const HEADINGS: [(&str, [u8; 4]); 8] = [
+  (
+    "E",
+    [
+      255, 0, 0, 255,
+    ],
+  ),
+  (
+    "NE",
+    [
+      255, 165, 0, 255,
+    ],
+  ),
+  (
+    "N",
+    [
+      255, 255, 0, 255,
+    ],
+  ),
+  (
+    "NW",
+    [
+      255, 255, 255, 255,
+    ],
+  ),
+  (
+    "W",
+    [
+      0, 255, 255, 255,
+    ],
+  ),
+  (
+    "SW",
+    [
+      255, 192, 203, 255,
+    ],
+  ),
+  (
+    "S",
+    [
+      250, 128, 114, 255,
+    ],
+  ),
+  (
+    "SE",
+    [
+      255, 215, 0, 255,
+    ],
+  ),
+];
+
+pub fn classify(
+  classifier: &Classifier,
+  genes_x: &[bool; GENES_MAX],
+  genes_y: &[bool; GENES_MAX],
+) -> usize {
+  let settings: &ClassifierSettings = &classifier.settings;
+  if settings.mode == ClassifierMode::Cluster {
+    let genome_key: u16 = to_genome_key(genes_x, genes_y);
+    return classifier
+      .representatives
+      .iter()
+      .position(|representative| {
+        (representative ^ genome_key).count_ones() <= settings.cluster_radius
+      })
+      .unwrap_or(classifier.representatives.len());
+  }
+  let (x_sum, y_sum) = to_net_movement(genes_x, genes_y);
+  let unscaled_speed: f64 =
+    ((x_sum as f64).powi(2) + (y_sum as f64).powi(2)).sqrt();
+  let scaling_factor: f64 = (2.0 * ((GENES_MAX as f64).powi(2))).sqrt();
+  let speed: f64 = unscaled_speed / scaling_factor;
+  if speed <= settings.twirlie_speed_max {
+    return CLASS_INDEX_TWIRLIE;
+  }
+  if speed < settings.cruiser_speed_min {
+    return CLASS_INDEX_NORMAL;
+  }
+  if settings.mode == ClassifierMode::Speed {
+    return CLASS_INDEX_CRUISER;
+  }
+  let angle: f64 = (-y_sum as f64).atan2(x_sum as f64);
+  let sector: isize = (angle / FRAC_PI_4).round() as isize;
+  CLASS_INDEX_CRUISER + sector.rem_euclid(HEADINGS.len() as isize) as usize
+}
+
+pub fn make_classes(classifier: &Classifier) -> Vec<SpeciesClass> {
+  match classifier.settings.mode {
+    ClassifierMode::Cluster => {
+      make_cluster_classes(&classifier.representatives)
+    },
+    ClassifierMode::Speed => make_speed_classes(),
+    ClassifierMode::SpeedHeading => make_speed_heading_classes(),
+  }
+}
+
+// The trailing class holds genomes too far from every representative
+fn make_cluster_classes(representatives: &[u16]) -> Vec<SpeciesClass> {
+  let mut classes: Vec<SpeciesClass> = representatives
+    .iter()
+    .map(|representative| {
+      // Spread the hues but skip the greens used for the food
+      let hue: u32 = (*representative as u32 * 137 % 270 + 150) % 360;
+      SpeciesClass {
+        color: to_rgba_from_hsl(hue as f64, 1., 0.6),
+        name: format!("Clade {:04x}", representative),
+      }
+    })
+    .collect();
+  classes.push(SpeciesClass {
+    color: [
+      128, 128, 128, 255,
+    ],
+    name: String::from("Other"),
+  });
+  classes
+}
+
+// Greedy clustering of the genotypes from the most to the least common
+pub fn make_representatives(
+  fauna: &Fauna,
+  cluster_radius: u32,
+) -> Vec<u16> {
+  let mut genome_counts = HashMap::<u16, usize>::new();
+  for index in 0..fauna.len() {
+    if fauna.energies[index] > 0 {
+      *genome_counts
+        .entry(to_genome_key(&fauna.genes_x[index], &fauna.genes_y[index]))
+        .or_insert(0) += 1;
+    }
+  }
+  let mut genomes: Vec<(u16, usize)> = genome_counts.into_iter().collect();
+  genomes.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+  let mut representatives = Vec::<u16>::new();
+  for (genome_key, _count) in genomes {
+    if representatives.len() >= CLASSIFIER_CLUSTER_COUNT_MAX {
+      break;
+    }
+    let clustered: bool = representatives.iter().any(|representative| {
+      (representative ^ genome_key).count_ones() <= cluster_radius
+    });
+    if !clustered {
+      representatives.push(genome_key);
+    }
+  }
+  representatives
+}
+
+pub fn make_speed_classes() -> Vec<SpeciesClass> {
+  vec![
+    SpeciesClass {
+      color: [
+        0, 0, 255, 255,
+      ],
+      name: String::from("Twirlie"),
+    },
+    SpeciesClass {
+      color: [
+        255, 0, 255, 255,
+      ],
+      name: String::from("Normal"),
+    },
+    SpeciesClass {
+      color: [
+        255, 0, 0, 255,
+      ],
+      name: String::from("Cruiser"),
+    },
+  ]
+}
+
+fn make_speed_heading_classes() -> Vec<SpeciesClass> {
+  let mut classes: Vec<SpeciesClass> = make_speed_classes();
+  classes.truncate(CLASS_INDEX_CRUISER);
+  for (heading, color) in HEADINGS {
+    classes.push(SpeciesClass {
+      color,
+      name: format!("Cruiser {}", heading),
+    });
+  }
+  classes
+}
+
+// Net displacement per gene cycle, positive toward the east and the south
+pub fn to_net_movement(
+  genes_x: &[bool; GENES_MAX],
+  genes_y: &[bool; GENES_MAX],
+) -> (isize, isize) {
+  let mut x_sum: isize = 0;
+  let mut y_sum: isize = 0;
+  for i in 0..GENES_MAX {
+    if genes_x[i] {
+      x_sum += 1;
+    } else {
+      x_sum -= 1;
+    }
+    if genes_y[i] {
+      y_sum += 1;
+    } else {
+      y_sum -= 1;
+    }
+  }
+  (x_sum, y_sum)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{classify, make_classes, make_representatives};
+  use crate::constants::{BABY_ENERGY, BIRTH_ENERGY, GENES_MAX, MOVE_COST};
+  use crate::engine::functions::genes::to_genome_key;
+  use crate::models::bug::Bug;
+  use crate::models::classifier::{Classifier, ClassifierMode};
+  use crate::models::fauna::Fauna;
+
+  // Genes with the given net movement along one axis
+  fn make_genes(net_movement: isize) -> [bool; GENES_MAX] {
+    let mut genes: [bool; GENES_MAX] = [false; GENES_MAX];
+    let count: usize = ((net_movement + GENES_MAX as isize) / 2) as usize;
+    for gene in genes.iter_mut().take(count) {
+      *gene = true;
+    }
+    genes
+  }
+
+  fn to_class_name(
+    classifier: &Classifier,
+    x_sum: isize,
+    y_sum: isize,
+  ) -> String {
+    let index: usize =
+      classify(classifier, &make_genes(x_sum), &make_genes(y_sum));
+    make_classes(classifier).swap_remove(index).name
+  }
+
+  #[test]
+  fn test_cluster() {
+    let near: [bool; GENES_MAX] = make_genes(2);
+    let far: [bool; GENES_MAX] = make_genes(-8);
+    let mut common: [bool; GENES_MAX] = near;
+    common[GENES_MAX - 1] = true;
+    let mut fauna = Fauna::default();
+    for (position, genes_x) in [
+      common, common, near, far, far, far,
+    ]
+    .iter()
+    .enumerate()
+    {
+      fauna.push(Bug {
+        baby_energy: BABY_ENERGY,
+        birth_energy: BIRTH_ENERGY,
+        birth_tick: 0,
+        energy: BIRTH_ENERGY,
+        genes_x: *genes_x,
+        genes_y: [false; GENES_MAX],
+        generation: 0,
+        lineage: 0,
+        move_cost: MOVE_COST,
+        position,
+        species: 0,
+      });
+    }
+    let mut classifier = Classifier::default();
+    classifier.settings.mode = ClassifierMode::Cluster;
+    classifier.representatives = make_representatives(&fauna, 1);
+    // The most common genotype first and the near one absorbed by the common
+    assert_eq!(
+      classifier.representatives,
+      vec![
+        to_genome_key(&far, &[false; GENES_MAX]),
+        to_genome_key(&common, &[false; GENES_MAX]),
+      ]
+    );
+    assert_eq!(classify(&classifier, &far, &[false; GENES_MAX]), 0);
+    assert_eq!(classify(&classifier, &near, &[false; GENES_MAX]), 1);
+    // Beyond the radius of every representative
+    assert_eq!(classify(&classifier, &far, &[true; GENES_MAX]), 2);
+    assert_eq!(make_classes(&classifier)[2].name, "Other");
+  }
+
+  #[test]
+  fn test_headings() {
+    let mut classifier = Classifier::default();
+    classifier.settings.mode = ClassifierMode::SpeedHeading;
+    // Screen y increases southward
+    for (x_sum, y_sum, name) in [
+      (8, 0, "Cruiser E"),
+      (6, -6, "Cruiser NE"),
+      (0, -8, "Cruiser N"),
+      (-6, -6, "Cruiser NW"),
+      (-8, 0, "Cruiser W"),
+      (-6, 6, "Cruiser SW"),
+      (0, 8, "Cruiser S"),
+      (6, 6, "Cruiser SE"),
+      (8, 2, "Cruiser E"),
+      (8, -4, "Cruiser NE"),
+      (0, 0, "Twirlie"),
+      (4, 4, "Normal"),
+    ] {
+      assert_eq!(to_class_name(&classifier, x_sum, y_sum), name);
+    }
+  }
+
+  #[test]
+  fn test_speed_matches_baseline() {
+    let classifier = Classifier::default();
+    let scaling_factor: f64 = (2.0 * ((GENES_MAX as f64).powi(2))).sqrt();
+    for x_sum in (-8..=8).step_by(2) {
+      for y_sum in (-8..=8).step_by(2) {
+        let speed: f64 = ((x_sum as f64).powi(2) + (y_sum as f64).powi(2))
+          .sqrt()
+          / scaling_factor;
+        // The fixed thresholds from before the classifier settings
+        let name: &str = if speed <= 0.30 {
+          "Twirlie"
+        } else if speed >= 0.70 {
+          "Cruiser"
+        } else {
+          "Normal"
+        };
+        assert_eq!(to_class_name(&classifier, x_sum, y_sum), name);
+      }
+    }
+  }
+
+  #[test]
+  fn test_speed_threshold_boundaries() {
+    let mut classifier = Classifier::default();
+    let speed: f64 = 4. / (2.0 * ((GENES_MAX as f64).powi(2))).sqrt();
+    assert_eq!(to_class_name(&classifier, 4, 0), "Normal");
+    // The twirlie maximum is inclusive
+    classifier.settings.twirlie_speed_max = speed;
+    assert_eq!(to_class_name(&classifier, 4, 0), "Twirlie");
+    // The cruiser minimum is inclusive
+    classifier.settings.twirlie_speed_max = 0.;
+    classifier.settings.cruiser_speed_min = speed;
+    assert_eq!(to_class_name(&classifier, 4, 0), "Cruiser");
+  }
+}
@@ -0,0 +1,69 @@
+// =============================================================================
+//! - Life-history trait functions for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{MAX_ENERGY, MOVE_COST_MAX};
+use crate::models::fauna::Fauna;
+
+// Number of living bugs at each value of each life-history trait
+pub struct TraitHistograms {
+  pub baby_energies: [usize; MAX_ENERGY + 1],
+  pub birth_energies: [usize; MAX_ENERGY + 1],
+  pub move_costs: [usize; MOVE_COST_MAX + 1],
+}
+
+// Mean value of each life-history trait over the living bugs
+#[derive(Clone, Copy, Default)]
+pub struct TraitMeans {
+  pub baby_energy: f64,
+  pub birth_energy: f64,
+  pub move_cost: f64,
+}
+
+pub fn to_trait_histograms(fauna: &Fauna) -> TraitHistograms {
+  let mut trait_histograms = TraitHistograms {
+    baby_energies: [0; MAX_ENERGY + 1],
+    birth_energies: [0; MAX_ENERGY + 1],
+    move_costs: [0; MOVE_COST_MAX + 1],
+  };
+  for index in 0..fauna.len() {
+    if fauna.energies[index] > 0 {
+      trait_histograms.baby_energies[fauna.baby_energies[index]] += 1;
+      trait_histograms.birth_energies[fauna.birth_energies[index]] += 1;
+      trait_histograms.move_costs[fauna.move_costs[index]] += 1;
+    }
+  }
+  trait_histograms
+}
+
+pub fn to_trait_means(fauna: &Fauna) -> TraitMeans {
+  let mut population: usize = 0;
+  let mut sums: [usize; 3] = [0; 3];
+  for index in 0..fauna.len() {
+    if fauna.energies[index] > 0 {
+      population += 1;
+      sums[0] += fauna.baby_energies[index];
+      sums[1] += fauna.birth_energies[index];
+      sums[2] += fauna.move_costs[index];
+    }
+  }
+  if population == 0 {
+    return TraitMeans::default();
+  }
+  let [baby_energy, birth_energy, move_cost] =
+    sums.map(|sum| sum as f64 / population as f64);
+  TraitMeans {
+    baby_energy,
+    birth_energy,
+    move_cost,
+  }
+}
@@ -0,0 +1,51 @@
+// =============================================================================
+//! - web-sys functions for CroftSoft Evolve for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+// Complements the handlers in com_croftsoft_lib_animation::web_sys
+
+use futures::channel::mpsc::{unbounded, UnboundedReceiver};
+use wasm_bindgen::prelude::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::{window, Event, EventTarget};
+
+// The default action is prevented in the listener itself because it cannot be
+// prevented later when the event is polled from the receiver
+pub fn add_event_listener<E: JsCast + 'static>(
+  event_target: &EventTarget,
+  event_type: &str,
+  prevent_default: bool,
+) -> UnboundedReceiver<E> {
+  let (mut sender, receiver) = unbounded();
+  let event_closure = move |event: Event| {
+    if prevent_default {
+      event.prevent_default();
+    }
+    let _result: Result<(), futures::channel::mpsc::SendError> =
+      sender.start_send(event.unchecked_into::<E>());
+  };
+  let event_closure_box: Box<dyn FnMut(Event)> = Box::new(event_closure);
+  let closure: Closure<dyn FnMut(Event)> = Closure::wrap(event_closure_box);
+  event_target
+    .add_event_listener_with_callback(
+      event_type,
+      closure.as_ref().unchecked_ref(),
+    )
+    .unwrap();
+  closure.forget();
+  receiver
+}
+
+// High resolution time in milliseconds for profiling
+pub fn get_performance_now() -> f64 {
+  window().unwrap().performance().unwrap().now()
+}
@@ -1,4 +1,7 @@
 pub mod configuration;
 pub mod functions;
 pub mod looper;
+pub mod occupancy;
+pub mod raster;
 pub mod traits;
+pub mod viewport;
@@ -0,0 +1,96 @@
+// =============================================================================
+//! - Occupancy index for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+// Pure Rust so that it can be unit tested natively without a browser
+
+// The indices of the bugs at each location grouped by location so that the
+// occupants of a location can be found without a search of the population
+pub struct Occupancy {
+  // Indices into the fauna columns sorted by location
+  bug_indices: Vec<usize>,
+  // Offset into the bug indices of the first bug at each location followed
+  // by the number of bugs so that the next start is the end of a location
+  starts: Vec<usize>,
+}
+
+impl Occupancy {
+  pub fn get_bug_indices(
+    &self,
+    location: usize,
+  ) -> &[usize] {
+    if location + 1 >= self.starts.len() {
+      return &[];
+    }
+    &self.bug_indices[self.starts[location]..self.starts[location + 1]]
+  }
+
+  pub fn new(location_count: usize) -> Self {
+    Self {
+      bug_indices: Vec::new(),
+      starts: vec![0; location_count + 1],
+    }
+  }
+
+  // A counting sort which is linear in the number of bugs and locations
+  pub fn rebuild(
+    &mut self,
+    positions: &[usize],
+  ) {
+    self.starts.iter_mut().for_each(|start| *start = 0);
+    for position in positions.iter() {
+      self.starts[*position] += 1;
+    }
+    // The end of each location is the running total of the counts
+    let mut end: usize = 0;
+    for start in self.starts.iter_mut() {
+      end += *start;
+      *start = end;
+    }
+    self.bug_indices.resize(positions.len(), 0);
+    // Filled from the back so that each end moves down to the start
+    for (bug_index, position) in positions.iter().enumerate().rev() {
+      self.starts[*position] -= 1;
+      self.bug_indices[self.starts[*position]] = bug_index;
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Occupancy;
+
+  #[test]
+  fn test_get_bug_indices() {
+    let mut occupancy = Occupancy::new(4);
+    occupancy.rebuild(&[
+      2, 0, 2, 3, 2,
+    ]);
+    assert_eq!(occupancy.get_bug_indices(0), &[1]);
+    assert!(occupancy.get_bug_indices(1).is_empty());
+    assert_eq!(occupancy.get_bug_indices(2), &[0, 2, 4]);
+    assert_eq!(occupancy.get_bug_indices(3), &[3]);
+    assert!(occupancy.get_bug_indices(4).is_empty());
+  }
+
+  #[test]
+  fn test_rebuild() {
+    let mut occupancy = Occupancy::new(3);
+    occupancy.rebuild(&[
+      1, 1, 2,
+    ]);
+    occupancy.rebuild(&[0]);
+    assert_eq!(occupancy.get_bug_indices(0), &[0]);
+    assert!(occupancy.get_bug_indices(1).is_empty());
+    assert!(occupancy.get_bug_indices(2).is_empty());
+  }
+}
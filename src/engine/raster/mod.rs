@@ -0,0 +1,173 @@
+// =============================================================================
+//! - RGBA pixel buffer rasterizer for CroftSoft Evolve for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+// Pure Rust so that it can be unit tested natively without a browser
+
+pub struct Raster {
+  // Red, green, blue, and alpha bytes for each pixel in row-major order
+  pub data: Vec<u8>,
+  pub height: usize,
+  pub width: usize,
+}
+
+impl Raster {
+  pub fn fill(
+    &mut self,
+    rgba: [u8; 4],
+  ) {
+    for pixel in self.data.chunks_exact_mut(4) {
+      pixel.copy_from_slice(&rgba);
+    }
+  }
+
+  // Clipped to the raster so partially visible rectangles are allowed.
+  // Colors that are not opaque are blended over the existing pixels.
+  pub fn fill_rect(
+    &mut self,
+    x: isize,
+    y: isize,
+    width: usize,
+    height: usize,
+    rgba: [u8; 4],
+  ) {
+    let x0: usize = x.clamp(0, self.width as isize) as usize;
+    let y0: usize = y.clamp(0, self.height as isize) as usize;
+    let x1: usize =
+      (x.saturating_add(width as isize)).clamp(0, self.width as isize) as usize;
+    let y1: usize = (y.saturating_add(height as isize))
+      .clamp(0, self.height as isize) as usize;
+    if x0 >= x1 {
+      return;
+    }
+    let alpha: u32 = rgba[3] as u32;
+    for row in y0..y1 {
+      let start: usize = 4 * (row * self.width + x0);
+      let end: usize = 4 * (row * self.width + x1);
+      for pixel in self.data[start..end].chunks_exact_mut(4) {
+        if alpha == 255 {
+          pixel.copy_from_slice(&rgba);
+          continue;
+        }
+        for channel in 0..3 {
+          pixel[channel] = ((rgba[channel] as u32 * alpha
+            + pixel[channel] as u32 * (255 - alpha)
+            + 127)
+            / 255) as u8;
+        }
+      }
+    }
+  }
+
+  pub fn new(
+    width: usize,
+    height: usize,
+    rgba: [u8; 4],
+  ) -> Self {
+    let mut raster = Self {
+      data: vec![0; 4 * width * height],
+      height,
+      width,
+    };
+    raster.fill(rgba);
+    raster
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Raster;
+
+  const BLACK: [u8; 4] = [
+    0, 0, 0, 255,
+  ];
+  const GREEN: [u8; 4] = [
+    0, 128, 0, 255,
+  ];
+
+  fn get_pixel(
+    raster: &Raster,
+    x: usize,
+    y: usize,
+  ) -> [u8; 4] {
+    let start: usize = 4 * (y * raster.width + x);
+    let mut rgba: [u8; 4] = [0; 4];
+    rgba.copy_from_slice(&raster.data[start..start + 4]);
+    rgba
+  }
+
+  #[test]
+  fn test_fill_rect() {
+    let mut raster = Raster::new(6, 4, BLACK);
+    raster.fill_rect(1, 1, 3, 2, GREEN);
+    for y in 0..4 {
+      for x in 0..6 {
+        let inside: bool = (1..4).contains(&x) && (1..3).contains(&y);
+        let expected: [u8; 4] = if inside {
+          GREEN
+        } else {
+          BLACK
+        };
+        assert_eq!(get_pixel(&raster, x, y), expected, "({}, {})", x, y);
+      }
+    }
+  }
+
+  #[test]
+  fn test_fill_rect_blended() {
+    let mut raster = Raster::new(2, 1, GREEN);
+    raster.fill_rect(
+      0,
+      0,
+      1,
+      1,
+      [
+        255, 0, 0, 0,
+      ],
+    );
+    assert_eq!(get_pixel(&raster, 0, 0), GREEN);
+    raster.fill_rect(
+      0,
+      0,
+      2,
+      1,
+      [
+        255, 0, 255, 128,
+      ],
+    );
+    assert_eq!(get_pixel(&raster, 0, 0), [128, 64, 128, 255]);
+    assert_eq!(get_pixel(&raster, 1, 0), [128, 64, 128, 255]);
+  }
+
+  #[test]
+  fn test_fill_rect_clipped() {
+    let mut raster = Raster::new(4, 4, BLACK);
+    raster.fill_rect(-2, 3, 4, 5, GREEN);
+    raster.fill_rect(10, 10, 2, 2, GREEN);
+    raster.fill_rect(-5, -5, 2, 2, GREEN);
+    let green_count: usize = raster
+      .data
+      .chunks_exact(4)
+      .filter(|pixel| *pixel == GREEN)
+      .count();
+    assert_eq!(green_count, 2);
+    assert_eq!(get_pixel(&raster, 0, 3), GREEN);
+    assert_eq!(get_pixel(&raster, 1, 3), GREEN);
+  }
+
+  #[test]
+  fn test_new() {
+    let raster = Raster::new(3, 2, GREEN);
+    assert_eq!(raster.data.len(), 3 * 2 * 4);
+    assert!(raster.data.chunks_exact(4).all(|pixel| pixel == GREEN));
+  }
+}
@@ -0,0 +1,186 @@
+// =============================================================================
+//! - Viewport transform for CroftSoft Evolve for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+// Shared by the world painters and the canvas hit-testing.  Pure Rust so that
+// it can be unit tested natively without a browser.
+
+use crate::constants::{SPACE_HEIGHT, SPACE_WIDTH};
+use crate::engine::raster::Raster;
+use crate::models::viewport::Viewport;
+
+pub struct ViewTransform {
+  // World coordinates of the upper left corner of the canvas
+  origin_x: f64,
+  origin_y: f64,
+  // Canvas pixels per world cell
+  scale_x: f64,
+  scale_y: f64,
+}
+
+impl ViewTransform {
+  // Paints every wrapped copy of a world rectangle that could be visible
+  pub fn fill_rect(
+    &self,
+    raster: &mut Raster,
+    world_x: f64,
+    world_y: f64,
+    world_width: f64,
+    world_height: f64,
+    rgba: [u8; 4],
+  ) {
+    let width: usize = (world_width * self.scale_x).trunc().max(1.) as usize;
+    let height: usize = (world_height * self.scale_y).trunc().max(1.) as usize;
+    let (offset_x, wrapped_x_option) =
+      Self::to_offsets(world_x - self.origin_x, world_width, SPACE_WIDTH);
+    let (offset_y, wrapped_y_option) =
+      Self::to_offsets(world_y - self.origin_y, world_height, SPACE_HEIGHT);
+    for x in [
+      Some(offset_x),
+      wrapped_x_option,
+    ]
+    .iter()
+    .flatten()
+    {
+      for y in [
+        Some(offset_y),
+        wrapped_y_option,
+      ]
+      .iter()
+      .flatten()
+      {
+        raster.fill_rect(
+          (self.scale_x * x).trunc() as isize,
+          (self.scale_y * y).trunc() as isize,
+          width,
+          height,
+          rgba,
+        );
+      }
+    }
+  }
+
+  pub fn new(
+    viewport: &Viewport,
+    canvas_width: f64,
+    canvas_height: f64,
+  ) -> Self {
+    let origin_x: f64 =
+      viewport.center_x - SPACE_WIDTH as f64 / (2. * viewport.zoom);
+    let origin_y: f64 =
+      viewport.center_y - SPACE_HEIGHT as f64 / (2. * viewport.zoom);
+    let scale_x: f64 = canvas_width * viewport.zoom / SPACE_WIDTH as f64;
+    let scale_y: f64 = canvas_height * viewport.zoom / SPACE_HEIGHT as f64;
+    Self {
+      origin_x,
+      origin_y,
+      scale_x,
+      scale_y,
+    }
+  }
+
+  // The offset from the origin wrapped into the world and, when the span
+  // crosses the far edge, the copy of it entering from the near edge
+  fn to_offsets(
+    offset: f64,
+    length: f64,
+    size: usize,
+  ) -> (f64, Option<f64>) {
+    let size: f64 = size as f64;
+    let wrapped: f64 = offset.rem_euclid(size);
+    if wrapped + length > size {
+      (wrapped, Some(wrapped - size))
+    } else {
+      (wrapped, None)
+    }
+  }
+
+  // Wrapped into the world since the view of the torus can straddle an edge
+  pub fn to_world_xy(
+    &self,
+    canvas_x: f64,
+    canvas_y: f64,
+  ) -> (f64, f64) {
+    let world_x: f64 = self.origin_x + canvas_x / self.scale_x;
+    let world_y: f64 = self.origin_y + canvas_y / self.scale_y;
+    (
+      world_x.rem_euclid(SPACE_WIDTH as f64),
+      world_y.rem_euclid(SPACE_HEIGHT as f64),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::ViewTransform;
+  use crate::constants::{SPACE_HEIGHT, SPACE_WIDTH};
+  use crate::engine::raster::Raster;
+  use crate::models::viewport::Viewport;
+
+  const BLACK: [u8; 4] = [
+    0, 0, 0, 255,
+  ];
+  const RED: [u8; 4] = [
+    255, 0, 0, 255,
+  ];
+
+  fn count_red(raster: &Raster) -> usize {
+    raster
+      .data
+      .chunks_exact(4)
+      .filter(|pixel| *pixel == RED)
+      .count()
+  }
+
+  #[test]
+  fn test_fill_rect_wraps_across_edges() {
+    let viewport = Viewport {
+      center_x: 0.,
+      center_y: 0.,
+      zoom: 1.,
+    };
+    let mut raster = Raster::new(SPACE_WIDTH, SPACE_HEIGHT, BLACK);
+    let view_transform =
+      ViewTransform::new(&viewport, SPACE_WIDTH as f64, SPACE_HEIGHT as f64);
+    // Straddles the seam at the center of the canvas
+    let world_x: f64 = SPACE_WIDTH as f64 - 1.;
+    let world_y: f64 = SPACE_HEIGHT as f64 - 1.;
+    view_transform.fill_rect(&mut raster, world_x, world_y, 2., 2., RED);
+    assert_eq!(count_red(&raster), 4);
+    // At the corner of the canvas it wraps to all four corners
+    let corner_x: f64 = SPACE_WIDTH as f64 / 2. - 1.;
+    let corner_y: f64 = SPACE_HEIGHT as f64 / 2. - 1.;
+    let mut raster = Raster::new(SPACE_WIDTH, SPACE_HEIGHT, BLACK);
+    view_transform.fill_rect(&mut raster, corner_x, corner_y, 2., 2., RED);
+    assert_eq!(count_red(&raster), 4);
+    let last: usize = 4 * (SPACE_WIDTH * SPACE_HEIGHT - 1);
+    assert_eq!(raster.data[0..4], RED);
+    assert_eq!(raster.data[last..last + 4], RED);
+  }
+
+  #[test]
+  fn test_to_world_xy() {
+    let viewport = Viewport {
+      center_x: 10.,
+      center_y: 95.,
+      zoom: 4.,
+    };
+    let view_transform = ViewTransform::new(&viewport, 400., 400.);
+    let (world_x, world_y) = view_transform.to_world_xy(200., 200.);
+    assert!((world_x - 10.).abs() < 1e-9);
+    assert!((world_y - 95.).abs() < 1e-9);
+    // A quarter of the world is visible so the corner is 12.5 cells away
+    let (world_x, world_y) = view_transform.to_world_xy(0., 400.);
+    assert!((world_x - (SPACE_WIDTH as f64 - 2.5)).abs() < 1e-9);
+    assert!((world_y - 7.5).abs() < 1e-9);
+  }
+}
@@ -2,48 +2,138 @@
 //! - Inputs for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-12-31
-//! - Updated: 2023-03-08
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+use crate::models::blight::Blight;
+use crate::models::classifier::ClassifierSettings;
+use crate::models::environment::EnvironmentSettings;
+use crate::models::fauna::{Aging, Crowding};
+use crate::models::flora::{Decomposition, FloraMode};
+use crate::models::garden::{GardenDrag, GardenSettings};
+use crate::models::genome::Genome;
+use crate::models::options::{ColorMode, DragMode, Palette, Schedule};
+use crate::models::region::Region;
+use crate::models::reset::ResetSettings;
 use crate::updaters::root::RootUpdaterInputs;
 
 #[derive(Default)]
 pub struct Inputs {
-  pub blight_requested: bool,
+  pub aging_change_requested: Option<Aging>,
+  pub blight_requested: Option<Blight>,
   pub bug_requested: Option<usize>,
+  pub classifier_change_requested: Option<ClassifierSettings>,
+  pub color_mode_change_requested: Option<ColorMode>,
+  pub crowding_change_requested: Option<Crowding>,
   pub current_time_millis: f64,
+  pub decomposition_change_requested: Option<Decomposition>,
+  pub density_heat_map_display_change_requested: Option<bool>,
+  pub density_heat_map_window_change_requested: Option<usize>,
+  pub diversity_display_change_requested: Option<bool>,
+  pub drag_mode_change_requested: Option<DragMode>,
+  pub environment_settings_change_requested: Option<EnvironmentSettings>,
   pub flora_growth_rate_change_requested: Option<usize>,
+  pub flora_mode_change_requested: Option<FloraMode>,
   pub frame_rate_display_change_requested: Option<bool>,
   pub garden_change_requested: Option<bool>,
+  pub garden_drag_requested: Option<GardenDrag>,
+  pub garden_settings_change_requested: Option<GardenSettings>,
+  pub gardens_clear_requested: bool,
+  pub gene_heat_map_display_change_requested: Option<bool>,
+  pub genome_change_requested: Option<Option<Genome>>,
+  pub genomes_import_requested: Option<Vec<Genome>>,
+  pub grazing_heat_map_display_change_requested: Option<bool>,
+  pub grazing_heat_map_window_change_requested: Option<usize>,
+  pub heading_display_change_requested: Option<bool>,
+  pub interpolation_change_requested: Option<bool>,
+  // Layer identifier and whether it should be visible
+  pub layer_visibility_change_requested: Option<(&'static str, bool)>,
+  pub life_history_display_change_requested: Option<bool>,
+  pub palette_change_requested: Option<Palette>,
   pub pause_change_requested: Option<bool>,
   pub period_millis_change_requested: Option<f64>,
+  pub profile_display_change_requested: Option<bool>,
   pub reset_requested: bool,
+  pub reset_settings_change_requested: Option<ResetSettings>,
+  pub scavenging_change_requested: Option<bool>,
+  pub schedule_change_requested: Option<Schedule>,
+  // Location of the bug whose lineage should be highlighted
+  pub selection_requested: Option<usize>,
+  pub start_region_change_requested: Option<Region>,
   pub time_display_change_requested: Option<bool>,
+  pub trail_display_change_requested: Option<bool>,
+  pub traits_display_change_requested: Option<bool>,
+  pub traits_heritable_change_requested: Option<bool>,
+  pub viewport_fit_requested: bool,
+  // Drag distance in fractions of the canvas width and height
+  pub viewport_pan_requested: Option<(f64, f64)>,
+  // Zoom factor and the canvas fractions of the point held in place
+  pub viewport_zoom_requested: Option<(f64, f64, f64)>,
 }
 
 impl Inputs {
   pub fn clear(&mut self) {
-    self.blight_requested = false;
+    self.aging_change_requested = None;
+    self.blight_requested = None;
     self.bug_requested = None;
+    self.classifier_change_requested = None;
+    self.color_mode_change_requested = None;
+    self.crowding_change_requested = None;
     self.current_time_millis = 0.;
+    self.decomposition_change_requested = None;
+    self.density_heat_map_display_change_requested = None;
+    self.density_heat_map_window_change_requested = None;
+    self.diversity_display_change_requested = None;
+    self.drag_mode_change_requested = None;
+    self.environment_settings_change_requested = None;
     self.flora_growth_rate_change_requested = None;
+    self.flora_mode_change_requested = None;
     self.frame_rate_display_change_requested = None;
     self.garden_change_requested = None;
+    self.garden_drag_requested = None;
+    self.garden_settings_change_requested = None;
+    self.gardens_clear_requested = false;
+    self.gene_heat_map_display_change_requested = None;
+    self.genome_change_requested = None;
+    self.genomes_import_requested = None;
+    self.grazing_heat_map_display_change_requested = None;
+    self.grazing_heat_map_window_change_requested = None;
+    self.heading_display_change_requested = None;
+    self.interpolation_change_requested = None;
+    self.layer_visibility_change_requested = None;
+    self.life_history_display_change_requested = None;
+    self.palette_change_requested = None;
     self.pause_change_requested = None;
     self.period_millis_change_requested = None;
+    self.profile_display_change_requested = None;
     self.reset_requested = false;
+    self.reset_settings_change_requested = None;
+    self.scavenging_change_requested = None;
+    self.schedule_change_requested = None;
+    self.selection_requested = None;
+    self.start_region_change_requested = None;
     self.time_display_change_requested = None;
+    self.trail_display_change_requested = None;
+    self.traits_display_change_requested = None;
+    self.traits_heritable_change_requested = None;
+    self.viewport_fit_requested = false;
+    self.viewport_pan_requested = None;
+    self.viewport_zoom_requested = None;
   }
 }
 
 impl RootUpdaterInputs for Inputs {
-  fn get_blight_requested(&self) -> bool {
+  fn get_aging_change_requested(&self) -> Option<Aging> {
+    self.aging_change_requested
+  }
+
+  fn get_blight_requested(&self) -> Option<Blight> {
     self.blight_requested
   }
 
@@ -51,14 +141,56 @@ impl RootUpdaterInputs for Inputs {
     self.bug_requested
   }
 
+  fn get_classifier_change_requested(&self) -> Option<ClassifierSettings> {
+    self.classifier_change_requested
+  }
+
+  fn get_color_mode_change_requested(&self) -> Option<ColorMode> {
+    self.color_mode_change_requested
+  }
+
+  fn get_crowding_change_requested(&self) -> Option<Crowding> {
+    self.crowding_change_requested
+  }
+
   fn get_current_time_millis(&self) -> f64 {
     self.current_time_millis
   }
 
+  fn get_decomposition_change_requested(&self) -> Option<Decomposition> {
+    self.decomposition_change_requested
+  }
+
+  fn get_density_heat_map_display_change_requested(&self) -> Option<bool> {
+    self.density_heat_map_display_change_requested
+  }
+
+  fn get_density_heat_map_window_change_requested(&self) -> Option<usize> {
+    self.density_heat_map_window_change_requested
+  }
+
+  fn get_diversity_display_change_requested(&self) -> Option<bool> {
+    self.diversity_display_change_requested
+  }
+
+  fn get_drag_mode_change_requested(&self) -> Option<DragMode> {
+    self.drag_mode_change_requested
+  }
+
+  fn get_environment_settings_change_requested(
+    &self
+  ) -> Option<EnvironmentSettings> {
+    self.environment_settings_change_requested
+  }
+
   fn get_flora_growth_rate_change_requested(&self) -> Option<usize> {
     self.flora_growth_rate_change_requested
   }
 
+  fn get_flora_mode_change_requested(&self) -> Option<FloraMode> {
+    self.flora_mode_change_requested
+  }
+
   fn get_frame_rate_display_change_requested(&self) -> Option<bool> {
     self.frame_rate_display_change_requested
   }
@@ -67,6 +199,60 @@ impl RootUpdaterInputs for Inputs {
     self.garden_change_requested
   }
 
+  fn get_garden_drag_requested(&self) -> Option<GardenDrag> {
+    self.garden_drag_requested
+  }
+
+  fn get_garden_settings_change_requested(&self) -> Option<GardenSettings> {
+    self.garden_settings_change_requested
+  }
+
+  fn get_gardens_clear_requested(&self) -> bool {
+    self.gardens_clear_requested
+  }
+
+  fn get_gene_heat_map_display_change_requested(&self) -> Option<bool> {
+    self.gene_heat_map_display_change_requested
+  }
+
+  fn get_genome_change_requested(&self) -> Option<Option<Genome>> {
+    self.genome_change_requested
+  }
+
+  fn get_genomes_import_requested(&self) -> Option<Vec<Genome>> {
+    self.genomes_import_requested.clone()
+  }
+
+  fn get_grazing_heat_map_display_change_requested(&self) -> Option<bool> {
+    self.grazing_heat_map_display_change_requested
+  }
+
+  fn get_grazing_heat_map_window_change_requested(&self) -> Option<usize> {
+    self.grazing_heat_map_window_change_requested
+  }
+
+  fn get_heading_display_change_requested(&self) -> Option<bool> {
+    self.heading_display_change_requested
+  }
+
+  fn get_interpolation_change_requested(&self) -> Option<bool> {
+    self.interpolation_change_requested
+  }
+
+  fn get_layer_visibility_change_requested(
+    &self
+  ) -> Option<(&'static str, bool)> {
+    self.layer_visibility_change_requested
+  }
+
+  fn get_life_history_display_change_requested(&self) -> Option<bool> {
+    self.life_history_display_change_requested
+  }
+
+  fn get_palette_change_requested(&self) -> Option<Palette> {
+    self.palette_change_requested
+  }
+
   fn get_pause_change_requested(&self) -> Option<bool> {
     self.pause_change_requested
   }
@@ -75,11 +261,59 @@ impl RootUpdaterInputs for Inputs {
     self.period_millis_change_requested
   }
 
+  fn get_profile_display_change_requested(&self) -> Option<bool> {
+    self.profile_display_change_requested
+  }
+
   fn get_reset_requested(&self) -> bool {
     self.reset_requested
   }
 
+  fn get_reset_settings_change_requested(&self) -> Option<ResetSettings> {
+    self.reset_settings_change_requested
+  }
+
+  fn get_scavenging_change_requested(&self) -> Option<bool> {
+    self.scavenging_change_requested
+  }
+
+  fn get_schedule_change_requested(&self) -> Option<Schedule> {
+    self.schedule_change_requested
+  }
+
+  fn get_selection_requested(&self) -> Option<usize> {
+    self.selection_requested
+  }
+
+  fn get_start_region_change_requested(&self) -> Option<Region> {
+    self.start_region_change_requested
+  }
+
   fn get_time_display_change_requested(&self) -> Option<bool> {
     self.time_display_change_requested
   }
+
+  fn get_trail_display_change_requested(&self) -> Option<bool> {
+    self.trail_display_change_requested
+  }
+
+  fn get_traits_display_change_requested(&self) -> Option<bool> {
+    self.traits_display_change_requested
+  }
+
+  fn get_traits_heritable_change_requested(&self) -> Option<bool> {
+    self.traits_heritable_change_requested
+  }
+
+  fn get_viewport_fit_requested(&self) -> bool {
+    self.viewport_fit_requested
+  }
+
+  fn get_viewport_pan_requested(&self) -> Option<(f64, f64)> {
+    self.viewport_pan_requested
+  }
+
+  fn get_viewport_zoom_requested(&self) -> Option<(f64, f64, f64)> {
+    self.viewport_zoom_requested
+  }
 }
@@ -0,0 +1,120 @@
+// =============================================================================
+//! - Blight model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::region::Region;
+use crate::constants::{SPACE_HEIGHT, SPACE_WIDTH};
+use crate::engine::functions::location::{
+  to_index_from_xy, to_x_from_index, to_y_from_index,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Blight {
+  // Destroys all of the food
+  All,
+  // Starts a contagion at a random location with food
+  Contagion,
+  // Destroys each food with the given percent chance
+  Fraction(usize),
+  // Destroys all of the food within the region
+  Region(Region),
+}
+
+// A blight spreading from food to neighboring food until it burns out
+pub struct Contagion {
+  // The locations with food which burn in the next update
+  pub front: Vec<usize>,
+  // Updates remaining in which the blight can spread
+  pub ticks: usize,
+}
+
+impl Contagion {
+  // The distinct neighbors of the front around the torus in index order
+  pub fn to_neighbors(&self) -> Vec<usize> {
+    let mut neighbors: Vec<usize> = Vec::new();
+    for location in self.front.iter() {
+      let x: usize = to_x_from_index(*location);
+      let y: usize = to_y_from_index(*location);
+      for delta_x in [
+        SPACE_WIDTH - 1,
+        0,
+        1,
+      ] {
+        for delta_y in [
+          SPACE_HEIGHT - 1,
+          0,
+          1,
+        ] {
+          if delta_x != 0 || delta_y != 0 {
+            neighbors.push(to_index_from_xy(
+              (x + delta_x) % SPACE_WIDTH,
+              (y + delta_y) % SPACE_HEIGHT,
+            ));
+          }
+        }
+      }
+    }
+    neighbors.sort_unstable();
+    neighbors.dedup();
+    neighbors
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Contagion;
+  use crate::constants::{SPACE_HEIGHT, SPACE_WIDTH};
+  use crate::engine::functions::location::to_index_from_xy;
+
+  #[test]
+  fn test_to_neighbors_at_the_corner() {
+    let contagion = Contagion {
+      front: vec![to_index_from_xy(0, 0)],
+      ticks: 1,
+    };
+    let neighbors: Vec<usize> = contagion.to_neighbors();
+    assert_eq!(neighbors.len(), 8);
+    assert!(!neighbors.contains(&to_index_from_xy(0, 0)));
+    for (x, y) in [
+      (SPACE_WIDTH - 1, SPACE_HEIGHT - 1),
+      (0, SPACE_HEIGHT - 1),
+      (1, SPACE_HEIGHT - 1),
+      (SPACE_WIDTH - 1, 0),
+      (1, 0),
+      (SPACE_WIDTH - 1, 1),
+      (0, 1),
+      (1, 1),
+    ] {
+      assert!(
+        neighbors.contains(&to_index_from_xy(x, y)),
+        "({}, {})",
+        x,
+        y
+      );
+    }
+  }
+
+  #[test]
+  fn test_to_neighbors_without_duplicates() {
+    // Two fronts next to each other across the edge share most neighbors
+    let contagion = Contagion {
+      front: vec![
+        to_index_from_xy(0, 0),
+        to_index_from_xy(SPACE_WIDTH - 1, 0),
+      ],
+      ticks: 1,
+    };
+    let neighbors: Vec<usize> = contagion.to_neighbors();
+    assert_eq!(neighbors.len(), 12);
+    assert!(neighbors.windows(2).all(|pair| pair[0] < pair[1]));
+  }
+}
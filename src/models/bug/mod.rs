@@ -2,9 +2,9 @@
 //! - Bug model for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
-//! - Version: 2023-01-30
+//! - Version: 2026-10-19
 //! - Since: 2022-12-10
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
@@ -13,17 +13,24 @@
 
 use crate::constants::GENES_MAX;
 
+// A single bug for insertion into the Fauna columns with an empty trail
 pub struct Bug {
+  // Energy given to each offspring
+  pub baby_energy: usize,
+  // Energy needed to reproduce
+  pub birth_energy: usize,
+  // Clock tick when the bug was created
+  pub birth_tick: usize,
   pub energy: usize,
   pub genes_x: [bool; GENES_MAX],
   pub genes_y: [bool; GENES_MAX],
+  // Zero for a founder and one more than the parent for offspring
+  pub generation: usize,
+  // Identifier of the founder shared by all of its descendants
+  pub lineage: usize,
+  // Energy spent per update which also sets the energy gained per food eaten
+  pub move_cost: usize,
   pub position: usize,
-  pub species: Species,
-}
-
-#[derive(Clone, Copy)]
-pub enum Species {
-  Cruiser,
-  Normal,
-  Twirlie,
+  // Index into the classes of the Classifier
+  pub species: usize,
 }
@@ -0,0 +1,68 @@
+// =============================================================================
+//! - Classifier Model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{
+  CLASSIFIER_CLUSTER_RADIUS_INIT, CLASSIFIER_CRUISER_SPEED_MIN_INIT,
+  CLASSIFIER_TWIRLIE_SPEED_MAX_INIT,
+};
+use crate::engine::functions::species::make_speed_classes;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ClassifierMode {
+  Cluster,
+  Speed,
+  SpeedHeading,
+}
+
+#[derive(Clone, Copy)]
+pub struct ClassifierSettings {
+  pub cluster_radius: u32,
+  pub cruiser_speed_min: f64,
+  pub mode: ClassifierMode,
+  pub twirlie_speed_max: f64,
+}
+
+impl Default for ClassifierSettings {
+  fn default() -> Self {
+    Self {
+      cluster_radius: CLASSIFIER_CLUSTER_RADIUS_INIT,
+      cruiser_speed_min: CLASSIFIER_CRUISER_SPEED_MIN_INIT,
+      mode: ClassifierMode::Speed,
+      twirlie_speed_max: CLASSIFIER_TWIRLIE_SPEED_MAX_INIT,
+    }
+  }
+}
+
+pub struct SpeciesClass {
+  // Red, green, blue, and alpha
+  pub color: [u8; 4],
+  pub name: String,
+}
+
+// The species of a bug is an index into the classes
+pub struct Classifier {
+  pub classes: Vec<SpeciesClass>,
+  // Genome keys of the cluster centers, parallel to the leading classes
+  pub representatives: Vec<u16>,
+  pub settings: ClassifierSettings,
+}
+
+impl Default for Classifier {
+  fn default() -> Self {
+    Self {
+      classes: make_speed_classes(),
+      representatives: Vec::new(),
+      settings: ClassifierSettings::default(),
+    }
+  }
+}
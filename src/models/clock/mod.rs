@@ -2,9 +2,9 @@
 //! - Clock Model for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
-//! - Version: 2023-01-25
+//! - Version: 2026-10-19
 //! - Since: 2023-01-08
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
@@ -13,5 +13,8 @@
 
 #[derive(Default)]
 pub struct Clock {
+  pub tick: usize,
   pub time: usize,
+  // Fraction of the update period elapsed since the latest update
+  pub update_fraction: f64,
 }
@@ -0,0 +1,113 @@
+// =============================================================================
+//! - Environment model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::ENVIRONMENT_AMPLITUDE_INIT;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Season {
+  Autumn,
+  Spring,
+  Summer,
+  Winter,
+}
+
+impl Season {
+  pub fn name(&self) -> &'static str {
+    match self {
+      Season::Autumn => "Autumn",
+      Season::Spring => "Spring",
+      Season::Summer => "Summer",
+      Season::Winter => "Winter",
+    }
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Weather {
+  Boom,
+  Drought,
+  Normal,
+}
+
+impl Weather {
+  pub fn name(&self) -> &'static str {
+    match self {
+      Weather::Boom => "Boom",
+      Weather::Drought => "Drought",
+      Weather::Normal => "Normal",
+    }
+  }
+}
+
+impl Default for Weather {
+  fn default() -> Self {
+    Weather::Normal
+  }
+}
+
+// A period of zero turns a cycle off
+#[derive(Clone, Copy, PartialEq)]
+pub struct EnvironmentSettings {
+  // Percentage by which the cycles raise and lower the food growth rate
+  pub amplitude: usize,
+  // Updates per day and night
+  pub day_period: usize,
+  // Updates per year of four seasons
+  pub season_period: usize,
+  // Whether droughts and booms start at random
+  pub weather_random: bool,
+}
+
+impl EnvironmentSettings {
+  pub fn is_enabled(&self) -> bool {
+    self.day_period > 0 || self.season_period > 0 || self.weather_random
+  }
+}
+
+impl Default for EnvironmentSettings {
+  fn default() -> Self {
+    Self {
+      amplitude: ENVIRONMENT_AMPLITUDE_INIT,
+      day_period: 0,
+      season_period: 0,
+      weather_random: false,
+    }
+  }
+}
+
+// The phase of the environment as of the latest update
+pub struct Environment {
+  // None when there is no day and night cycle
+  pub daytime: Option<bool>,
+  // Multiplies the food growth rate set by the slider
+  pub growth_factor: f64,
+  // None when there is no cycle of seasons
+  pub season: Option<Season>,
+  pub settings: EnvironmentSettings,
+  pub weather: Weather,
+  // Updates until the weather returns to normal
+  pub weather_ticks: usize,
+}
+
+impl Default for Environment {
+  fn default() -> Self {
+    Self {
+      daytime: None,
+      growth_factor: 1.,
+      season: None,
+      settings: EnvironmentSettings::default(),
+      weather: Weather::default(),
+      weather_ticks: 0,
+    }
+  }
+}
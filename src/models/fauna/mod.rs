@@ -2,9 +2,9 @@
 //! - Fauna Model for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
-//! - Version: 2023-01-25
+//! - Version: 2026-10-19
 //! - Since: 2023-01-05
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
@@ -12,8 +12,284 @@
 // =============================================================================
 
 use super::bug::Bug;
+use crate::constants::{
+  BABY_ENERGY, BIRTH_ENERGY, BIRTH_ENERGY_OVERHEAD, GENES_MAX, LOCATION_COUNT,
+  MOVE_COST, TRAIL_LENGTH,
+};
+use crate::engine::occupancy::Occupancy;
 
-#[derive(Default)]
+// How age shortens the life of a bug.  Each variant holds a number of updates.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Aging {
+  // Energy charged per update rises by one for every this many updates of age
+  Cost(usize),
+  // Bugs die on reaching this age
+  Lifespan(usize),
+  // The chance of dying each update is the age divided by this
+  Mortality(usize),
+  Off,
+}
+
+impl Default for Aging {
+  fn default() -> Self {
+    Aging::Off
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Crowding {
+  // Moves into and births on a location holding this many bugs are blocked
+  Capacity(usize),
+  // Energy charged per update for each other bug on the location moved to
+  Cost(usize),
+  Off,
+}
+
+impl Default for Crowding {
+  fn default() -> Self {
+    Crowding::Off
+  }
+}
+
+// The life history of a bug removed from the fauna
+#[derive(Clone, Copy)]
+pub struct Death {
+  pub age: usize,
+  // The age at which the bug first reproduced or None if it never did
+  pub first_spawn_age: Option<usize>,
+  pub offspring: usize,
+  pub position: usize,
+  pub species: usize,
+}
+
+// Struct of arrays with one column per bug field so that the update loop only
+// touches the columns it needs.  The columns always have the same length.
 pub struct Fauna {
-  pub bugs: Vec<Bug>,
+  pub aging: Aging,
+  pub baby_energies: Vec<usize>,
+  pub birth_energies: Vec<usize>,
+  pub birth_ticks: Vec<usize>,
+  // Number of bugs at each location kept current during an update
+  pub crowd_counts: Vec<usize>,
+  pub crowding: Crowding,
+  // Bugs removed during the latest update
+  pub deaths: Vec<Death>,
+  pub energies: Vec<usize>,
+  // Tick of the first reproduction of each bug or None if not yet
+  pub first_spawn_ticks: Vec<Option<usize>>,
+  pub generations: Vec<usize>,
+  pub genes_x: Vec<[bool; GENES_MAX]>,
+  pub genes_y: Vec<[bool; GENES_MAX]>,
+  // Locations where flora was eaten during the latest update
+  pub grazed_positions: Vec<usize>,
+  // Lineage identifier for the next founder
+  pub lineage_next: usize,
+  pub lineages: Vec<usize>,
+  pub move_costs: Vec<usize>,
+  // Indices of the bugs at each location as of the latest rebuild
+  pub occupancy: Occupancy,
+  // Number of offspring of each bug so far
+  pub offspring_counts: Vec<usize>,
+  pub positions: Vec<usize>,
+  pub species: Vec<usize>,
+  // Ring buffer slot of the most recent previous positions
+  pub trail_head: usize,
+  // Number of previous positions recorded for each bug up to the maximum
+  pub trail_lengths: Vec<usize>,
+  // Previous positions with TRAIL_LENGTH ring buffer slots per bug.  Every
+  // bug records one position per update so all of the bugs share the head.
+  pub trail_positions: Vec<usize>,
+  // Whether the baby energy, birth energy, and move cost mutate
+  pub traits_heritable: bool,
+}
+
+impl Fauna {
+  // Called once per update before the positions are recorded
+  pub fn advance_trails(&mut self) {
+    self.trail_head = (self.trail_head + 1) % TRAIL_LENGTH;
+  }
+
+  pub fn clear(&mut self) {
+    self.baby_energies.clear();
+    self.birth_energies.clear();
+    self.birth_ticks.clear();
+    self.deaths.clear();
+    self.energies.clear();
+    self.first_spawn_ticks.clear();
+    self.generations.clear();
+    self.genes_x.clear();
+    self.genes_y.clear();
+    self.grazed_positions.clear();
+    self.lineages.clear();
+    self.move_costs.clear();
+    self.offspring_counts.clear();
+    self.positions.clear();
+    self.species.clear();
+    self.trail_head = 0;
+    self.trail_lengths.clear();
+    self.trail_positions.clear();
+    self.rebuild_occupancy();
+  }
+
+  // Enough energy to reach the birth energy and to survive giving birth
+  pub fn can_spawn(
+    &self,
+    index: usize,
+  ) -> bool {
+    let energy: usize = self.energies[index];
+    energy >= self.birth_energies[index]
+      && energy > self.baby_energies[index] + BIRTH_ENERGY_OVERHEAD
+  }
+
+  // Called at the start of an update when crowding is on
+  pub fn count_crowds(&mut self) {
+    self.crowd_counts.clear();
+    self.crowd_counts.resize(LOCATION_COUNT, 0);
+    for position in self.positions.iter() {
+      self.crowd_counts[*position] += 1;
+    }
+  }
+
+  // Previous positions of a bug with the most recent first
+  pub fn get_trail(
+    &self,
+    index: usize,
+  ) -> impl Iterator<Item = usize> + '_ {
+    let slots: &[usize] =
+      &self.trail_positions[index * TRAIL_LENGTH..(index + 1) * TRAIL_LENGTH];
+    (0..self.trail_lengths[index]).map(move |age| {
+      slots[(self.trail_head + TRAIL_LENGTH - age) % TRAIL_LENGTH]
+    })
+  }
+
+  pub fn len(&self) -> usize {
+    self.positions.len()
+  }
+
+  pub fn push(
+    &mut self,
+    bug: Bug,
+  ) {
+    self.baby_energies.push(bug.baby_energy);
+    self.birth_energies.push(bug.birth_energy);
+    self.birth_ticks.push(bug.birth_tick);
+    self.energies.push(bug.energy);
+    self.first_spawn_ticks.push(None);
+    self.generations.push(bug.generation);
+    self.genes_x.push(bug.genes_x);
+    self.genes_y.push(bug.genes_y);
+    self.lineages.push(bug.lineage);
+    self.move_costs.push(bug.move_cost);
+    self.offspring_counts.push(0);
+    self.positions.push(bug.position);
+    self.species.push(bug.species);
+    self.trail_lengths.push(0);
+    self
+      .trail_positions
+      .resize(self.trail_positions.len() + TRAIL_LENGTH, 0);
+  }
+
+  pub fn record_trail(
+    &mut self,
+    index: usize,
+    position: usize,
+  ) {
+    self.trail_positions[index * TRAIL_LENGTH + self.trail_head] = position;
+    let trail_length: &mut usize = &mut self.trail_lengths[index];
+    *trail_length = (*trail_length + 1).min(TRAIL_LENGTH);
+  }
+
+  // Restores the life-history traits of every bug to the constants
+  pub fn reset_traits(&mut self) {
+    self.baby_energies.fill(BABY_ENERGY);
+    self.birth_energies.fill(BIRTH_ENERGY);
+    self.move_costs.fill(MOVE_COST);
+  }
+
+  // Called after the positions change and before the occupancy is read
+  pub fn rebuild_occupancy(&mut self) {
+    self.occupancy.rebuild(&self.positions);
+  }
+
+  // Records the deaths of the bugs without energy and removes them by moving
+  // the last bug into each gap instead of shifting every bug after it.  This
+  // reorders the bugs.
+  pub fn remove_dead(
+    &mut self,
+    tick: usize,
+  ) {
+    for index in (0..self.len()).rev() {
+      if self.energies[index] == 0 {
+        let birth_tick: usize = self.birth_ticks[index];
+        self.deaths.push(Death {
+          age: tick - birth_tick,
+          first_spawn_age: self.first_spawn_ticks[index]
+            .map(|first_spawn_tick| first_spawn_tick - birth_tick),
+          offspring: self.offspring_counts[index],
+          position: self.positions[index],
+          species: self.species[index],
+        });
+        self.swap_remove(index);
+      }
+    }
+  }
+
+  pub fn swap_remove(
+    &mut self,
+    index: usize,
+  ) {
+    self.baby_energies.swap_remove(index);
+    self.birth_energies.swap_remove(index);
+    self.birth_ticks.swap_remove(index);
+    self.energies.swap_remove(index);
+    self.first_spawn_ticks.swap_remove(index);
+    self.generations.swap_remove(index);
+    self.genes_x.swap_remove(index);
+    self.genes_y.swap_remove(index);
+    self.lineages.swap_remove(index);
+    self.move_costs.swap_remove(index);
+    self.offspring_counts.swap_remove(index);
+    self.positions.swap_remove(index);
+    self.species.swap_remove(index);
+    self.trail_lengths.swap_remove(index);
+    let last_start: usize = self.trail_positions.len() - TRAIL_LENGTH;
+    if index * TRAIL_LENGTH != last_start {
+      self.trail_positions.copy_within(
+        last_start..last_start + TRAIL_LENGTH,
+        index * TRAIL_LENGTH,
+      );
+    }
+    self.trail_positions.truncate(last_start);
+  }
+}
+
+impl Default for Fauna {
+  fn default() -> Self {
+    Self {
+      aging: Aging::default(),
+      baby_energies: Vec::new(),
+      birth_energies: Vec::new(),
+      birth_ticks: Vec::new(),
+      crowd_counts: Vec::new(),
+      crowding: Crowding::default(),
+      deaths: Vec::new(),
+      energies: Vec::new(),
+      first_spawn_ticks: Vec::new(),
+      generations: Vec::new(),
+      genes_x: Vec::new(),
+      genes_y: Vec::new(),
+      grazed_positions: Vec::new(),
+      lineage_next: 0,
+      lineages: Vec::new(),
+      move_costs: Vec::new(),
+      occupancy: Occupancy::new(LOCATION_COUNT),
+      offspring_counts: Vec::new(),
+      positions: Vec::new(),
+      species: Vec::new(),
+      trail_head: 0,
+      trail_lengths: Vec::new(),
+      trail_positions: Vec::new(),
+      traits_heritable: false,
+    }
+  }
 }
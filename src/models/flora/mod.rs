@@ -2,29 +2,257 @@
 //! - Flora Model for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
-//! - Version: 2023-01-25
+//! - Version: 2026-10-19
 //! - Since: 2023-01-04
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
-use crate::constants::{FLORA_GROWTH_RATE_INIT, LOCATION_COUNT};
+use super::blight::Contagion;
+use super::garden::{Garden, GardenEdit, GardenSettings};
+use crate::constants::{
+  BIOMASS_CAPACITY, BIOMASS_REGROWTH_DIVISOR, CORPSE_TICKS, FERTILITY_TICKS,
+  FLORA_ENERGY, FLORA_GROWTH_RATE_INIT, LOCATION_COUNT, SPACE_HEIGHT,
+  SPACE_WIDTH,
+};
+use crate::engine::functions::location::{
+  to_index_from_xy, to_x_from_index, to_y_from_index,
+};
 
+#[derive(Clone, Copy, PartialEq)]
+pub enum Decomposition {
+  // A dead bug leaves a corpse which rots into fertile soil unless eaten
+  Corpse,
+  // A dead bug immediately fertilizes its location and the neighbors
+  Fertilize,
+  Off,
+}
+
+impl Default for Decomposition {
+  fn default() -> Self {
+    Decomposition::Off
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum FloraMode {
+  // Each location holds an amount of food which regrows toward a capacity
+  Biomass,
+  // Each location either holds one meal of food or none
+  Present,
+}
+
+impl Default for FloraMode {
+  fn default() -> Self {
+    FloraMode::Present
+  }
+}
+
+// The flora present flags stay true exactly where the biomass is above zero
+// in the biomass model
 pub struct Flora {
+  pub biomass: Vec<usize>,
+  // The contagious blight spreading through the food if any
+  pub contagion: Option<Contagion>,
+  // Updates until the corpse at each location rots or zero if there is none
+  pub corpse_ticks: Vec<usize>,
+  pub decomposition: Decomposition,
   pub enabled_garden: bool,
+  // Updates that each location remains fertile
+  pub fertility_ticks: Vec<usize>,
   pub flora_growth_rate: usize,
+  pub flora_mode: FloraMode,
   pub flora_present: [bool; LOCATION_COUNT],
+  // The garden being drawn or moved by the drag in progress
+  pub garden_edit: Option<GardenEdit>,
+  // The garden last drawn, moved, or resized whose replenish rate is edited
+  pub garden_selected: Option<usize>,
+  pub garden_settings: GardenSettings,
+  pub gardens: Vec<Garden>,
+  // Whether bugs can eat corpses
+  pub scavenging: bool,
+}
+
+impl Flora {
+  pub fn clear_remains(&mut self) {
+    self.corpse_ticks.iter_mut().for_each(|ticks| *ticks = 0);
+    self.fertility_ticks.iter_mut().for_each(|ticks| *ticks = 0);
+  }
+
+  // Leaves the remains of a bug that died at the position
+  pub fn decompose(
+    &mut self,
+    position: usize,
+  ) {
+    match self.decomposition {
+      Decomposition::Corpse => self.corpse_ticks[position] = CORPSE_TICKS,
+      Decomposition::Fertilize => self.fertilize(position),
+      Decomposition::Off => {},
+    }
+  }
+
+  // Fertilizes the location and its eight neighbors around the torus
+  pub fn fertilize(
+    &mut self,
+    position: usize,
+  ) {
+    let x: usize = to_x_from_index(position);
+    let y: usize = to_y_from_index(position);
+    for delta_x in [
+      SPACE_WIDTH - 1,
+      0,
+      1,
+    ] {
+      for delta_y in [
+        SPACE_HEIGHT - 1,
+        0,
+        1,
+      ] {
+        let index: usize = to_index_from_xy(
+          (x + delta_x) % SPACE_WIDTH,
+          (y + delta_y) % SPACE_HEIGHT,
+        );
+        self.fertility_ticks[index] = FERTILITY_TICKS;
+      }
+    }
+  }
+
+  // Eats up to the appetite from the food at the location in the biomass model
+  // or the whole meal otherwise and returns the energy of the food eaten
+  pub fn graze(
+    &mut self,
+    index: usize,
+    appetite: usize,
+  ) -> usize {
+    if !self.flora_present[index] {
+      return 0;
+    }
+    match self.flora_mode {
+      FloraMode::Biomass => {
+        let bite: usize = self.biomass[index].min(appetite);
+        self.biomass[index] -= bite;
+        self.flora_present[index] = self.biomass[index] > 0;
+        bite
+      },
+      FloraMode::Present => {
+        self.uproot(index);
+        FLORA_ENERGY
+      },
+    }
+  }
+
+  pub fn has_carrion(
+    &self,
+    position: usize,
+  ) -> bool {
+    self.scavenging && self.corpse_ticks[position] > 0
+  }
+
+  pub fn plant(
+    &mut self,
+    index: usize,
+    biomass: usize,
+  ) {
+    self.flora_present[index] = true;
+    self.biomass[index] = (self.biomass[index] + biomass).min(BIOMASS_CAPACITY);
+  }
+
+  // Logistic growth so that a lightly grazed location recovers quickly while
+  // one grazed down to almost nothing recovers slowly
+  pub fn regrow_biomass(&mut self) {
+    let divisor: usize = BIOMASS_CAPACITY * BIOMASS_REGROWTH_DIVISOR;
+    for biomass in self.biomass.iter_mut() {
+      // Rounded up so that food below the capacity always grows
+      *biomass +=
+        (*biomass * (BIOMASS_CAPACITY - *biomass) + divisor - 1) / divisor;
+    }
+  }
+
+  // Locations with food start out full when switching to the biomass model
+  pub fn set_flora_mode(
+    &mut self,
+    flora_mode: FloraMode,
+  ) {
+    if flora_mode == FloraMode::Biomass && self.flora_mode != flora_mode {
+      for (biomass, present) in
+        self.biomass.iter_mut().zip(self.flora_present.iter())
+      {
+        *biomass = if *present {
+          BIOMASS_CAPACITY
+        } else {
+          0
+        };
+      }
+    }
+    self.flora_mode = flora_mode;
+  }
+
+  // The food energy available at the standard metabolic rate
+  pub fn to_biomass_total(&self) -> usize {
+    match self.flora_mode {
+      FloraMode::Biomass => self.biomass.iter().sum(),
+      FloraMode::Present => {
+        FLORA_ENERGY
+          * self
+            .flora_present
+            .iter()
+            .filter(|present| **present)
+            .count()
+      },
+    }
+  }
+
+  pub fn uproot(
+    &mut self,
+    index: usize,
+  ) {
+    self.flora_present[index] = false;
+    self.biomass[index] = 0;
+  }
 }
 
 impl Default for Flora {
   fn default() -> Self {
     Self {
+      biomass: vec![0; LOCATION_COUNT],
+      contagion: None,
+      corpse_ticks: vec![0; LOCATION_COUNT],
+      decomposition: Decomposition::default(),
       enabled_garden: true,
+      fertility_ticks: vec![0; LOCATION_COUNT],
       flora_growth_rate: FLORA_GROWTH_RATE_INIT,
+      flora_mode: FloraMode::default(),
       flora_present: [false; LOCATION_COUNT],
+      garden_edit: None,
+      garden_selected: None,
+      garden_settings: GardenSettings::default(),
+      gardens: vec![Garden::default()],
+      scavenging: false,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Flora, FloraMode};
+  use crate::constants::BIOMASS_CAPACITY;
+
+  #[test]
+  fn test_regrow_biomass_to_capacity() {
+    let mut flora = Flora::default();
+    flora.set_flora_mode(FloraMode::Biomass);
+    flora.plant(0, 1);
+    let mut previous: usize = flora.biomass[0];
+    for _ in 0..1_000 {
+      flora.regrow_biomass();
+      let biomass: usize = flora.biomass[0];
+      assert!(biomass <= BIOMASS_CAPACITY);
+      assert!(biomass > previous || biomass == BIOMASS_CAPACITY);
+      previous = biomass;
     }
+    assert_eq!(flora.biomass[0], BIOMASS_CAPACITY);
   }
 }
@@ -0,0 +1,245 @@
+// =============================================================================
+//! - Garden model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::region::Region;
+use crate::constants::{
+  EDEN_HEIGHT, EDEN_WIDTH, EDEN_X0, EDEN_Y0, GARDEN_PERIOD_INIT,
+  GARDEN_REPLENISH_RATE_INIT, SPACE_HEIGHT, SPACE_WIDTH,
+};
+use crate::engine::functions::location::{
+  to_index_from_xy, to_x_from_index, to_y_from_index,
+};
+
+// A rectangle of locations where food is replenished which can wrap around
+// the edges of the space when it moves
+#[derive(Clone, Copy, PartialEq)]
+pub struct Garden {
+  // Added to the coordinates with wrapping each time the garden drifts
+  pub drift_x: usize,
+  pub drift_y: usize,
+  pub height: usize,
+  // Percent chance per update that each location is replenished
+  pub replenish_rate: usize,
+  pub width: usize,
+  // The corner from which the width and height extend
+  pub x: usize,
+  pub y: usize,
+}
+
+impl Garden {
+  pub fn contains(
+    &self,
+    index: usize,
+  ) -> bool {
+    let delta_x: usize =
+      (to_x_from_index(index) + SPACE_WIDTH - self.x) % SPACE_WIDTH;
+    let delta_y: usize =
+      (to_y_from_index(index) + SPACE_HEIGHT - self.y) % SPACE_HEIGHT;
+    delta_x < self.width && delta_y < self.height
+  }
+
+  pub fn set_region(
+    &mut self,
+    region: Region,
+  ) {
+    self.height = region.height;
+    self.width = region.width;
+    self.x = region.x;
+    self.y = region.y;
+  }
+
+  pub fn to_indices(self) -> Vec<usize> {
+    self.to_region().to_indices()
+  }
+
+  pub fn to_region(self) -> Region {
+    Region {
+      height: self.height,
+      width: self.width,
+      x: self.x,
+      y: self.y,
+    }
+  }
+
+  // Resizes by dragging the corner nearest to the location while the
+  // opposite corner stays put
+  pub fn to_resize_edit(
+    self,
+    index: usize,
+    location: usize,
+  ) -> GardenEdit {
+    let (anchor_x, offset_x) = Self::to_resize_span(
+      self.x,
+      self.width,
+      (to_x_from_index(location) + SPACE_WIDTH - self.x) % SPACE_WIDTH,
+      SPACE_WIDTH,
+    );
+    let (anchor_y, offset_y) = Self::to_resize_span(
+      self.y,
+      self.height,
+      (to_y_from_index(location) + SPACE_HEIGHT - self.y) % SPACE_HEIGHT,
+      SPACE_HEIGHT,
+    );
+    GardenEdit::Resize {
+      anchor: to_index_from_xy(anchor_x, anchor_y),
+      index,
+      offset_x,
+      offset_y,
+    }
+  }
+
+  // The fixed coordinate along one axis and the signed offset from it to the
+  // dragged edge
+  fn to_resize_span(
+    start: usize,
+    length: usize,
+    offset: usize,
+    size: usize,
+  ) -> (usize, isize) {
+    let extent: isize = length as isize - 1;
+    if 2 * offset < length {
+      ((start + length - 1) % size, -extent)
+    } else {
+      (start, extent)
+    }
+  }
+
+  pub fn translate(
+    &mut self,
+    delta_x: usize,
+    delta_y: usize,
+  ) {
+    self.x = (self.x + delta_x) % SPACE_WIDTH;
+    self.y = (self.y + delta_y) % SPACE_HEIGHT;
+  }
+}
+
+// The Garden of Eden
+impl Default for Garden {
+  fn default() -> Self {
+    Self {
+      drift_x: 1,
+      drift_y: 0,
+      height: EDEN_HEIGHT,
+      replenish_rate: 100,
+      width: EDEN_WIDTH,
+      x: EDEN_X0,
+      y: EDEN_Y0,
+    }
+  }
+}
+
+// A drag on the canvas in the garden drag mode
+#[derive(Clone, Copy, PartialEq)]
+pub struct GardenDrag {
+  // The drag in locations, which can cross the edges of the space
+  pub delta_x: isize,
+  pub delta_y: isize,
+  pub location_from: usize,
+  // Whether a drag starting in a garden resizes it instead of moving it
+  pub resize: bool,
+  // Whether this is the first request of the drag
+  pub started: bool,
+}
+
+// The garden being changed by the drag in progress
+#[derive(Clone, Copy, PartialEq)]
+pub enum GardenEdit {
+  // An existing garden dragged from where it was at the start of the drag
+  Move {
+    index: usize,
+    x: usize,
+    y: usize,
+  },
+  // A garden spanning from a fixed corner to a dragged corner which starts at
+  // the offset from the fixed corner, which is zero for a new garden
+  Resize {
+    anchor: usize,
+    index: usize,
+    offset_x: isize,
+    offset_y: isize,
+  },
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum GardenMotion {
+  // Each garden moves one location along its own heading
+  Drift,
+  // Each garden moves to a random location
+  Jump,
+  Still,
+}
+
+impl Default for GardenMotion {
+  fn default() -> Self {
+    GardenMotion::Still
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct GardenSettings {
+  pub motion: GardenMotion,
+  // Updates between moves of the gardens
+  pub period: usize,
+  // The replenish rate of the selected garden and the gardens drawn next
+  pub replenish_rate: usize,
+}
+
+impl Default for GardenSettings {
+  fn default() -> Self {
+    Self {
+      motion: GardenMotion::default(),
+      period: GARDEN_PERIOD_INIT,
+      replenish_rate: GARDEN_REPLENISH_RATE_INIT,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Garden, GardenEdit};
+  use crate::constants::SPACE_WIDTH;
+  use crate::engine::functions::location::to_index_from_xy;
+  use crate::models::region::Region;
+
+  #[test]
+  fn test_resize_across_the_edge() {
+    let mut garden = Garden {
+      height: 3,
+      width: 4,
+      x: SPACE_WIDTH - 2,
+      y: 5,
+      ..Garden::default()
+    };
+    // Dragging from the lower right corner, which has wrapped to x 1
+    let edit: GardenEdit = garden.to_resize_edit(0, to_index_from_xy(1, 7));
+    let (anchor, offset_x, offset_y) = match edit {
+      GardenEdit::Resize {
+        anchor,
+        offset_x,
+        offset_y,
+        ..
+      } => (anchor, offset_x, offset_y),
+      GardenEdit::Move {
+        ..
+      } => panic!("expected a resize"),
+    };
+    assert_eq!(anchor, to_index_from_xy(SPACE_WIDTH - 2, 5));
+    assert_eq!((offset_x, offset_y), (3, 2));
+    garden.set_region(Region::from_drag(anchor, offset_x + 2, offset_y));
+    assert_eq!((garden.x, garden.y), (SPACE_WIDTH - 2, 5));
+    assert_eq!((garden.width, garden.height), (6, 3));
+    assert!(garden.contains(to_index_from_xy(3, 7)));
+    assert!(!garden.contains(to_index_from_xy(4, 7)));
+  }
+}
@@ -0,0 +1,42 @@
+// =============================================================================
+//! - Gene History Model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::LOCUS_COUNT;
+use std::collections::VecDeque;
+
+// Each column is the mean per-locus frequency of the 1 allele over a bucket
+#[derive(Default)]
+pub struct GeneHistory {
+  pub bucket_sample_count: usize,
+  pub bucket_sums: [f64; LOCUS_COUNT],
+  pub columns: VecDeque<[f64; LOCUS_COUNT]>,
+}
+
+impl GeneHistory {
+  pub fn clear(&mut self) {
+    self.bucket_sample_count = 0;
+    self.bucket_sums = [0.; LOCUS_COUNT];
+    self.columns.clear();
+  }
+
+  pub fn get_bucket_average(&self) -> Option<[f64; LOCUS_COUNT]> {
+    if self.bucket_sample_count == 0 {
+      return None;
+    }
+    let mut average: [f64; LOCUS_COUNT] = self.bucket_sums;
+    average
+      .iter_mut()
+      .for_each(|sum| *sum /= self.bucket_sample_count as f64);
+    Some(average)
+  }
+}
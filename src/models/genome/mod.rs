@@ -0,0 +1,20 @@
+// =============================================================================
+//! - Genome Model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::GENES_MAX;
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Genome {
+  pub genes_x: [bool; GENES_MAX],
+  pub genes_y: [bool; GENES_MAX],
+}
@@ -0,0 +1,48 @@
+// =============================================================================
+//! - Heat Maps Model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{HEAT_MAP_WINDOW_INIT, LOCATION_COUNT};
+
+// Per-location event counts that decay exponentially with time
+pub struct HeatMap {
+  pub values: Vec<f64>,
+  // Time constant of the exponential decay in updates
+  pub window: usize,
+}
+
+impl HeatMap {
+  pub fn clear(&mut self) {
+    self.values.iter_mut().for_each(|value| *value = 0.);
+  }
+
+  pub fn get_value_max(&self) -> f64 {
+    self.values.iter().cloned().fold(0., f64::max)
+  }
+}
+
+impl Default for HeatMap {
+  fn default() -> Self {
+    Self {
+      values: vec![0.; LOCATION_COUNT],
+      window: HEAT_MAP_WINDOW_INIT,
+    }
+  }
+}
+
+#[derive(Default)]
+pub struct HeatMaps {
+  // Where the bugs have been
+  pub density: HeatMap,
+  // Where the flora has been eaten
+  pub grazing: HeatMap,
+}
@@ -1,7 +1,21 @@
+pub mod blight;
 pub mod bug;
+pub mod classifier;
 pub mod clock;
+pub mod environment;
 pub mod fauna;
 pub mod flora;
+pub mod garden;
+pub mod gene_history;
+pub mod genome;
+pub mod heat_maps;
 pub mod options;
 pub mod overlay;
+pub mod profiler;
+pub mod random;
+pub mod region;
+pub mod reset;
 pub mod root;
+pub mod selection;
+pub mod statistics;
+pub mod viewport;
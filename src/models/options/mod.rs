@@ -2,29 +2,135 @@
 //! - Options Model for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-02-27
-//! - Updated: 2023-03-08
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+use crate::models::genome::Genome;
 use crate::updaters::root::RootUpdaterOptions;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum ColorMode {
+  Age,
+  Energy,
+  Generation,
+  Genotype,
+  Lineage,
+  Species,
+}
+
+impl Default for ColorMode {
+  fn default() -> Self {
+    ColorMode::Species
+  }
+}
+
+// What a drag on the canvas does
+#[derive(Clone, Copy, PartialEq)]
+pub enum DragMode {
+  // Destroys the food in the rectangle drawn once the drag ends
+  Blight,
+  // Draws a new garden or moves an existing one
+  Garden,
+  Pan,
+  // Draws the region where the founders are placed when reset
+  StartRegion,
+}
+
+impl Default for DragMode {
+  fn default() -> Self {
+    DragMode::Pan
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Palette {
+  // Okabe-Ito categories and a viridis ramp
+  ColorBlind,
+  Standard,
+}
+
+impl Default for Palette {
+  fn default() -> Self {
+    Palette::Standard
+  }
+}
+
+// The order in which the bugs graze, spawn, and move during an update
+#[derive(Clone, Copy, PartialEq)]
+pub enum Schedule {
+  // A fresh random order each update
+  Permutation,
+  // Storage order which favors the bugs that have been alive the longest
+  Sequential,
+  // All act at once and the highest energy wins contested food
+  SimultaneousEnergy,
+  // All act at once and a random draw decides contested food
+  SimultaneousRandom,
+}
+
+impl Default for Schedule {
+  fn default() -> Self {
+    Schedule::Sequential
+  }
+}
 
 #[derive(Default)]
 pub struct Options {
+  pub color_mode: ColorMode,
+  pub density_heat_map_display: bool,
+  pub diversity_display: bool,
+  pub drag_mode: DragMode,
+  pub gene_heat_map_display: bool,
+  // Genome of the bugs spawned by a click or random if None
+  pub genome: Option<Genome>,
+  pub grazing_heat_map_display: bool,
+  pub heading_display: bool,
+  pub interpolation: bool,
+  // Visibility of the layers toggled by the user keyed by layer identifier
+  pub layer_visibilities: HashMap<&'static str, bool>,
+  pub life_history_display: bool,
+  pub palette: Palette,
   pub pause: bool,
+  pub profile_display: bool,
+  pub schedule: Schedule,
   pub time_display: bool,
+  pub trail_display: bool,
+  pub traits_display: bool,
   pub update_rate_display: bool,
 }
 
 impl RootUpdaterOptions for Options {
+  fn get_diversity_display(&self) -> bool {
+    self.diversity_display
+  }
+
+  fn get_genome(&self) -> Option<Genome> {
+    self.genome
+  }
+
+  fn get_interpolation(&self) -> bool {
+    self.interpolation
+  }
+
+  fn get_life_history_display(&self) -> bool {
+    self.life_history_display
+  }
+
   fn get_pause(&self) -> bool {
     self.pause
   }
 
+  fn get_schedule(&self) -> Schedule {
+    self.schedule
+  }
+
   fn get_time_display(&self) -> bool {
     self.time_display
   }
@@ -2,10 +2,10 @@
 //! - Overlay Model for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-02-09
-//! - Updated: 2023-03-04
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -13,6 +13,12 @@
 
 #[derive(Default)]
 pub struct Overlay {
+  pub diversity_string: String,
+  // The environmental phase or empty when the environment is constant
+  pub environment_string: String,
+  // A heading and then one line per species with completed lives
+  pub life_history_strings: Vec<String>,
+  pub species_strings: Vec<String>,
   pub status_string: String,
   pub time_string: String,
   pub update_rate_string: String,
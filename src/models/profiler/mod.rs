@@ -0,0 +1,107 @@
+// =============================================================================
+//! - Profiler Model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{MILLIS_PER_SECOND, PROFILER_SAMPLES_MAX};
+use std::collections::VecDeque;
+
+// Rolling durations of the latest calls to something being timed
+pub struct Timing {
+  pub durations_millis: VecDeque<f64>,
+  pub name: &'static str,
+}
+
+impl Timing {
+  pub fn get_max(&self) -> f64 {
+    self.durations_millis.iter().cloned().fold(0., f64::max)
+  }
+
+  pub fn get_mean(&self) -> f64 {
+    if self.durations_millis.is_empty() {
+      return 0.;
+    }
+    self.durations_millis.iter().sum::<f64>()
+      / self.durations_millis.len() as f64
+  }
+
+  pub fn get_min(&self) -> f64 {
+    self
+      .durations_millis
+      .iter()
+      .cloned()
+      .reduce(f64::min)
+      .unwrap_or(0.)
+  }
+
+  pub fn new(name: &'static str) -> Self {
+    Self {
+      durations_millis: VecDeque::with_capacity(PROFILER_SAMPLES_MAX),
+      name,
+    }
+  }
+
+  pub fn push(
+    &mut self,
+    duration_millis: f64,
+  ) {
+    if self.durations_millis.len() >= PROFILER_SAMPLES_MAX {
+      self.durations_millis.pop_front();
+    }
+    self.durations_millis.push_back(duration_millis);
+  }
+}
+
+// Times of the events within the latest second
+#[derive(Default)]
+pub struct EventRate {
+  pub times_millis: VecDeque<f64>,
+}
+
+impl EventRate {
+  pub fn get_rate(&self) -> usize {
+    self.times_millis.len()
+  }
+
+  pub fn push(
+    &mut self,
+    time_millis: f64,
+  ) {
+    self.times_millis.push_back(time_millis);
+    while let Some(time_millis_oldest) = self.times_millis.front() {
+      if time_millis - time_millis_oldest < MILLIS_PER_SECOND {
+        break;
+      }
+      self.times_millis.pop_front();
+    }
+  }
+}
+
+pub struct Profiler {
+  pub paint: Timing,
+  // Paints per second
+  pub render_rate: EventRate,
+  // Simulation updates per second
+  pub tick_rate: EventRate,
+  // One for each child updater of the RootUpdater in the order updated
+  pub updaters: Vec<Timing>,
+}
+
+impl Default for Profiler {
+  fn default() -> Self {
+    Self {
+      paint: Timing::new("paint"),
+      render_rate: EventRate::default(),
+      tick_rate: EventRate::default(),
+      updaters: Vec::new(),
+    }
+  }
+}
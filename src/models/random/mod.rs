@@ -0,0 +1,40 @@
+// =============================================================================
+//! - Random Model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+pub struct Random {
+  pub rng: StdRng,
+  pub seed: u64,
+}
+
+impl Random {
+  pub fn reseed(
+    &mut self,
+    seed: u64,
+  ) {
+    self.rng = StdRng::seed_from_u64(seed);
+    self.seed = seed;
+  }
+}
+
+impl Default for Random {
+  fn default() -> Self {
+    let seed: u64 = rand::random();
+    Self {
+      rng: StdRng::seed_from_u64(seed),
+      seed,
+    }
+  }
+}
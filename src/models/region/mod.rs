@@ -0,0 +1,128 @@
+// =============================================================================
+//! - Region model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{SPACE_HEIGHT, SPACE_WIDTH};
+use crate::engine::functions::location::{
+  to_index_from_xy, to_x_from_index, to_y_from_index,
+};
+
+// A rectangle of locations which wraps around the edges of the space
+#[derive(Clone, Copy, PartialEq)]
+pub struct Region {
+  pub height: usize,
+  pub width: usize,
+  // The corner from which the width and height extend
+  pub x: usize,
+  pub y: usize,
+}
+
+impl Region {
+  // The rectangle from the location where a drag started to the location
+  // reached by the drag, which can cross the edges of the space
+  pub fn from_drag(
+    location: usize,
+    delta_x: isize,
+    delta_y: isize,
+  ) -> Self {
+    let (x, width) =
+      Self::to_span(to_x_from_index(location), delta_x, SPACE_WIDTH);
+    let (y, height) =
+      Self::to_span(to_y_from_index(location), delta_y, SPACE_HEIGHT);
+    Self {
+      height,
+      width,
+      x,
+      y,
+    }
+  }
+
+  // The location at the offset from the corner with wrapping
+  pub fn to_index(
+    self,
+    delta_x: usize,
+    delta_y: usize,
+  ) -> usize {
+    to_index_from_xy(
+      (self.x + delta_x) % SPACE_WIDTH,
+      (self.y + delta_y) % SPACE_HEIGHT,
+    )
+  }
+
+  pub fn to_indices(self) -> Vec<usize> {
+    let mut indices: Vec<usize> = Vec::with_capacity(self.width * self.height);
+    for delta_y in 0..self.height {
+      for delta_x in 0..self.width {
+        indices.push(self.to_index(delta_x, delta_y));
+      }
+    }
+    indices
+  }
+
+  // The first coordinate and the length of the span along one axis
+  fn to_span(
+    start: usize,
+    delta: isize,
+    size: usize,
+  ) -> (usize, usize) {
+    let distance: usize = delta.unsigned_abs();
+    let length: usize = (distance + 1).min(size);
+    if delta < 0 {
+      ((start + size - distance % size) % size, length)
+    } else {
+      (start, length)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::Region;
+  use crate::constants::SPACE_WIDTH;
+  use crate::engine::functions::location::to_index_from_xy;
+
+  #[test]
+  fn test_from_drag_across_the_edge() {
+    let from: usize = to_index_from_xy(SPACE_WIDTH - 5, 10);
+    let region = Region::from_drag(from, 10, -2);
+    assert!(
+      region
+        == Region {
+          height: 3,
+          width: 11,
+          x: SPACE_WIDTH - 5,
+          y: 8,
+        }
+    );
+    let indices: Vec<usize> = region.to_indices();
+    assert_eq!(indices.len(), 33);
+    assert!(indices.contains(&to_index_from_xy(5, 10)));
+    assert!(indices.contains(&to_index_from_xy(SPACE_WIDTH - 1, 8)));
+    assert!(!indices.contains(&to_index_from_xy(6, 10)));
+    assert!(!indices.contains(&to_index_from_xy(SPACE_WIDTH / 2, 10)));
+  }
+
+  #[test]
+  fn test_from_drag_backward() {
+    let from: usize = to_index_from_xy(3, 4);
+    let region = Region::from_drag(from, -5, 0);
+    assert!(
+      region
+        == Region {
+          height: 1,
+          width: 6,
+          x: SPACE_WIDTH - 2,
+          y: 4,
+        }
+    );
+  }
+}
@@ -0,0 +1,79 @@
+// =============================================================================
+//! - Reset model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::genome::Genome;
+use super::region::Region;
+use crate::constants::BUGS_MAX;
+
+// Where the genomes of the founders of a new run come from
+#[derive(Clone, Copy, PartialEq)]
+pub enum GenomeSource {
+  Random,
+  // Copies of the click genome or of one random genome if there is none
+  Single,
+  // Cycles through the genomes from the last import
+  Supplied,
+  // Cycles through the genomes of the bugs alive when reset
+  Survivors,
+}
+
+impl Default for GenomeSource {
+  fn default() -> Self {
+    GenomeSource::Random
+  }
+}
+
+// Where the founders of a new run are placed
+#[derive(Clone, Copy, PartialEq)]
+pub enum Placement {
+  // All at the center of the space
+  Center,
+  // Scattered over a random garden for each or centered if there are none
+  Gardens,
+  // Scattered over the region drawn by the user or centered if none
+  Region,
+  // Scattered over the whole space
+  Uniform,
+}
+
+impl Default for Placement {
+  fn default() -> Self {
+    Placement::Center
+  }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct ResetSettings {
+  pub genome_source: GenomeSource,
+  pub placement: Placement,
+  pub population: usize,
+}
+
+impl Default for ResetSettings {
+  fn default() -> Self {
+    Self {
+      genome_source: GenomeSource::default(),
+      placement: Placement::default(),
+      population: BUGS_MAX,
+    }
+  }
+}
+
+#[derive(Default)]
+pub struct Reset {
+  // The genomes from the last import
+  pub genomes_supplied: Vec<Genome>,
+  // The region drawn by the user for the Region placement
+  pub region: Option<Region>,
+  pub settings: ResetSettings,
+}
@@ -2,26 +2,46 @@
 //! - Root Model for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-12-10
-//! - Updated: 2023-03-08
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+use super::classifier::Classifier;
 use super::clock::Clock;
+use super::environment::Environment;
 use super::fauna::Fauna;
 use super::flora::Flora;
+use super::gene_history::GeneHistory;
+use super::heat_maps::HeatMaps;
 use super::overlay::Overlay;
+use super::profiler::Profiler;
+use super::random::Random;
+use super::reset::Reset;
+use super::selection::Selection;
+use super::statistics::Statistics;
+use super::viewport::Viewport;
 use core::cell::RefCell;
 use std::rc::Rc;
 
 #[derive(Default)]
 pub struct Root {
+  pub classifier: Rc<RefCell<Classifier>>,
   pub clock: Rc<RefCell<Clock>>,
+  pub environment: Rc<RefCell<Environment>>,
   pub fauna: Rc<RefCell<Fauna>>,
   pub flora: Rc<RefCell<Flora>>,
+  pub gene_history: Rc<RefCell<GeneHistory>>,
+  pub heat_maps: Rc<RefCell<HeatMaps>>,
   pub overlay: Rc<RefCell<Overlay>>,
+  pub profiler: Rc<RefCell<Profiler>>,
+  pub random: Rc<RefCell<Random>>,
+  pub reset: Rc<RefCell<Reset>>,
+  pub selection: Rc<RefCell<Selection>>,
+  pub statistics: Rc<RefCell<Statistics>>,
+  pub viewport: Rc<RefCell<Viewport>>,
 }
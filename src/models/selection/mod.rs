@@ -0,0 +1,18 @@
+// =============================================================================
+//! - Selection Model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+#[derive(Default)]
+pub struct Selection {
+  // Lineage of the bugs to highlight
+  pub lineage: Option<usize>,
+}
@@ -0,0 +1,181 @@
+// =============================================================================
+//! - Statistics Model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::fauna::Death;
+use crate::constants::{LIFESPAN_BIN_WIDTH, LOCUS_COUNT};
+use crate::engine::functions::diversity::Diversity;
+use crate::engine::functions::traits::TraitMeans;
+use std::collections::VecDeque;
+
+// Tallies of the completed lives of the bugs of one species.  The bugs still
+// alive are not included.
+#[derive(Clone, Default)]
+pub struct LifeHistory {
+  pub deaths: usize,
+  // Sum of the ages at first reproduction of the bugs that reproduced
+  pub first_spawn_age_sum: usize,
+  // Deaths by age in bins of LIFESPAN_BIN_WIDTH updates
+  pub lifespan_histogram: Vec<usize>,
+  pub lifespan_sum: usize,
+  // Sum of the lifetime reproductive success over the completed lives
+  pub offspring_sum: usize,
+  // Number of bugs that reproduced at least once
+  pub parents: usize,
+}
+
+impl LifeHistory {
+  pub fn record(
+    &mut self,
+    death: &Death,
+  ) {
+    self.deaths += 1;
+    if let Some(first_spawn_age) = death.first_spawn_age {
+      self.first_spawn_age_sum += first_spawn_age;
+      self.parents += 1;
+    }
+    let bin: usize = death.age / LIFESPAN_BIN_WIDTH;
+    if bin >= self.lifespan_histogram.len() {
+      self.lifespan_histogram.resize(bin + 1, 0);
+    }
+    self.lifespan_histogram[bin] += 1;
+    self.lifespan_sum += death.age;
+    self.offspring_sum += death.offspring;
+  }
+
+  // The upper edge of the bin holding the lifespan quantile or zero if none
+  pub fn to_lifespan_quantile(
+    &self,
+    quantile: f64,
+  ) -> usize {
+    let rank: f64 = quantile * self.deaths as f64;
+    let mut count: usize = 0;
+    for (bin, bin_count) in self.lifespan_histogram.iter().enumerate() {
+      count += bin_count;
+      if count as f64 >= rank && count > 0 {
+        return (bin + 1) * LIFESPAN_BIN_WIDTH;
+      }
+    }
+    0
+  }
+}
+
+// A simulation parameter that changed after the reset
+pub struct ParameterChange {
+  pub name: &'static str,
+  // The tick of the first sample taken with the new value
+  pub tick: usize,
+  pub value: String,
+}
+
+pub struct StatisticsSample {
+  pub diversity: Diversity,
+  pub energy_max: usize,
+  pub energy_mean: f64,
+  pub energy_min: usize,
+  // Food energy available summed over the locations
+  pub flora_biomass: usize,
+  pub flora_coverage: f64,
+  pub flora_growth_rate: usize,
+  pub garden_enabled: bool,
+  pub gene_frequencies: [f64; LOCUS_COUNT],
+  // Environmental multiplier of the food growth rate
+  pub growth_factor: f64,
+  pub population: usize,
+  // Indexed like the species names, which may have grown since the sample
+  pub species_counts: Vec<usize>,
+  pub tick: usize,
+  pub trait_means: TraitMeans,
+}
+
+// One sample per simulation update, oldest first
+#[derive(Default)]
+pub struct Statistics {
+  // Species name index of each classifier class as of the latest sample
+  pub class_name_indices: Vec<usize>,
+  // Indexed like the species names
+  pub life_histories: Vec<LifeHistory>,
+  pub parameter_changes: Vec<ParameterChange>,
+  // The simulation parameters as of the reset
+  pub parameters: Vec<(&'static str, String)>,
+  // The simulation parameters as of the latest sample
+  pub parameters_current: Vec<(&'static str, String)>,
+  pub samples: VecDeque<StatisticsSample>,
+  // Every species class name seen during the run, in order of appearance
+  pub species_names: Vec<String>,
+}
+
+impl Statistics {
+  pub fn clear(&mut self) {
+    self.class_name_indices.clear();
+    self.life_histories.clear();
+    self.parameter_changes.clear();
+    self.parameters.clear();
+    self.parameters_current.clear();
+    self.samples.clear();
+    self.species_names.clear();
+  }
+
+  // The bugs died classified by the classes as of the latest sample
+  pub fn record_deaths(
+    &mut self,
+    deaths: &[Death],
+  ) {
+    self
+      .life_histories
+      .resize(self.species_names.len(), LifeHistory::default());
+    for death in deaths {
+      if let Some(index) = self.class_name_indices.get(death.species) {
+        self.life_histories[*index].record(death);
+      }
+    }
+  }
+
+  // Snapshots the parameters after a clear and otherwise records the changes
+  pub fn record_parameters(
+    &mut self,
+    parameters: Vec<(&'static str, String)>,
+    tick: usize,
+  ) {
+    if self.parameters.is_empty() {
+      self.parameters = parameters.clone();
+    } else {
+      for ((name, value), (_, value_current)) in
+        parameters.iter().zip(self.parameters_current.iter())
+      {
+        if value != value_current {
+          self.parameter_changes.push(ParameterChange {
+            name,
+            tick,
+            value: value.clone(),
+          });
+        }
+      }
+    }
+    self.parameters_current = parameters;
+  }
+
+  pub fn register_species_name(
+    &mut self,
+    species_name: &str,
+  ) -> usize {
+    if let Some(index) = self
+      .species_names
+      .iter()
+      .position(|name| name == species_name)
+    {
+      return index;
+    }
+    self.species_names.push(String::from(species_name));
+    self.species_names.len() - 1
+  }
+}
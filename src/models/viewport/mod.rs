@@ -0,0 +1,31 @@
+// =============================================================================
+//! - Viewport Model for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{SPACE_HEIGHT, SPACE_WIDTH};
+
+// The visible part of the world in cells; a zoom of one fits the world
+pub struct Viewport {
+  pub center_x: f64,
+  pub center_y: f64,
+  pub zoom: f64,
+}
+
+impl Default for Viewport {
+  fn default() -> Self {
+    Self {
+      center_x: SPACE_WIDTH as f64 / 2.,
+      center_y: SPACE_HEIGHT as f64 / 2.,
+      zoom: 1.,
+    }
+  }
+}
@@ -2,79 +2,197 @@
 //! - Fauna Painter for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-12-10
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
-use crate::constants::{PAINT_OFFSET, PAINT_SCALE};
-use crate::engine::functions::location::{to_x_from_index, to_y_from_index};
-use crate::models::bug::Species;
+use crate::constants::{
+  HEADING_SCALE, MAX_ENERGY, PAINT_OFFSET, PAINT_SCALE, RGBA_HEADING,
+};
+use crate::engine::functions::color::{
+  to_categorical_rgba, to_sequential_rgba,
+};
+use crate::engine::functions::genes::to_genome_key;
+use crate::engine::functions::location::{
+  to_interpolated_xy, to_x_from_index, to_y_from_index,
+};
+use crate::engine::raster::Raster;
+use crate::engine::viewport::ViewTransform;
+use crate::models::classifier::Classifier;
+use crate::models::clock::Clock;
 use crate::models::fauna::Fauna;
+use crate::models::options::{ColorMode, Options, Palette};
+use crate::models::viewport::Viewport;
 use com_croftsoft_lib_role::Painter;
-use core::cell::RefCell;
+use core::cell::{Ref, RefCell, RefMut};
 use std::rc::Rc;
-use wasm_bindgen::JsValue;
-use web_sys::CanvasRenderingContext2d;
+
+pub type RgbaFunction<'a> = Box<dyn Fn(usize) -> [u8; 4] + 'a>;
 
 pub struct FaunaPainter {
-  bug_color_cruiser: JsValue,
-  bug_color_normal: JsValue,
-  bug_color_twirler: JsValue,
-  bug_height: f64,
-  bug_width: f64,
-  context: Rc<RefCell<CanvasRenderingContext2d>>,
+  canvas_height: f64,
+  canvas_width: f64,
+  classifier: Rc<RefCell<Classifier>>,
+  clock: Rc<RefCell<Clock>>,
   fauna: Rc<RefCell<Fauna>>,
-  scale_x: f64,
-  scale_y: f64,
+  options: Rc<RefCell<Options>>,
+  raster: Rc<RefCell<Raster>>,
+  viewport: Rc<RefCell<Viewport>>,
 }
 
 impl FaunaPainter {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
-    context: Rc<RefCell<CanvasRenderingContext2d>>,
+    canvas_height: f64,
+    canvas_width: f64,
+    classifier: Rc<RefCell<Classifier>>,
+    clock: Rc<RefCell<Clock>>,
     fauna: Rc<RefCell<Fauna>>,
-    scale_x: f64,
-    scale_y: f64,
+    options: Rc<RefCell<Options>>,
+    raster: Rc<RefCell<Raster>>,
+    viewport: Rc<RefCell<Viewport>>,
   ) -> Self {
-    let bug_color_cruiser = JsValue::from_str("red");
-    let bug_color_normal = JsValue::from_str("magenta");
-    let bug_color_twirler = JsValue::from_str("blue");
-    let bug_height = (PAINT_SCALE * scale_y).trunc();
-    let bug_width = (PAINT_SCALE * scale_x).trunc();
     Self {
-      bug_color_cruiser,
-      bug_color_normal,
-      bug_color_twirler,
-      bug_height,
-      bug_width,
-      context,
+      canvas_height,
+      canvas_width,
+      classifier,
+      clock,
       fauna,
-      scale_x,
-      scale_y,
+      options,
+      raster,
+      viewport,
     }
   }
 }
 
 impl Painter for FaunaPainter {
   fn paint(&self) {
-    let context = self.context.borrow();
-    for bug in self.fauna.borrow().bugs.iter() {
-      let bug_color = match bug.species {
-        Species::Cruiser => &self.bug_color_cruiser,
-        Species::Normal => &self.bug_color_normal,
-        Species::Twirlie => &self.bug_color_twirler,
+    let mut raster: RefMut<Raster> = self.raster.borrow_mut();
+    let classifier: Ref<Classifier> = self.classifier.borrow();
+    let clock: Ref<Clock> = self.clock.borrow();
+    let fauna: Ref<Fauna> = self.fauna.borrow();
+    let options: Ref<Options> = self.options.borrow();
+    let to_rgba = make_rgba_function(&fauna, &classifier, &clock, &options);
+    let view_transform = ViewTransform::new(
+      &self.viewport.borrow(),
+      self.canvas_width,
+      self.canvas_height,
+    );
+    for index in 0..fauna.len() {
+      let (x, y) = to_display_xy(&fauna, index, clock.update_fraction);
+      view_transform.fill_rect(
+        &mut raster,
+        x + PAINT_OFFSET,
+        y + PAINT_OFFSET,
+        PAINT_SCALE,
+        PAINT_SCALE,
+        to_rgba(index),
+      );
+      if !options.heading_display {
+        continue;
+      }
+      // A mark just outside the square in the direction of the current genes
+      let heading_x: f64 = if fauna.genes_x[index][clock.time] {
+        1.
+      } else {
+        -1.
+      };
+      let heading_y: f64 = if fauna.genes_y[index][clock.time] {
+        1.
+      } else {
+        -1.
       };
-      context.set_fill_style(bug_color);
-      let index = bug.position;
-      let x: f64 = to_x_from_index(index) as f64;
-      let y: f64 = to_y_from_index(index) as f64;
-      let corner_x = (self.scale_x * (x + PAINT_OFFSET)).trunc();
-      let corner_y = (self.scale_y * (y + PAINT_OFFSET)).trunc();
-      context.fill_rect(corner_x, corner_y, self.bug_width, self.bug_height);
+      let glyph_offset: f64 = (PAINT_SCALE + HEADING_SCALE) / 2.;
+      view_transform.fill_rect(
+        &mut raster,
+        x + 0.5 + heading_x * glyph_offset - HEADING_SCALE / 2.,
+        y + 0.5 + heading_y * glyph_offset - HEADING_SCALE / 2.,
+        HEADING_SCALE,
+        HEADING_SCALE,
+        RGBA_HEADING,
+      );
     }
   }
 }
+
+// Generations and ages are relative to the largest in the population.
+// The returned function maps an index into the fauna columns to a color.
+pub fn make_rgba_function<'a>(
+  fauna: &'a Fauna,
+  classifier: &'a Classifier,
+  clock: &Clock,
+  options: &Options,
+) -> RgbaFunction<'a> {
+  let palette: Palette = options.palette;
+  match options.color_mode {
+    ColorMode::Age => {
+      let tick: usize = clock.tick;
+      let age_max: usize = fauna
+        .birth_ticks
+        .iter()
+        .map(|birth_tick| tick.saturating_sub(*birth_tick))
+        .max()
+        .unwrap_or(0)
+        .max(1);
+      Box::new(move |index: usize| {
+        let age: usize = tick.saturating_sub(fauna.birth_ticks[index]);
+        to_sequential_rgba(palette, age as f64 / age_max as f64)
+      })
+    },
+    ColorMode::Energy => Box::new(move |index: usize| {
+      to_sequential_rgba(
+        palette,
+        fauna.energies[index] as f64 / MAX_ENERGY as f64,
+      )
+    }),
+    ColorMode::Generation => {
+      let generation_max: usize =
+        fauna.generations.iter().copied().max().unwrap_or(0).max(1);
+      Box::new(move |index: usize| {
+        to_sequential_rgba(
+          palette,
+          fauna.generations[index] as f64 / generation_max as f64,
+        )
+      })
+    },
+    ColorMode::Genotype => Box::new(move |index: usize| {
+      let genome_key: u16 =
+        to_genome_key(&fauna.genes_x[index], &fauna.genes_y[index]);
+      to_categorical_rgba(palette, genome_key as usize)
+    }),
+    ColorMode::Lineage => Box::new(move |index: usize| {
+      to_categorical_rgba(palette, fauna.lineages[index])
+    }),
+    ColorMode::Species => match palette {
+      Palette::ColorBlind => Box::new(move |index: usize| {
+        to_categorical_rgba(palette, fauna.species[index])
+      }),
+      Palette::Standard => Box::new(move |index: usize| {
+        classifier.classes[fauna.species[index]].color
+      }),
+    },
+  }
+}
+
+// Between the previous and current locations while the motion is interpolated
+pub fn to_display_xy(
+  fauna: &Fauna,
+  index: usize,
+  update_fraction: f64,
+) -> (f64, f64) {
+  let position: usize = fauna.positions[index];
+  match fauna.get_trail(index).next() {
+    Some(position_previous) if update_fraction < 1. => {
+      to_interpolated_xy(position_previous, position, update_fraction)
+    },
+    _ => (
+      to_x_from_index(position) as f64,
+      to_y_from_index(position) as f64,
+    ),
+  }
+}
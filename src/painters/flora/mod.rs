@@ -2,79 +2,84 @@
 //! - Flora Painter for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-12-10
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
-use crate::constants::{PAINT_OFFSET, PAINT_SCALE};
+use crate::constants::{
+  BIOMASS_CAPACITY, FLORA_ALPHA_MIN, PAINT_OFFSET, PAINT_SCALE, RGBA_FLORA,
+};
 use crate::engine::functions::location::{to_x_from_index, to_y_from_index};
-use crate::models::flora::Flora;
+use crate::engine::raster::Raster;
+use crate::engine::viewport::ViewTransform;
+use crate::models::flora::{Flora, FloraMode};
+use crate::models::viewport::Viewport;
 use com_croftsoft_lib_role::Painter;
-use core::cell::RefCell;
+use core::cell::{Ref, RefCell, RefMut};
 use std::rc::Rc;
-use wasm_bindgen::JsValue;
-use web_sys::CanvasRenderingContext2d;
 
 pub struct FloraPainter {
-  context: Rc<RefCell<CanvasRenderingContext2d>>,
-  fill_style: JsValue,
+  canvas_height: f64,
+  canvas_width: f64,
   flora: Rc<RefCell<Flora>>,
-  flora_height: f64,
-  flora_width: f64,
-  scale_x: f64,
-  scale_y: f64,
+  raster: Rc<RefCell<Raster>>,
+  viewport: Rc<RefCell<Viewport>>,
 }
 
 impl FloraPainter {
   pub fn new(
-    context: Rc<RefCell<CanvasRenderingContext2d>>,
+    canvas_height: f64,
+    canvas_width: f64,
     flora: Rc<RefCell<Flora>>,
-    scale_x: f64,
-    scale_y: f64,
+    raster: Rc<RefCell<Raster>>,
+    viewport: Rc<RefCell<Viewport>>,
   ) -> Self {
-    let fill_style = JsValue::from_str("green");
-    let flora_height = (PAINT_SCALE * scale_y).trunc();
-    let flora_width = (PAINT_SCALE * scale_x).trunc();
     Self {
-      context,
-      fill_style,
-      flora_height,
-      flora_width,
+      canvas_height,
+      canvas_width,
       flora,
-      scale_x,
-      scale_y,
+      raster,
+      viewport,
     }
   }
 }
 
 impl Painter for FloraPainter {
   fn paint(&self) {
-    let context = self.context.borrow();
-    context.set_fill_style(&self.fill_style);
-    self
-      .flora
-      .borrow()
-      .flora_present
-      .iter()
-      .enumerate()
-      .for_each(|(index, location)| {
-        if *location {
-          let x: f64 = to_x_from_index(index) as f64;
-          let y: f64 = to_y_from_index(index) as f64;
-          let corner_x = (self.scale_x * (x + PAINT_OFFSET)).trunc();
-          let corner_y = (self.scale_y * (y + PAINT_OFFSET)).trunc();
-          context.fill_rect(
-            corner_x,
-            corner_y,
-            self.flora_width,
-            self.flora_height,
-          );
-        }
-      });
+    let mut raster: RefMut<Raster> = self.raster.borrow_mut();
+    let view_transform = ViewTransform::new(
+      &self.viewport.borrow(),
+      self.canvas_width,
+      self.canvas_height,
+    );
+    let flora: Ref<Flora> = self.flora.borrow();
+    for (index, present) in flora.flora_present.iter().enumerate() {
+      if !*present {
+        continue;
+      }
+      let mut rgba: [u8; 4] = RGBA_FLORA;
+      // Intensity proportional to the biomass with a floor so that a sliver
+      // of food is still visible
+      if flora.flora_mode == FloraMode::Biomass {
+        rgba[3] = (RGBA_FLORA[3] as usize * flora.biomass[index]
+          / BIOMASS_CAPACITY)
+          .max(FLORA_ALPHA_MIN as usize) as u8;
+      }
+      let x: f64 = to_x_from_index(index) as f64;
+      let y: f64 = to_y_from_index(index) as f64;
+      view_transform.fill_rect(
+        &mut raster,
+        x + PAINT_OFFSET,
+        y + PAINT_OFFSET,
+        PAINT_SCALE,
+        PAINT_SCALE,
+        rgba,
+      );
+    }
   }
 }
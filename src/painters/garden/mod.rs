@@ -0,0 +1,98 @@
+// =============================================================================
+//! - Garden Painter for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{
+  RGBA_GARDEN, RGBA_SELECTION, SPACE_HEIGHT, SPACE_WIDTH,
+};
+use crate::engine::raster::Raster;
+use crate::engine::viewport::ViewTransform;
+use crate::models::flora::Flora;
+use crate::models::viewport::Viewport;
+use com_croftsoft_lib_role::Painter;
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+// Outlines the gardens
+pub struct GardenPainter {
+  canvas_height: f64,
+  canvas_width: f64,
+  flora: Rc<RefCell<Flora>>,
+  raster: Rc<RefCell<Raster>>,
+  viewport: Rc<RefCell<Viewport>>,
+}
+
+impl GardenPainter {
+  pub fn new(
+    canvas_height: f64,
+    canvas_width: f64,
+    flora: Rc<RefCell<Flora>>,
+    raster: Rc<RefCell<Raster>>,
+    viewport: Rc<RefCell<Viewport>>,
+  ) -> Self {
+    Self {
+      canvas_height,
+      canvas_width,
+      flora,
+      raster,
+      viewport,
+    }
+  }
+}
+
+impl Painter for GardenPainter {
+  fn paint(&self) {
+    let mut raster: RefMut<Raster> = self.raster.borrow_mut();
+    let view_transform = ViewTransform::new(
+      &self.viewport.borrow(),
+      self.canvas_width,
+      self.canvas_height,
+    );
+    let space_height: f64 = SPACE_HEIGHT as f64;
+    let space_width: f64 = SPACE_WIDTH as f64;
+    let flora: Ref<Flora> = self.flora.borrow();
+    for (index, garden) in flora.gardens.iter().enumerate() {
+      let rgba: [u8; 4] = if flora.garden_selected == Some(index) {
+        RGBA_SELECTION
+      } else {
+        RGBA_GARDEN
+      };
+      let width: f64 = garden.width as f64;
+      let height: f64 = garden.height as f64;
+      // A garden that wraps around an edge is outlined again from the
+      // opposite side so that both parts show
+      for x0 in [
+        garden.x as f64,
+        garden.x as f64 - space_width,
+      ] {
+        for y0 in [
+          garden.y as f64,
+          garden.y as f64 - space_height,
+        ] {
+          if x0 + width <= 0. || y0 + height <= 0. {
+            continue;
+          }
+          let x1: f64 = x0 + width;
+          let y1: f64 = y0 + height;
+          for (x, y, w, h) in [
+            (x0, y0, width, 0.),
+            (x0, y1, width, 0.),
+            (x0, y0, 0., height),
+            (x1, y0, 0., height),
+          ] {
+            view_transform.fill_rect(&mut raster, x, y, w, h, rgba);
+          }
+        }
+      }
+    }
+  }
+}
@@ -0,0 +1,128 @@
+// =============================================================================
+//! - Gene Heat Map Painter for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{GENE_HEAT_MAP_COLUMNS_MAX, LOCUS_COUNT};
+use crate::engine::functions::genes::to_locus_label;
+use crate::models::gene_history::GeneHistory;
+use crate::models::options::Options;
+use com_croftsoft_lib_role::Painter;
+use core::cell::{Ref, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::CanvasRenderingContext2d;
+
+const FONT_SIZE: f64 = 7.;
+const LABEL_WIDTH: f64 = 30.;
+const ROW_HEIGHT: f64 = 8.;
+
+// The height of the panel along the bottom of the canvas
+pub fn to_panel_height(font_scale: f64) -> f64 {
+  (ROW_HEIGHT * font_scale).round() * LOCUS_COUNT as f64
+}
+
+pub struct GeneHeatMapPainter {
+  background_fill_style: JsValue,
+  canvas_height: f64,
+  cell_width: f64,
+  context: Rc<RefCell<CanvasRenderingContext2d>>,
+  font: String,
+  font_scale: f64,
+  gene_history: Rc<RefCell<GeneHistory>>,
+  label_fill_style: JsValue,
+  label_margin: f64,
+  label_width: f64,
+  options: Rc<RefCell<Options>>,
+  row_height: f64,
+}
+
+impl GeneHeatMapPainter {
+  // Blue where no living bug carries a 1, yellow where every bug does
+  fn make_fill_style(frequency: f64) -> JsValue {
+    let level: u8 = (255. * frequency.clamp(0., 1.)).round() as u8;
+    JsValue::from_str(&format!("rgb({},{},{})", level, level, 255 - level))
+  }
+
+  pub fn new(
+    canvas_height: f64,
+    canvas_width: f64,
+    context: Rc<RefCell<CanvasRenderingContext2d>>,
+    font_scale: f64,
+    gene_history: Rc<RefCell<GeneHistory>>,
+    options: Rc<RefCell<Options>>,
+  ) -> Self {
+    let background_fill_style = JsValue::from_str("rgba(0,0,0,0.75)");
+    let label_margin = (4. * font_scale).round();
+    let label_width = (LABEL_WIDTH * font_scale).round();
+    let cell_width =
+      (canvas_width - label_width) / (GENE_HEAT_MAP_COLUMNS_MAX + 1) as f64;
+    let font = format!("{}px monospace", (FONT_SIZE * font_scale).round());
+    let label_fill_style = JsValue::from_str("white");
+    let row_height = (ROW_HEIGHT * font_scale).round();
+    Self {
+      background_fill_style,
+      canvas_height,
+      cell_width,
+      context,
+      font,
+      font_scale,
+      gene_history,
+      label_fill_style,
+      label_margin,
+      label_width,
+      options,
+      row_height,
+    }
+  }
+}
+
+impl Painter for GeneHeatMapPainter {
+  fn paint(&self) {
+    if !self.options.borrow().gene_heat_map_display {
+      return;
+    }
+    let context = self.context.borrow();
+    let gene_history: Ref<GeneHistory> = self.gene_history.borrow();
+    let panel_height = to_panel_height(self.font_scale);
+    let panel_top = self.canvas_height - panel_height;
+    let panel_width = self.label_width
+      + self.cell_width * (GENE_HEAT_MAP_COLUMNS_MAX + 1) as f64;
+    context.set_fill_style(&self.background_fill_style);
+    context.fill_rect(0., panel_top, panel_width, panel_height);
+    context.set_fill_style(&self.label_fill_style);
+    context.set_font(&self.font);
+    for locus in 0..LOCUS_COUNT {
+      let row_bottom = panel_top + self.row_height * (locus + 1) as f64;
+      context
+        .fill_text(&to_locus_label(locus), self.label_margin, row_bottom - 1.)
+        .unwrap();
+    }
+    let columns = gene_history
+      .columns
+      .iter()
+      .copied()
+      .chain(gene_history.get_bucket_average());
+    for (column_index, column) in columns.enumerate() {
+      let column_left =
+        self.label_width + self.cell_width * column_index as f64;
+      for (locus, frequency) in column.iter().enumerate() {
+        context.set_fill_style(&Self::make_fill_style(*frequency));
+        context.fill_rect(
+          column_left,
+          panel_top + self.row_height * locus as f64,
+          self.cell_width.ceil(),
+          self.row_height,
+        );
+      }
+    }
+  }
+}
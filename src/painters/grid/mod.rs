@@ -0,0 +1,81 @@
+// =============================================================================
+//! - Grid Painter for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{GRID_CELL_PIXELS_MIN, GRID_SPACING, RGBA_GRID};
+use crate::constants::{SPACE_HEIGHT, SPACE_WIDTH};
+use crate::engine::raster::Raster;
+use crate::engine::viewport::ViewTransform;
+use crate::models::viewport::Viewport;
+use com_croftsoft_lib_role::Painter;
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+pub struct GridPainter {
+  canvas_height: f64,
+  canvas_width: f64,
+  raster: Rc<RefCell<Raster>>,
+  viewport: Rc<RefCell<Viewport>>,
+}
+
+impl GridPainter {
+  pub fn new(
+    canvas_height: f64,
+    canvas_width: f64,
+    raster: Rc<RefCell<Raster>>,
+    viewport: Rc<RefCell<Viewport>>,
+  ) -> Self {
+    Self {
+      canvas_height,
+      canvas_width,
+      raster,
+      viewport,
+    }
+  }
+}
+
+impl Painter for GridPainter {
+  fn paint(&self) {
+    let mut raster: RefMut<Raster> = self.raster.borrow_mut();
+    let viewport: Ref<Viewport> = self.viewport.borrow();
+    let view_transform =
+      ViewTransform::new(&viewport, self.canvas_width, self.canvas_height);
+    // Every cell when zoomed in far enough to see them, otherwise every tenth
+    let cell_pixels: f64 =
+      self.canvas_width * viewport.zoom / SPACE_WIDTH as f64;
+    let spacing: usize = if cell_pixels >= GRID_CELL_PIXELS_MIN {
+      1
+    } else {
+      GRID_SPACING
+    };
+    for x in (0..SPACE_WIDTH).step_by(spacing) {
+      view_transform.fill_rect(
+        &mut raster,
+        x as f64,
+        0.,
+        0.,
+        SPACE_HEIGHT as f64,
+        RGBA_GRID,
+      );
+    }
+    for y in (0..SPACE_HEIGHT).step_by(spacing) {
+      view_transform.fill_rect(
+        &mut raster,
+        0.,
+        y as f64,
+        SPACE_WIDTH as f64,
+        0.,
+        RGBA_GRID,
+      );
+    }
+  }
+}
@@ -0,0 +1,109 @@
+// =============================================================================
+//! - Heat Maps Painter for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{HEAT_MAP_ALPHA_MAX, RGBA_DENSITY, RGBA_GRAZING};
+use crate::engine::functions::location::{to_x_from_index, to_y_from_index};
+use crate::engine::raster::Raster;
+use crate::engine::viewport::ViewTransform;
+use crate::models::heat_maps::{HeatMap, HeatMaps};
+use crate::models::options::Options;
+use crate::models::viewport::Viewport;
+use com_croftsoft_lib_role::Painter;
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+pub struct HeatMapsPainter {
+  canvas_height: f64,
+  canvas_width: f64,
+  heat_maps: Rc<RefCell<HeatMaps>>,
+  options: Rc<RefCell<Options>>,
+  raster: Rc<RefCell<Raster>>,
+  viewport: Rc<RefCell<Viewport>>,
+}
+
+impl HeatMapsPainter {
+  pub fn new(
+    canvas_height: f64,
+    canvas_width: f64,
+    heat_maps: Rc<RefCell<HeatMaps>>,
+    options: Rc<RefCell<Options>>,
+    raster: Rc<RefCell<Raster>>,
+    viewport: Rc<RefCell<Viewport>>,
+  ) -> Self {
+    Self {
+      canvas_height,
+      canvas_width,
+      heat_maps,
+      options,
+      raster,
+      viewport,
+    }
+  }
+
+  // Tints each location in proportion to the square root of its share of the
+  // hottest location so that sparse territory remains visible
+  fn paint_heat_map(
+    heat_map: &HeatMap,
+    raster: &mut Raster,
+    rgba: [u8; 4],
+    view_transform: &ViewTransform,
+  ) {
+    let value_max: f64 = heat_map.get_value_max();
+    if value_max <= 0. {
+      return;
+    }
+    let mut rgba: [u8; 4] = rgba;
+    for (index, value) in heat_map.values.iter().enumerate() {
+      let level: f64 = (value / value_max).sqrt();
+      rgba[3] = (level * HEAT_MAP_ALPHA_MAX as f64).round() as u8;
+      if rgba[3] == 0 {
+        continue;
+      }
+      let x: f64 = to_x_from_index(index) as f64;
+      let y: f64 = to_y_from_index(index) as f64;
+      view_transform.fill_rect(raster, x, y, 1., 1., rgba);
+    }
+  }
+}
+
+impl Painter for HeatMapsPainter {
+  fn paint(&self) {
+    let options: Ref<Options> = self.options.borrow();
+    if !options.density_heat_map_display && !options.grazing_heat_map_display {
+      return;
+    }
+    let heat_maps: Ref<HeatMaps> = self.heat_maps.borrow();
+    let mut raster: RefMut<Raster> = self.raster.borrow_mut();
+    let view_transform = ViewTransform::new(
+      &self.viewport.borrow(),
+      self.canvas_width,
+      self.canvas_height,
+    );
+    if options.density_heat_map_display {
+      Self::paint_heat_map(
+        &heat_maps.density,
+        &mut raster,
+        RGBA_DENSITY,
+        &view_transform,
+      );
+    }
+    if options.grazing_heat_map_display {
+      Self::paint_heat_map(
+        &heat_maps.grazing,
+        &mut raster,
+        RGBA_GRAZING,
+        &view_transform,
+      );
+    }
+  }
+}
@@ -0,0 +1,363 @@
+// =============================================================================
+//! - Layers for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+// The registry of the layers painted in order from the bottom up.  A new
+// painter is added by appending a LayerDescriptor to LAYERS.
+
+use super::fauna::FaunaPainter;
+use super::flora::FloraPainter;
+use super::garden::GardenPainter;
+use super::gene_heat_map::GeneHeatMapPainter;
+use super::grid::GridPainter;
+use super::heat_maps::HeatMapsPainter;
+use super::legend::LegendPainter;
+use super::overlay::OverlayPainter;
+use super::profiler::ProfilerPainter;
+use super::raster::RasterPainter;
+use super::region::RegionPainter;
+use super::remains::RemainsPainter;
+use super::selection::SelectionPainter;
+use super::trail::TrailPainter;
+use super::traits::TraitsPainter;
+use crate::engine::raster::Raster;
+use crate::models::options::Options;
+use crate::models::root::Root;
+use com_croftsoft_lib_role::Painter;
+use core::cell::RefCell;
+use std::rc::Rc;
+use web_sys::CanvasRenderingContext2d;
+
+// What a layer needs to make its painter
+pub struct LayerContext<'a> {
+  pub canvas_height: f64,
+  pub canvas_width: f64,
+  pub context: Rc<RefCell<CanvasRenderingContext2d>>,
+  // Text keeps its size in CSS pixels but shrinks to fit a small canvas
+  pub font_scale: f64,
+  pub options: Rc<RefCell<Options>>,
+  // Filled by the world layers and copied to the canvas by the raster layer
+  pub raster: Rc<RefCell<Raster>>,
+  pub root_model: &'a Root,
+}
+
+pub struct LayerDescriptor {
+  // Unique identifier used for the visibility option and the control
+  pub id: &'static str,
+  // Label of the visibility control or None if shown by other controls
+  pub label: Option<&'static str>,
+  pub make_painter: fn(&LayerContext) -> Box<dyn Painter>,
+  pub visible_initially: bool,
+}
+
+impl LayerDescriptor {
+  pub fn is_visible(
+    &self,
+    options: &Options,
+  ) -> bool {
+    if self.label.is_none() {
+      return true;
+    }
+    options
+      .layer_visibilities
+      .get(self.id)
+      .copied()
+      .unwrap_or(self.visible_initially)
+  }
+}
+
+pub static LAYERS: &[LayerDescriptor] = &[
+  LayerDescriptor {
+    id: "remains",
+    label: Some("Remains"),
+    make_painter: make_remains_painter,
+    visible_initially: true,
+  },
+  LayerDescriptor {
+    id: "flora",
+    label: Some("Food"),
+    make_painter: make_flora_painter,
+    visible_initially: true,
+  },
+  LayerDescriptor {
+    id: "heat-maps",
+    label: None,
+    make_painter: make_heat_maps_painter,
+    visible_initially: true,
+  },
+  LayerDescriptor {
+    id: "grid",
+    label: Some("Grid lines"),
+    make_painter: make_grid_painter,
+    visible_initially: false,
+  },
+  LayerDescriptor {
+    id: "garden",
+    label: Some("Garden outlines"),
+    make_painter: make_garden_painter,
+    visible_initially: false,
+  },
+  LayerDescriptor {
+    id: "region",
+    label: Some("Start region"),
+    make_painter: make_region_painter,
+    visible_initially: true,
+  },
+  LayerDescriptor {
+    id: "trail",
+    label: None,
+    make_painter: make_trail_painter,
+    visible_initially: true,
+  },
+  LayerDescriptor {
+    id: "fauna",
+    label: Some("Critters"),
+    make_painter: make_fauna_painter,
+    visible_initially: true,
+  },
+  LayerDescriptor {
+    id: "selection",
+    label: Some("Selection"),
+    make_painter: make_selection_painter,
+    visible_initially: true,
+  },
+  LayerDescriptor {
+    id: "raster",
+    label: None,
+    make_painter: make_raster_painter,
+    visible_initially: true,
+  },
+  LayerDescriptor {
+    id: "gene-heat-map",
+    label: None,
+    make_painter: make_gene_heat_map_painter,
+    visible_initially: true,
+  },
+  LayerDescriptor {
+    id: "traits",
+    label: None,
+    make_painter: make_traits_painter,
+    visible_initially: true,
+  },
+  LayerDescriptor {
+    id: "overlay",
+    label: Some("Overlay"),
+    make_painter: make_overlay_painter,
+    visible_initially: true,
+  },
+  LayerDescriptor {
+    id: "legend",
+    label: Some("Legend"),
+    make_painter: make_legend_painter,
+    visible_initially: false,
+  },
+  LayerDescriptor {
+    id: "profiler",
+    label: None,
+    make_painter: make_profiler_painter,
+    visible_initially: true,
+  },
+];
+
+// Skips the painter of a layer while it is hidden
+pub struct LayerPainter {
+  layer_descriptor: &'static LayerDescriptor,
+  options: Rc<RefCell<Options>>,
+  painter: Box<dyn Painter>,
+}
+
+impl LayerPainter {
+  pub fn new(
+    layer_context: &LayerContext,
+    layer_descriptor: &'static LayerDescriptor,
+  ) -> Self {
+    Self {
+      layer_descriptor,
+      options: layer_context.options.clone(),
+      painter: (layer_descriptor.make_painter)(layer_context),
+    }
+  }
+}
+
+impl Painter for LayerPainter {
+  fn paint(&self) {
+    if self.layer_descriptor.is_visible(&self.options.borrow()) {
+      self.painter.paint();
+    }
+  }
+}
+
+fn make_fauna_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  let root_model: &Root = layer_context.root_model;
+  Box::new(FaunaPainter::new(
+    layer_context.canvas_height,
+    layer_context.canvas_width,
+    root_model.classifier.clone(),
+    root_model.clock.clone(),
+    root_model.fauna.clone(),
+    layer_context.options.clone(),
+    layer_context.raster.clone(),
+    root_model.viewport.clone(),
+  ))
+}
+
+fn make_flora_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  Box::new(FloraPainter::new(
+    layer_context.canvas_height,
+    layer_context.canvas_width,
+    layer_context.root_model.flora.clone(),
+    layer_context.raster.clone(),
+    layer_context.root_model.viewport.clone(),
+  ))
+}
+
+fn make_garden_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  Box::new(GardenPainter::new(
+    layer_context.canvas_height,
+    layer_context.canvas_width,
+    layer_context.root_model.flora.clone(),
+    layer_context.raster.clone(),
+    layer_context.root_model.viewport.clone(),
+  ))
+}
+
+fn make_gene_heat_map_painter(
+  layer_context: &LayerContext
+) -> Box<dyn Painter> {
+  Box::new(GeneHeatMapPainter::new(
+    layer_context.canvas_height,
+    layer_context.canvas_width,
+    layer_context.context.clone(),
+    layer_context.font_scale,
+    layer_context.root_model.gene_history.clone(),
+    layer_context.options.clone(),
+  ))
+}
+
+fn make_grid_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  Box::new(GridPainter::new(
+    layer_context.canvas_height,
+    layer_context.canvas_width,
+    layer_context.raster.clone(),
+    layer_context.root_model.viewport.clone(),
+  ))
+}
+
+fn make_heat_maps_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  Box::new(HeatMapsPainter::new(
+    layer_context.canvas_height,
+    layer_context.canvas_width,
+    layer_context.root_model.heat_maps.clone(),
+    layer_context.options.clone(),
+    layer_context.raster.clone(),
+    layer_context.root_model.viewport.clone(),
+  ))
+}
+
+fn make_legend_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  let root_model: &Root = layer_context.root_model;
+  Box::new(LegendPainter::new(
+    layer_context.canvas_width,
+    root_model.classifier.clone(),
+    root_model.clock.clone(),
+    layer_context.context.clone(),
+    root_model.fauna.clone(),
+    layer_context.font_scale,
+    layer_context.options.clone(),
+  ))
+}
+
+fn make_overlay_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  Box::new(OverlayPainter::new(
+    layer_context.context.clone(),
+    layer_context.font_scale,
+    layer_context.options.clone(),
+    layer_context.root_model.overlay.clone(),
+  ))
+}
+
+fn make_profiler_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  Box::new(ProfilerPainter::new(
+    layer_context.canvas_height,
+    layer_context.canvas_width,
+    layer_context.context.clone(),
+    layer_context.font_scale,
+    layer_context.options.clone(),
+    layer_context.root_model.profiler.clone(),
+  ))
+}
+
+fn make_raster_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  Box::new(RasterPainter::new(
+    layer_context.context.clone(),
+    layer_context.raster.clone(),
+  ))
+}
+
+fn make_region_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  let root_model: &Root = layer_context.root_model;
+  Box::new(RegionPainter::new(
+    layer_context.canvas_height,
+    layer_context.canvas_width,
+    layer_context.options.clone(),
+    layer_context.raster.clone(),
+    root_model.reset.clone(),
+    root_model.viewport.clone(),
+  ))
+}
+
+fn make_remains_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  Box::new(RemainsPainter::new(
+    layer_context.canvas_height,
+    layer_context.canvas_width,
+    layer_context.root_model.flora.clone(),
+    layer_context.raster.clone(),
+    layer_context.root_model.viewport.clone(),
+  ))
+}
+
+fn make_selection_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  Box::new(SelectionPainter::new(
+    layer_context.canvas_height,
+    layer_context.canvas_width,
+    layer_context.root_model.clock.clone(),
+    layer_context.root_model.fauna.clone(),
+    layer_context.raster.clone(),
+    layer_context.root_model.selection.clone(),
+    layer_context.root_model.viewport.clone(),
+  ))
+}
+
+fn make_trail_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  let root_model: &Root = layer_context.root_model;
+  Box::new(TrailPainter::new(
+    layer_context.canvas_height,
+    layer_context.canvas_width,
+    root_model.classifier.clone(),
+    root_model.clock.clone(),
+    root_model.fauna.clone(),
+    layer_context.options.clone(),
+    layer_context.raster.clone(),
+    root_model.viewport.clone(),
+  ))
+}
+
+fn make_traits_painter(layer_context: &LayerContext) -> Box<dyn Painter> {
+  Box::new(TraitsPainter::new(
+    layer_context.canvas_height,
+    layer_context.canvas_width,
+    layer_context.context.clone(),
+    layer_context.root_model.fauna.clone(),
+    layer_context.font_scale,
+    layer_context.options.clone(),
+  ))
+}
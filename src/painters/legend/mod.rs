@@ -0,0 +1,200 @@
+// =============================================================================
+//! - Legend Painter for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::fauna::make_rgba_function;
+use crate::constants::{LEGEND_ENTRIES_MAX, MAX_ENERGY};
+use crate::engine::functions::color::to_sequential_rgba;
+use crate::engine::functions::genes::{to_genome_key, to_genome_string};
+use crate::models::classifier::Classifier;
+use crate::models::clock::Clock;
+use crate::models::fauna::Fauna;
+use crate::models::options::{ColorMode, Options};
+use com_croftsoft_lib_role::Painter;
+use core::cell::{Ref, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::CanvasRenderingContext2d;
+
+const FONT_SIZE: f64 = 12.;
+const LEVEL_COUNT: usize = 5;
+
+// Explains the current mapping from bugs to colors in the upper right corner
+pub struct LegendPainter {
+  canvas_width: f64,
+  classifier: Rc<RefCell<Classifier>>,
+  clock: Rc<RefCell<Clock>>,
+  context: Rc<RefCell<CanvasRenderingContext2d>>,
+  fauna: Rc<RefCell<Fauna>>,
+  fill_style: JsValue,
+  font: String,
+  line_height: f64,
+  margin: f64,
+  options: Rc<RefCell<Options>>,
+}
+
+impl LegendPainter {
+  // The most common categories with the number of bugs in each
+  fn make_categorical_entries(
+    &self,
+    fauna: &Fauna,
+    options: &Options,
+  ) -> Vec<([u8; 4], String)> {
+    let classifier: Ref<Classifier> = self.classifier.borrow();
+    let to_rgba =
+      make_rgba_function(fauna, &classifier, &self.clock.borrow(), options);
+    let to_key = |index: usize| -> usize {
+      match options.color_mode {
+        ColorMode::Genotype => {
+          to_genome_key(&fauna.genes_x[index], &fauna.genes_y[index]) as usize
+        },
+        ColorMode::Lineage => fauna.lineages[index],
+        _ => fauna.species[index],
+      }
+    };
+    // The count and an example bug for each category
+    let mut counts: HashMap<usize, (usize, usize)> = HashMap::new();
+    for index in 0..fauna.len() {
+      counts.entry(to_key(index)).or_insert((0, index)).0 += 1;
+    }
+    let mut categories: Vec<(usize, usize, usize)> = counts
+      .into_iter()
+      .map(|(key, (count, index))| (key, count, index))
+      .collect();
+    categories.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    categories
+      .into_iter()
+      .take(LEGEND_ENTRIES_MAX)
+      .map(|(_key, count, index)| {
+        let name: String = match options.color_mode {
+          ColorMode::Genotype => {
+            to_genome_string(&fauna.genes_x[index], &fauna.genes_y[index])
+          },
+          ColorMode::Lineage => format!("Lineage {}", fauna.lineages[index]),
+          _ => classifier.classes[fauna.species[index]].name.clone(),
+        };
+        (to_rgba(index), format!("{} ({})", name, count))
+      })
+      .collect()
+  }
+
+  // Evenly spaced levels from zero to the largest value
+  fn make_sequential_entries(
+    &self,
+    fauna: &Fauna,
+    options: &Options,
+  ) -> Vec<([u8; 4], String)> {
+    let tick: usize = self.clock.borrow().tick;
+    let value_max: usize = match options.color_mode {
+      ColorMode::Age => fauna
+        .birth_ticks
+        .iter()
+        .map(|birth_tick| tick.saturating_sub(*birth_tick))
+        .max()
+        .unwrap_or(0)
+        .max(1),
+      ColorMode::Generation => {
+        fauna.generations.iter().copied().max().unwrap_or(0).max(1)
+      },
+      _ => MAX_ENERGY,
+    };
+    (0..LEVEL_COUNT)
+      .rev()
+      .map(|index| {
+        let level: f64 = index as f64 / (LEVEL_COUNT - 1) as f64;
+        let value: f64 = (level * value_max as f64).round();
+        (
+          to_sequential_rgba(options.palette, level),
+          format!("{}", value),
+        )
+      })
+      .collect()
+  }
+
+  pub fn new(
+    canvas_width: f64,
+    classifier: Rc<RefCell<Classifier>>,
+    clock: Rc<RefCell<Clock>>,
+    context: Rc<RefCell<CanvasRenderingContext2d>>,
+    fauna: Rc<RefCell<Fauna>>,
+    font_scale: f64,
+    options: Rc<RefCell<Options>>,
+  ) -> Self {
+    let fill_style: JsValue = JsValue::from_str("white");
+    let line_height: f64 = (FONT_SIZE * font_scale).round();
+    let font: String = format!("{}px monospace", line_height);
+    let margin: f64 = (4. * font_scale).round();
+    Self {
+      canvas_width,
+      classifier,
+      clock,
+      context,
+      fauna,
+      fill_style,
+      font,
+      line_height,
+      margin,
+      options,
+    }
+  }
+}
+
+impl Painter for LegendPainter {
+  fn paint(&self) {
+    let options: Ref<Options> = self.options.borrow();
+    let fauna: Ref<Fauna> = self.fauna.borrow();
+    let (title, entries) = match options.color_mode {
+      ColorMode::Age => ("Age", self.make_sequential_entries(&fauna, &options)),
+      ColorMode::Energy => {
+        ("Energy", self.make_sequential_entries(&fauna, &options))
+      },
+      ColorMode::Generation => {
+        ("Generation", self.make_sequential_entries(&fauna, &options))
+      },
+      ColorMode::Genotype => {
+        ("Genotype", self.make_categorical_entries(&fauna, &options))
+      },
+      ColorMode::Lineage => {
+        ("Lineage", self.make_categorical_entries(&fauna, &options))
+      },
+      ColorMode::Species => {
+        ("Species", self.make_categorical_entries(&fauna, &options))
+      },
+    };
+    let context = self.context.borrow();
+    context.set_font(&self.font);
+    context.set_text_align("right");
+    let right: f64 = self.canvas_width - self.margin;
+    let swatch_size: f64 = self.line_height - 2.;
+    let text_right: f64 = right - swatch_size - self.margin;
+    let mut y: f64 = self.line_height;
+    context.set_fill_style(&self.fill_style);
+    context.fill_text(title, right, y).unwrap();
+    for (rgba, label) in entries.iter() {
+      y += self.line_height;
+      context.set_fill_style(&self.fill_style);
+      context.fill_text(label, text_right, y).unwrap();
+      context.set_fill_style(&JsValue::from_str(&format!(
+        "rgb({},{},{})",
+        rgba[0], rgba[1], rgba[2]
+      )));
+      context.fill_rect(
+        right - swatch_size,
+        y - swatch_size,
+        swatch_size,
+        swatch_size,
+      );
+    }
+    context.set_text_align("left");
+  }
+}
@@ -1,4 +1,17 @@
 pub mod fauna;
 pub mod flora;
+pub mod garden;
+pub mod gene_heat_map;
+pub mod grid;
+pub mod heat_maps;
+pub mod layers;
+pub mod legend;
 pub mod overlay;
+pub mod profiler;
+pub mod raster;
+pub mod region;
+pub mod remains;
 pub mod root;
+pub mod selection;
+pub mod trail;
+pub mod traits;
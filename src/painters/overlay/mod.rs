@@ -2,10 +2,10 @@
 //! - OverlayPainter for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-12-10
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -22,6 +22,9 @@ use web_sys::CanvasRenderingContext2d;
 pub struct OverlayPainter {
   context: Rc<RefCell<CanvasRenderingContext2d>>,
   fill_style: JsValue,
+  font: String,
+  line_height: f64,
+  margin: f64,
   options: Rc<RefCell<Options>>,
   overlay: Rc<RefCell<Overlay>>,
 }
@@ -29,13 +32,20 @@ pub struct OverlayPainter {
 impl OverlayPainter {
   pub fn new(
     context: Rc<RefCell<CanvasRenderingContext2d>>,
+    font_scale: f64,
     options: Rc<RefCell<Options>>,
     overlay: Rc<RefCell<Overlay>>,
   ) -> Self {
     let fill_style: JsValue = JsValue::from_str("white");
+    let line_height: f64 = (17. * font_scale).round();
+    let font: String = format!("bold {}px monospace", line_height);
+    let margin: f64 = (4. * font_scale).round();
     Self {
       context,
       fill_style,
+      font,
+      line_height,
+      margin,
       options,
       overlay,
     }
@@ -46,17 +56,48 @@ impl Painter for OverlayPainter {
   fn paint(&self) {
     let context = self.context.borrow();
     context.set_fill_style(&self.fill_style);
-    context.set_font("bold 17px monospace");
+    context.set_font(&self.font);
     let overlay: Ref<Overlay> = self.overlay.borrow();
-    context.fill_text(&overlay.status_string, 4., 17.).unwrap();
+    let mut y: f64 = self.line_height;
+    context
+      .fill_text(&overlay.status_string, self.margin, y)
+      .unwrap();
+    for species_string in overlay.species_strings.iter() {
+      y += self.line_height;
+      context.fill_text(species_string, self.margin, y).unwrap();
+    }
+    if !overlay.environment_string.is_empty() {
+      y += self.line_height;
+      context
+        .fill_text(&overlay.environment_string, self.margin, y)
+        .unwrap();
+    }
     let options = self.options.borrow();
     if options.update_rate_display && !options.pause {
+      y += self.line_height;
+      context
+        .fill_text(&overlay.update_rate_string, self.margin, y)
+        .unwrap();
+    }
+    if options.diversity_display {
+      y += self.line_height;
       context
-        .fill_text(&overlay.update_rate_string, 4., 34.)
+        .fill_text(&overlay.diversity_string, self.margin, y)
         .unwrap();
     }
+    if options.life_history_display {
+      for life_history_string in overlay.life_history_strings.iter() {
+        y += self.line_height;
+        context
+          .fill_text(life_history_string, self.margin, y)
+          .unwrap();
+      }
+    }
     if options.time_display {
-      context.fill_text(&overlay.time_string, 4., 51.).unwrap();
+      y += self.line_height;
+      context
+        .fill_text(&overlay.time_string, self.margin, y)
+        .unwrap();
     }
   }
 }
@@ -0,0 +1,117 @@
+// =============================================================================
+//! - Profiler Painter for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::models::options::Options;
+use crate::models::profiler::{Profiler, Timing};
+use com_croftsoft_lib_role::Painter;
+use core::cell::{Ref, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::CanvasRenderingContext2d;
+
+const FONT_SIZE: f64 = 11.;
+// Characters in the widest line of the panel
+const LINE_LENGTH: f64 = 34.;
+
+// A panel of the rates and the rolling timings in milliseconds
+pub struct ProfilerPainter {
+  background_fill_style: JsValue,
+  canvas_height: f64,
+  canvas_width: f64,
+  context: Rc<RefCell<CanvasRenderingContext2d>>,
+  fill_style: JsValue,
+  font: String,
+  line_height: f64,
+  margin: f64,
+  options: Rc<RefCell<Options>>,
+  profiler: Rc<RefCell<Profiler>>,
+}
+
+impl ProfilerPainter {
+  fn make_timing_string(timing: &Timing) -> String {
+    format!(
+      "{:<13}{:>7.2}{:>7.2}{:>7.2}",
+      timing.name,
+      timing.get_min(),
+      timing.get_mean(),
+      timing.get_max(),
+    )
+  }
+
+  pub fn new(
+    canvas_height: f64,
+    canvas_width: f64,
+    context: Rc<RefCell<CanvasRenderingContext2d>>,
+    font_scale: f64,
+    options: Rc<RefCell<Options>>,
+    profiler: Rc<RefCell<Profiler>>,
+  ) -> Self {
+    let background_fill_style = JsValue::from_str("rgba(0,0,0,0.75)");
+    let fill_style = JsValue::from_str("white");
+    let line_height: f64 = (FONT_SIZE * font_scale).round();
+    let font: String = format!("{}px monospace", line_height);
+    let margin: f64 = (4. * font_scale).round();
+    Self {
+      background_fill_style,
+      canvas_height,
+      canvas_width,
+      context,
+      fill_style,
+      font,
+      line_height,
+      margin,
+      options,
+      profiler,
+    }
+  }
+}
+
+impl Painter for ProfilerPainter {
+  fn paint(&self) {
+    if !self.options.borrow().profile_display {
+      return;
+    }
+    let profiler: Ref<Profiler> = self.profiler.borrow();
+    let mut lines: Vec<String> = vec![
+      format!(
+        "Render FPS {:>3}  Sim TPS {:>3}",
+        profiler.render_rate.get_rate(),
+        profiler.tick_rate.get_rate(),
+      ),
+      format!("{:<13}{:>7}{:>7}{:>7}", "Timing ms", "min", "mean", "max"),
+    ];
+    for timing in profiler.updaters.iter() {
+      lines.push(Self::make_timing_string(timing));
+    }
+    lines.push(Self::make_timing_string(&profiler.paint));
+    // Monospace characters are about six tenths of the font size wide
+    let panel_width: f64 =
+      0.6 * self.line_height * LINE_LENGTH + 2. * self.margin;
+    let panel_height: f64 =
+      self.line_height * lines.len() as f64 + 2. * self.margin;
+    let panel_left: f64 = self.canvas_width - panel_width;
+    let panel_top: f64 = (self.canvas_height - panel_height) / 2.;
+    let context = self.context.borrow();
+    context.set_fill_style(&self.background_fill_style);
+    context.fill_rect(panel_left, panel_top, panel_width, panel_height);
+    context.set_fill_style(&self.fill_style);
+    context.set_font(&self.font);
+    let mut y: f64 = panel_top + self.margin;
+    for line in lines.iter() {
+      y += self.line_height;
+      context
+        .fill_text(line, panel_left + self.margin, y - 2.)
+        .unwrap();
+    }
+  }
+}
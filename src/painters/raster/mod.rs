@@ -0,0 +1,59 @@
+// =============================================================================
+//! - Raster Painter for CroftSoft Evolve for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::RGBA_BACKGROUND;
+use crate::engine::raster::Raster;
+use com_croftsoft_lib_role::Painter;
+use core::cell::{Ref, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::Clamped;
+use web_sys::{CanvasRenderingContext2d, ImageData};
+
+// Copies the raster filled by the world painters to the canvas in one call
+pub struct RasterPainter {
+  context: Rc<RefCell<CanvasRenderingContext2d>>,
+  raster: Rc<RefCell<Raster>>,
+}
+
+impl RasterPainter {
+  pub fn new(
+    context: Rc<RefCell<CanvasRenderingContext2d>>,
+    raster: Rc<RefCell<Raster>>,
+  ) -> Self {
+    Self {
+      context,
+      raster,
+    }
+  }
+}
+
+impl Painter for RasterPainter {
+  fn paint(&self) {
+    {
+      let raster: Ref<Raster> = self.raster.borrow();
+      let image_data: ImageData = ImageData::new_with_u8_clamped_array_and_sh(
+        Clamped(&raster.data),
+        raster.width as u32,
+        raster.height as u32,
+      )
+      .unwrap();
+      self
+        .context
+        .borrow()
+        .put_image_data(&image_data, 0., 0.)
+        .unwrap();
+    }
+    // Cleared after the copy so the world painters start on the background
+    self.raster.borrow_mut().fill(RGBA_BACKGROUND);
+  }
+}
@@ -0,0 +1,89 @@
+// =============================================================================
+//! - Region Painter for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::RGBA_REGION;
+use crate::engine::raster::Raster;
+use crate::engine::viewport::ViewTransform;
+use crate::models::options::{DragMode, Options};
+use crate::models::region::Region;
+use crate::models::reset::{Placement, Reset};
+use crate::models::viewport::Viewport;
+use com_croftsoft_lib_role::Painter;
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+// Outlines the start region while it is in use or being drawn
+pub struct RegionPainter {
+  canvas_height: f64,
+  canvas_width: f64,
+  options: Rc<RefCell<Options>>,
+  raster: Rc<RefCell<Raster>>,
+  reset: Rc<RefCell<Reset>>,
+  viewport: Rc<RefCell<Viewport>>,
+}
+
+impl RegionPainter {
+  pub fn new(
+    canvas_height: f64,
+    canvas_width: f64,
+    options: Rc<RefCell<Options>>,
+    raster: Rc<RefCell<Raster>>,
+    reset: Rc<RefCell<Reset>>,
+    viewport: Rc<RefCell<Viewport>>,
+  ) -> Self {
+    Self {
+      canvas_height,
+      canvas_width,
+      options,
+      raster,
+      reset,
+      viewport,
+    }
+  }
+}
+
+impl Painter for RegionPainter {
+  fn paint(&self) {
+    let reset: Ref<Reset> = self.reset.borrow();
+    let region: Region = match reset.region {
+      Some(region) => region,
+      None => return,
+    };
+    if reset.settings.placement != Placement::Region
+      && self.options.borrow().drag_mode != DragMode::StartRegion
+    {
+      return;
+    }
+    let mut raster: RefMut<Raster> = self.raster.borrow_mut();
+    let view_transform = ViewTransform::new(
+      &self.viewport.borrow(),
+      self.canvas_width,
+      self.canvas_height,
+    );
+    // Drawing past the far edges wraps the outline around them
+    let x0: f64 = region.x as f64;
+    let y0: f64 = region.y as f64;
+    let width: f64 = region.width as f64;
+    let height: f64 = region.height as f64;
+    let x1: f64 = x0 + width;
+    let y1: f64 = y0 + height;
+    for (x, y, w, h) in [
+      (x0, y0, width, 0.),
+      (x0, y1, width, 0.),
+      (x0, y0, 0., height),
+      (x1, y0, 0., height),
+    ] {
+      view_transform.fill_rect(&mut raster, x, y, w, h, RGBA_REGION);
+    }
+  }
+}
@@ -0,0 +1,82 @@
+// =============================================================================
+//! - Remains painter for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{PAINT_OFFSET, PAINT_SCALE, RGBA_CORPSE, RGBA_FERTILE};
+use crate::engine::functions::location::{to_x_from_index, to_y_from_index};
+use crate::engine::raster::Raster;
+use crate::engine::viewport::ViewTransform;
+use crate::models::flora::{Decomposition, Flora};
+use crate::models::viewport::Viewport;
+use com_croftsoft_lib_role::Painter;
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+// Paints fertile soil as a tint over the whole location and the corpses at
+// the size of the food
+pub struct RemainsPainter {
+  canvas_height: f64,
+  canvas_width: f64,
+  flora: Rc<RefCell<Flora>>,
+  raster: Rc<RefCell<Raster>>,
+  viewport: Rc<RefCell<Viewport>>,
+}
+
+impl RemainsPainter {
+  pub fn new(
+    canvas_height: f64,
+    canvas_width: f64,
+    flora: Rc<RefCell<Flora>>,
+    raster: Rc<RefCell<Raster>>,
+    viewport: Rc<RefCell<Viewport>>,
+  ) -> Self {
+    Self {
+      canvas_height,
+      canvas_width,
+      flora,
+      raster,
+      viewport,
+    }
+  }
+}
+
+impl Painter for RemainsPainter {
+  fn paint(&self) {
+    let flora: Ref<Flora> = self.flora.borrow();
+    if flora.decomposition == Decomposition::Off {
+      return;
+    }
+    let mut raster: RefMut<Raster> = self.raster.borrow_mut();
+    let view_transform = ViewTransform::new(
+      &self.viewport.borrow(),
+      self.canvas_width,
+      self.canvas_height,
+    );
+    for index in 0..flora.fertility_ticks.len() {
+      let x: f64 = to_x_from_index(index) as f64;
+      let y: f64 = to_y_from_index(index) as f64;
+      if flora.fertility_ticks[index] > 0 {
+        view_transform.fill_rect(&mut raster, x, y, 1., 1., RGBA_FERTILE);
+      }
+      if flora.corpse_ticks[index] > 0 {
+        view_transform.fill_rect(
+          &mut raster,
+          x + PAINT_OFFSET,
+          y + PAINT_OFFSET,
+          PAINT_SCALE,
+          PAINT_SCALE,
+          RGBA_CORPSE,
+        );
+      }
+    }
+  }
+}
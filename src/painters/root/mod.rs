@@ -2,22 +2,20 @@
 //! - Root Painter for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2022-2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2022-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2022-11-27
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
-use super::overlay::OverlayPainter;
-use crate::constants::{FILL_STYLE_BACKGROUND, SPACE_HEIGHT, SPACE_WIDTH};
+use super::layers::{LayerContext, LayerPainter, LAYERS};
+use crate::constants::{CANVAS_WIDTH_NOMINAL, RGBA_BACKGROUND};
+use crate::engine::raster::Raster;
 use crate::models::options::Options;
 use crate::models::root::Root;
-use crate::painters::fauna::FaunaPainter;
-use crate::painters::flora::FloraPainter;
-use com_croftsoft_lib_animation::painter::background::BackgroundPainter;
 use com_croftsoft_lib_role::Painter;
 use core::cell::RefCell;
 use js_sys::Object;
@@ -48,34 +46,30 @@ impl RootPainter {
       Rc::new(RefCell::new(canvas_context));
     let canvas_height: f64 = html_canvas_element.height() as f64;
     let canvas_width: f64 = html_canvas_element.width() as f64;
-    let background_painter = BackgroundPainter::new(
+    let device_pixel_ratio: f64 = window().unwrap().device_pixel_ratio();
+    // Text keeps its size in CSS pixels but shrinks to fit a small canvas
+    let font_scale: f64 =
+      (canvas_width / CANVAS_WIDTH_NOMINAL).min(device_pixel_ratio);
+    let raster: Rc<RefCell<Raster>> = Rc::new(RefCell::new(Raster::new(
+      canvas_width as usize,
+      canvas_height as usize,
+      RGBA_BACKGROUND,
+    )));
+    let layer_context = LayerContext {
       canvas_height,
       canvas_width,
-      context.clone(),
-      FILL_STYLE_BACKGROUND,
-    );
-    let scale_x = canvas_width / SPACE_WIDTH as f64;
-    let scale_y = canvas_height / SPACE_HEIGHT as f64;
-    let fauna_painter = FaunaPainter::new(
-      context.clone(),
-      root_model.fauna.clone(),
-      scale_x,
-      scale_y,
-    );
-    let flora_painter = FloraPainter::new(
-      context.clone(),
-      root_model.flora.clone(),
-      scale_x,
-      scale_y,
-    );
-    let overlay_painter =
-      OverlayPainter::new(context, options, root_model.overlay.clone());
-    let painters: Vec<Box<dyn Painter>> = vec![
-      Box::new(background_painter),
-      Box::new(flora_painter),
-      Box::new(fauna_painter),
-      Box::new(overlay_painter),
-    ];
+      context,
+      font_scale,
+      options,
+      raster,
+      root_model,
+    };
+    let painters: Vec<Box<dyn Painter>> = LAYERS
+      .iter()
+      .map(|layer_descriptor| -> Box<dyn Painter> {
+        Box::new(LayerPainter::new(&layer_context, layer_descriptor))
+      })
+      .collect();
     Self {
       painters,
     }
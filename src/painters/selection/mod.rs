@@ -0,0 +1,88 @@
+// =============================================================================
+//! - Selection Painter for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::fauna::to_display_xy;
+use crate::constants::RGBA_SELECTION;
+use crate::engine::raster::Raster;
+use crate::engine::viewport::ViewTransform;
+use crate::models::clock::Clock;
+use crate::models::fauna::Fauna;
+use crate::models::selection::Selection;
+use crate::models::viewport::Viewport;
+use com_croftsoft_lib_role::Painter;
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+// Outlines the cells of the bugs in the selected lineage
+pub struct SelectionPainter {
+  canvas_height: f64,
+  canvas_width: f64,
+  clock: Rc<RefCell<Clock>>,
+  fauna: Rc<RefCell<Fauna>>,
+  raster: Rc<RefCell<Raster>>,
+  selection: Rc<RefCell<Selection>>,
+  viewport: Rc<RefCell<Viewport>>,
+}
+
+impl SelectionPainter {
+  pub fn new(
+    canvas_height: f64,
+    canvas_width: f64,
+    clock: Rc<RefCell<Clock>>,
+    fauna: Rc<RefCell<Fauna>>,
+    raster: Rc<RefCell<Raster>>,
+    selection: Rc<RefCell<Selection>>,
+    viewport: Rc<RefCell<Viewport>>,
+  ) -> Self {
+    Self {
+      canvas_height,
+      canvas_width,
+      clock,
+      fauna,
+      raster,
+      selection,
+      viewport,
+    }
+  }
+}
+
+impl Painter for SelectionPainter {
+  fn paint(&self) {
+    let lineage: usize = match self.selection.borrow().lineage {
+      Some(lineage) => lineage,
+      None => return,
+    };
+    let update_fraction: f64 = self.clock.borrow().update_fraction;
+    let mut raster: RefMut<Raster> = self.raster.borrow_mut();
+    let view_transform = ViewTransform::new(
+      &self.viewport.borrow(),
+      self.canvas_width,
+      self.canvas_height,
+    );
+    let fauna: Ref<Fauna> = self.fauna.borrow();
+    for index in 0..fauna.len() {
+      if fauna.lineages[index] != lineage {
+        continue;
+      }
+      let (x, y) = to_display_xy(&fauna, index, update_fraction);
+      for (x, y, w, h) in [
+        (x, y, 1., 0.),
+        (x, y + 1., 1., 0.),
+        (x, y, 0., 1.),
+        (x + 1., y, 0., 1.),
+      ] {
+        view_transform.fill_rect(&mut raster, x, y, w, h, RGBA_SELECTION);
+      }
+    }
+  }
+}
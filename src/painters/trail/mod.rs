@@ -0,0 +1,125 @@
+// =============================================================================
+//! - Trail Painter for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::fauna::{make_rgba_function, to_display_xy};
+use crate::constants::{SPACE_HEIGHT, SPACE_WIDTH, TRAIL_LENGTH, TRAIL_SCALE};
+use crate::engine::functions::location::{
+  to_wrapped_delta, to_x_from_index, to_y_from_index,
+};
+use crate::engine::raster::Raster;
+use crate::engine::viewport::ViewTransform;
+use crate::models::classifier::Classifier;
+use crate::models::clock::Clock;
+use crate::models::fauna::Fauna;
+use crate::models::options::Options;
+use crate::models::viewport::Viewport;
+use com_croftsoft_lib_role::Painter;
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+// Dots painted along each step so that a diagonal step looks like a line
+const TRAIL_DOTS_PER_STEP: usize = 4;
+
+pub struct TrailPainter {
+  canvas_height: f64,
+  canvas_width: f64,
+  classifier: Rc<RefCell<Classifier>>,
+  clock: Rc<RefCell<Clock>>,
+  fauna: Rc<RefCell<Fauna>>,
+  options: Rc<RefCell<Options>>,
+  raster: Rc<RefCell<Raster>>,
+  viewport: Rc<RefCell<Viewport>>,
+}
+
+impl TrailPainter {
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    canvas_height: f64,
+    canvas_width: f64,
+    classifier: Rc<RefCell<Classifier>>,
+    clock: Rc<RefCell<Clock>>,
+    fauna: Rc<RefCell<Fauna>>,
+    options: Rc<RefCell<Options>>,
+    raster: Rc<RefCell<Raster>>,
+    viewport: Rc<RefCell<Viewport>>,
+  ) -> Self {
+    Self {
+      canvas_height,
+      canvas_width,
+      classifier,
+      clock,
+      fauna,
+      options,
+      raster,
+      viewport,
+    }
+  }
+}
+
+impl Painter for TrailPainter {
+  fn paint(&self) {
+    let options: Ref<Options> = self.options.borrow();
+    if !options.trail_display {
+      return;
+    }
+    let mut raster: RefMut<Raster> = self.raster.borrow_mut();
+    let classifier: Ref<Classifier> = self.classifier.borrow();
+    let clock: Ref<Clock> = self.clock.borrow();
+    let fauna: Ref<Fauna> = self.fauna.borrow();
+    let to_rgba = make_rgba_function(&fauna, &classifier, &clock, &options);
+    let view_transform = ViewTransform::new(
+      &self.viewport.borrow(),
+      self.canvas_width,
+      self.canvas_height,
+    );
+    for index in 0..fauna.len() {
+      let mut rgba: [u8; 4] = to_rgba(index);
+      // Unwrapped so that a step across an edge of the torus stays short
+      let (mut x, mut y) = to_display_xy(&fauna, index, clock.update_fraction);
+      let mut position: usize = fauna.positions[index];
+      // The first step starts where the bug is shown while interpolating
+      let mut step_fraction: f64 = clock.update_fraction;
+      for (age, previous_position) in fauna.get_trail(index).enumerate() {
+        let delta_x: f64 = step_fraction
+          * to_wrapped_delta(
+            to_x_from_index(position),
+            to_x_from_index(previous_position),
+            SPACE_WIDTH,
+          ) as f64;
+        let delta_y: f64 = step_fraction
+          * to_wrapped_delta(
+            to_y_from_index(position),
+            to_y_from_index(previous_position),
+            SPACE_HEIGHT,
+          ) as f64;
+        // Fades from half opaque to transparent over the length of the trail
+        rgba[3] = (128 * (TRAIL_LENGTH - age) / TRAIL_LENGTH) as u8;
+        for dot in 0..TRAIL_DOTS_PER_STEP {
+          let fraction: f64 = dot as f64 / TRAIL_DOTS_PER_STEP as f64;
+          view_transform.fill_rect(
+            &mut raster,
+            x + fraction * delta_x + (1. - TRAIL_SCALE) / 2.,
+            y + fraction * delta_y + (1. - TRAIL_SCALE) / 2.,
+            TRAIL_SCALE,
+            TRAIL_SCALE,
+            rgba,
+          );
+        }
+        x += delta_x;
+        y += delta_y;
+        position = previous_position;
+        step_fraction = 1.;
+      }
+    }
+  }
+}
@@ -0,0 +1,166 @@
+// =============================================================================
+//! - Traits Painter for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use super::gene_heat_map::to_panel_height;
+use crate::engine::functions::traits::{to_trait_histograms, TraitHistograms};
+use crate::models::fauna::Fauna;
+use crate::models::options::Options;
+use com_croftsoft_lib_role::Painter;
+use core::cell::{Ref, RefCell};
+use std::rc::Rc;
+use wasm_bindgen::JsValue;
+use web_sys::CanvasRenderingContext2d;
+
+const FONT_SIZE: f64 = 10.;
+const HISTOGRAM_HEIGHT: f64 = 40.;
+const HISTOGRAM_WIDTH: f64 = 120.;
+const MARGIN: f64 = 4.;
+
+// Histograms of the life-history traits in the lower right corner
+pub struct TraitsPainter {
+  background_fill_style: JsValue,
+  bar_fill_style: JsValue,
+  canvas_height: f64,
+  canvas_width: f64,
+  context: Rc<RefCell<CanvasRenderingContext2d>>,
+  fauna: Rc<RefCell<Fauna>>,
+  font: String,
+  font_scale: f64,
+  histogram_height: f64,
+  histogram_width: f64,
+  label_fill_style: JsValue,
+  line_height: f64,
+  margin: f64,
+  options: Rc<RefCell<Options>>,
+}
+
+impl TraitsPainter {
+  pub fn new(
+    canvas_height: f64,
+    canvas_width: f64,
+    context: Rc<RefCell<CanvasRenderingContext2d>>,
+    fauna: Rc<RefCell<Fauna>>,
+    font_scale: f64,
+    options: Rc<RefCell<Options>>,
+  ) -> Self {
+    let line_height: f64 = (FONT_SIZE * font_scale).round();
+    Self {
+      background_fill_style: JsValue::from_str("rgba(0,0,0,0.75)"),
+      bar_fill_style: JsValue::from_str("rgb(86,180,233)"),
+      canvas_height,
+      canvas_width,
+      context,
+      fauna,
+      font: format!("{}px monospace", line_height),
+      font_scale,
+      histogram_height: (HISTOGRAM_HEIGHT * font_scale).round(),
+      histogram_width: (HISTOGRAM_WIDTH * font_scale).round(),
+      label_fill_style: JsValue::from_str("white"),
+      line_height,
+      margin: (MARGIN * font_scale).round(),
+      options,
+    }
+  }
+
+  // Bars scaled to the most common value with the mean in the label
+  fn paint_histogram(
+    &self,
+    context: &CanvasRenderingContext2d,
+    counts: &[usize],
+    label: &str,
+    left: f64,
+    top: f64,
+  ) {
+    let population: usize = counts.iter().sum();
+    let count_max: usize = counts.iter().copied().max().unwrap_or(0);
+    let mean: f64 = if population == 0 {
+      0.
+    } else {
+      counts
+        .iter()
+        .enumerate()
+        .map(|(value, count)| value * count)
+        .sum::<usize>() as f64
+        / population as f64
+    };
+    context.set_fill_style(&self.label_fill_style);
+    context
+      .fill_text(
+        &format!("{} {:.1}", label, mean),
+        left,
+        top + self.line_height,
+      )
+      .unwrap();
+    if count_max == 0 {
+      return;
+    }
+    let bar_width: f64 = self.histogram_width / counts.len() as f64;
+    let bottom: f64 =
+      top + self.line_height + self.margin + self.histogram_height;
+    context.set_fill_style(&self.bar_fill_style);
+    for (value, count) in counts.iter().enumerate() {
+      let bar_height: f64 =
+        self.histogram_height * *count as f64 / count_max as f64;
+      context.fill_rect(
+        left + bar_width * value as f64,
+        bottom - bar_height,
+        bar_width.ceil(),
+        bar_height,
+      );
+    }
+  }
+}
+
+impl Painter for TraitsPainter {
+  fn paint(&self) {
+    let options: Ref<Options> = self.options.borrow();
+    if !options.traits_display {
+      return;
+    }
+    let trait_histograms: TraitHistograms =
+      to_trait_histograms(&self.fauna.borrow());
+    let panel_height: f64 =
+      self.line_height + self.histogram_height + 3. * self.margin;
+    let panel_width: f64 = 3. * self.histogram_width + 4. * self.margin;
+    // Stacked above the gene heat map when both are shown
+    let panel_bottom: f64 = if options.gene_heat_map_display {
+      self.canvas_height - to_panel_height(self.font_scale)
+    } else {
+      self.canvas_height
+    };
+    let panel_left: f64 = self.canvas_width - panel_width;
+    let panel_top: f64 = panel_bottom - panel_height;
+    let context = self.context.borrow();
+    context.set_fill_style(&self.background_fill_style);
+    context.fill_rect(panel_left, panel_top, panel_width, panel_height);
+    context.set_font(&self.font);
+    for (column, (counts, label)) in [
+      (&trait_histograms.birth_energies[..], "Birth at"),
+      (&trait_histograms.baby_energies[..], "Invest"),
+      (&trait_histograms.move_costs[..], "Metabolism"),
+    ]
+    .iter()
+    .enumerate()
+    {
+      self.paint_histogram(
+        &context,
+        counts,
+        label,
+        panel_left
+          + self.margin
+          + (self.histogram_width + self.margin) * column as f64,
+        panel_top + self.margin,
+      );
+    }
+  }
+}
@@ -0,0 +1,112 @@
+// =============================================================================
+//! - Classifier Updater for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::CLASSIFIER_CLUSTER_PERIOD;
+use crate::engine::functions::species::{
+  classify, make_classes, make_representatives,
+};
+use crate::models::classifier::{
+  Classifier, ClassifierMode, ClassifierSettings,
+};
+use crate::models::clock::Clock;
+use crate::models::fauna::Fauna;
+use com_croftsoft_lib_role::Updater;
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+pub trait ClassifierUpdaterEvents {
+  fn set_updated(&mut self);
+}
+
+pub trait ClassifierUpdaterInputs {
+  fn get_classifier_change_requested(&self) -> Option<ClassifierSettings>;
+  fn get_reset_requested(&self) -> bool;
+  fn get_time_to_update(&self) -> bool;
+}
+
+pub trait ClassifierUpdaterOptions {
+  fn get_pause(&self) -> bool;
+}
+
+pub struct ClassifierUpdater {
+  classifier: Rc<RefCell<Classifier>>,
+  clock: Rc<RefCell<Clock>>,
+  events: Rc<RefCell<dyn ClassifierUpdaterEvents>>,
+  fauna: Rc<RefCell<Fauna>>,
+  inputs: Rc<RefCell<dyn ClassifierUpdaterInputs>>,
+  options: Rc<RefCell<dyn ClassifierUpdaterOptions>>,
+}
+
+impl ClassifierUpdater {
+  pub fn new(
+    classifier: Rc<RefCell<Classifier>>,
+    clock: Rc<RefCell<Clock>>,
+    events: Rc<RefCell<dyn ClassifierUpdaterEvents>>,
+    fauna: Rc<RefCell<Fauna>>,
+    inputs: Rc<RefCell<dyn ClassifierUpdaterInputs>>,
+    options: Rc<RefCell<dyn ClassifierUpdaterOptions>>,
+  ) -> Self {
+    Self {
+      classifier,
+      clock,
+      events,
+      fauna,
+      inputs,
+      options,
+    }
+  }
+
+  fn reclassify(&self) {
+    let mut classifier: RefMut<Classifier> = self.classifier.borrow_mut();
+    let mut fauna: RefMut<Fauna> = self.fauna.borrow_mut();
+    if classifier.settings.mode == ClassifierMode::Cluster {
+      classifier.representatives =
+        make_representatives(&fauna, classifier.settings.cluster_radius);
+    } else {
+      classifier.representatives.clear();
+    }
+    classifier.classes = make_classes(&classifier);
+    let fauna: &mut Fauna = &mut fauna;
+    for ((species, genes_x), genes_y) in fauna
+      .species
+      .iter_mut()
+      .zip(&fauna.genes_x)
+      .zip(&fauna.genes_y)
+    {
+      *species = classify(&classifier, genes_x, genes_y);
+    }
+  }
+}
+
+impl Updater for ClassifierUpdater {
+  fn update(&self) {
+    let inputs: Ref<dyn ClassifierUpdaterInputs> = self.inputs.borrow();
+    let mut reclassify_requested: bool = inputs.get_reset_requested();
+    if let Some(settings) = inputs.get_classifier_change_requested() {
+      self.classifier.borrow_mut().settings = settings;
+      reclassify_requested = true;
+    }
+    // Clusters drift as the population evolves so they are rebuilt regularly
+    if inputs.get_time_to_update()
+      && !self.options.borrow().get_pause()
+      && self.classifier.borrow().settings.mode == ClassifierMode::Cluster
+      && self.clock.borrow().tick % CLASSIFIER_CLUSTER_PERIOD == 0
+    {
+      reclassify_requested = true;
+    }
+    if reclassify_requested {
+      self.reclassify();
+      self.events.borrow_mut().set_updated();
+    }
+  }
+}
@@ -2,10 +2,10 @@
 //! - Clock Updater for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-01-25
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
@@ -13,6 +13,7 @@
 
 use crate::constants::GENES_MAX;
 use crate::models::clock::Clock;
+use com_croftsoft_lib_animation::metronome::delta::DeltaMetronome;
 use com_croftsoft_lib_role::Updater;
 use core::cell::{Ref, RefCell, RefMut};
 use std::rc::Rc;
@@ -22,11 +23,13 @@ pub trait ClockUpdaterEvents {
 }
 
 pub trait ClockUpdaterInputs {
+  fn get_current_time_millis(&self) -> f64;
   fn get_reset_requested(&self) -> bool;
   fn get_time_to_update(&self) -> bool;
 }
 
 pub trait ClockUpdaterOptions {
+  fn get_interpolation(&self) -> bool;
   fn get_pause(&self) -> bool;
 }
 
@@ -34,6 +37,7 @@ pub struct ClockUpdater {
   clock: Rc<RefCell<Clock>>,
   events: Rc<RefCell<dyn ClockUpdaterEvents>>,
   inputs: Rc<RefCell<dyn ClockUpdaterInputs>>,
+  metronome: Rc<RefCell<DeltaMetronome>>,
   options: Rc<RefCell<dyn ClockUpdaterOptions>>,
 }
 
@@ -42,15 +46,36 @@ impl ClockUpdater {
     clock: Rc<RefCell<Clock>>,
     events: Rc<RefCell<dyn ClockUpdaterEvents>>,
     inputs: Rc<RefCell<dyn ClockUpdaterInputs>>,
+    metronome: Rc<RefCell<DeltaMetronome>>,
     options: Rc<RefCell<dyn ClockUpdaterOptions>>,
   ) -> Self {
     Self {
       clock,
       events,
       inputs,
+      metronome,
       options,
     }
   }
+
+  // Repaints every frame while the bugs are shown between locations
+  fn update_fraction(
+    &self,
+    clock: &mut Clock,
+    current_time_millis: f64,
+  ) {
+    let options: Ref<dyn ClockUpdaterOptions> = self.options.borrow();
+    if !options.get_interpolation() || options.get_pause() {
+      clock.update_fraction = 1.;
+      return;
+    }
+    let metronome: Ref<DeltaMetronome> = self.metronome.borrow();
+    let millis_remaining: f64 =
+      metronome.time_millis_next_tick - current_time_millis;
+    clock.update_fraction =
+      (1. - millis_remaining / metronome.period_millis).clamp(0., 1.);
+    self.events.borrow_mut().set_updated();
+  }
 }
 
 impl Updater for ClockUpdater {
@@ -58,13 +83,17 @@ impl Updater for ClockUpdater {
     let mut clock: RefMut<Clock> = self.clock.borrow_mut();
     let inputs: Ref<dyn ClockUpdaterInputs> = self.inputs.borrow();
     if inputs.get_reset_requested() {
+      clock.tick = 0;
       clock.time = 0;
+      clock.update_fraction = 1.;
       self.events.borrow_mut().set_updated();
       return;
     }
+    self.update_fraction(&mut clock, inputs.get_current_time_millis());
     if !inputs.get_time_to_update() || self.options.borrow().get_pause() {
       return;
     }
+    clock.tick += 1;
     if clock.time >= GENES_MAX - 1 {
       clock.time = 0;
     } else {
@@ -0,0 +1,281 @@
+// =============================================================================
+//! - Environment updater for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{
+  WEATHER_BOOM_FACTOR, WEATHER_DROUGHT_FACTOR, WEATHER_ODDS, WEATHER_TICKS,
+};
+use crate::models::clock::Clock;
+use crate::models::environment::{
+  Environment, EnvironmentSettings, Season, Weather,
+};
+use crate::models::random::Random;
+use com_croftsoft_lib_role::Updater;
+use core::cell::{RefCell, RefMut};
+use core::f64::consts::TAU;
+use rand::Rng;
+use std::rc::Rc;
+
+pub trait EnvironmentUpdaterEvents {
+  fn set_updated(&mut self);
+}
+
+pub trait EnvironmentUpdaterInputs {
+  fn get_environment_settings_change_requested(
+    &self
+  ) -> Option<EnvironmentSettings>;
+  fn get_reset_requested(&self) -> bool;
+  fn get_time_to_update(&self) -> bool;
+}
+
+pub trait EnvironmentUpdaterOptions {
+  fn get_pause(&self) -> bool;
+}
+
+pub struct EnvironmentUpdater {
+  clock: Rc<RefCell<Clock>>,
+  environment: Rc<RefCell<Environment>>,
+  events: Rc<RefCell<dyn EnvironmentUpdaterEvents>>,
+  inputs: Rc<RefCell<dyn EnvironmentUpdaterInputs>>,
+  options: Rc<RefCell<dyn EnvironmentUpdaterOptions>>,
+  random: Rc<RefCell<Random>>,
+}
+
+impl EnvironmentUpdater {
+  pub fn new(
+    clock: Rc<RefCell<Clock>>,
+    environment: Rc<RefCell<Environment>>,
+    events: Rc<RefCell<dyn EnvironmentUpdaterEvents>>,
+    inputs: Rc<RefCell<dyn EnvironmentUpdaterInputs>>,
+    options: Rc<RefCell<dyn EnvironmentUpdaterOptions>>,
+    random: Rc<RefCell<Random>>,
+  ) -> Self {
+    Self {
+      clock,
+      environment,
+      events,
+      inputs,
+      options,
+      random,
+    }
+  }
+
+  // The seasons follow a sine wave peaking in summer and the days a square
+  // wave with the first half of each period light, rounded up so that a
+  // period of one is always light
+  fn update_phase(
+    environment: &mut Environment,
+    tick: usize,
+  ) {
+    let settings: EnvironmentSettings = environment.settings;
+    let amplitude: f64 = settings.amplitude as f64 / 100.;
+    let mut growth_factor: f64 = 1.;
+    environment.season = if settings.season_period > 0 {
+      let phase: f64 =
+        (tick % settings.season_period) as f64 / settings.season_period as f64;
+      growth_factor *= 1. + amplitude * (TAU * phase).sin();
+      // Each season is centered on a quarter of the sine wave
+      Some(match ((phase + 0.125) * 4.) as usize % 4 {
+        0 => Season::Spring,
+        1 => Season::Summer,
+        2 => Season::Autumn,
+        _ => Season::Winter,
+      })
+    } else {
+      None
+    };
+    environment.daytime = if settings.day_period > 0 {
+      let daytime: bool =
+        2 * (tick % settings.day_period) < settings.day_period;
+      growth_factor *= if daytime {
+        1. + amplitude
+      } else {
+        1. - amplitude
+      };
+      Some(daytime)
+    } else {
+      None
+    };
+    growth_factor *= match environment.weather {
+      Weather::Boom => WEATHER_BOOM_FACTOR,
+      Weather::Drought => WEATHER_DROUGHT_FACTOR,
+      Weather::Normal => 1.,
+    };
+    environment.growth_factor = growth_factor.max(0.);
+  }
+
+  fn update_weather(
+    environment: &mut Environment,
+    random: &mut Random,
+  ) {
+    if environment.weather_ticks > 0 {
+      environment.weather_ticks -= 1;
+      if environment.weather_ticks == 0 {
+        environment.weather = Weather::Normal;
+      }
+    } else if environment.settings.weather_random
+      && random.rng.gen_range(0..WEATHER_ODDS) == 0
+    {
+      environment.weather = if random.rng.gen() {
+        Weather::Boom
+      } else {
+        Weather::Drought
+      };
+      environment.weather_ticks = WEATHER_TICKS;
+    }
+  }
+}
+
+impl Updater for EnvironmentUpdater {
+  fn update(&self) {
+    let mut environment: RefMut<Environment> = self.environment.borrow_mut();
+    let tick: usize = self.clock.borrow().tick;
+    if let Some(settings) = self
+      .inputs
+      .borrow()
+      .get_environment_settings_change_requested()
+    {
+      environment.settings = settings;
+      if !settings.weather_random {
+        environment.weather = Weather::Normal;
+        environment.weather_ticks = 0;
+      }
+      Self::update_phase(&mut environment, tick);
+      self.events.borrow_mut().set_updated();
+    }
+    if self.inputs.borrow().get_reset_requested() {
+      environment.weather = Weather::Normal;
+      environment.weather_ticks = 0;
+      Self::update_phase(&mut environment, tick);
+      self.events.borrow_mut().set_updated();
+      return;
+    }
+    if !self.inputs.borrow().get_time_to_update()
+      || self.options.borrow().get_pause()
+    {
+      return;
+    }
+    Self::update_weather(&mut environment, &mut self.random.borrow_mut());
+    Self::update_phase(&mut environment, tick);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::EnvironmentUpdater;
+  use crate::constants::{WEATHER_BOOM_FACTOR, WEATHER_TICKS};
+  use crate::models::environment::{
+    Environment, EnvironmentSettings, Season, Weather,
+  };
+  use crate::models::random::Random;
+
+  const SEED: u64 = 0;
+  const TOLERANCE: f64 = 1e-9;
+
+  fn make_environment(
+    day_period: usize,
+    season_period: usize,
+  ) -> Environment {
+    Environment {
+      settings: EnvironmentSettings {
+        amplitude: 50,
+        day_period,
+        season_period,
+        weather_random: false,
+      },
+      ..Environment::default()
+    }
+  }
+
+  #[test]
+  fn test_day_and_night() {
+    let mut environment: Environment = make_environment(4, 0);
+    for (tick, daytime, growth_factor) in [
+      (0, true, 1.5),
+      (1, true, 1.5),
+      (2, false, 0.5),
+      (3, false, 0.5),
+      (4, true, 1.5),
+    ] {
+      EnvironmentUpdater::update_phase(&mut environment, tick);
+      assert!(environment.season.is_none());
+      assert_eq!(environment.daytime, Some(daytime));
+      assert!((environment.growth_factor - growth_factor).abs() < TOLERANCE);
+    }
+    // The light half of an odd or a single update period is rounded up
+    environment.settings.day_period = 3;
+    let daytimes: Vec<Option<bool>> = (0..3)
+      .map(|tick| {
+        EnvironmentUpdater::update_phase(&mut environment, tick);
+        environment.daytime
+      })
+      .collect();
+    assert_eq!(
+      daytimes,
+      [
+        Some(true),
+        Some(true),
+        Some(false)
+      ]
+    );
+    environment.settings.day_period = 1;
+    for tick in 0..3 {
+      EnvironmentUpdater::update_phase(&mut environment, tick);
+      assert_eq!(environment.daytime, Some(true));
+    }
+  }
+
+  #[test]
+  fn test_seasons() {
+    let mut environment: Environment = make_environment(0, 8);
+    for (tick, season, growth_factor) in [
+      (0, Season::Spring, 1.),
+      (2, Season::Summer, 1.5),
+      (4, Season::Autumn, 1.),
+      (6, Season::Winter, 0.5),
+      (7, Season::Spring, 1. - 0.5 / 2_f64.sqrt()),
+      (8, Season::Spring, 1.),
+    ] {
+      EnvironmentUpdater::update_phase(&mut environment, tick);
+      assert!(environment.daytime.is_none());
+      assert!(environment.season == Some(season));
+      assert!((environment.growth_factor - growth_factor).abs() < TOLERANCE);
+    }
+  }
+
+  #[test]
+  fn test_weather_ends() {
+    let mut environment: Environment = make_environment(0, 0);
+    let mut random = Random::default();
+    random.reseed(SEED);
+    environment.weather = Weather::Boom;
+    environment.weather_ticks = WEATHER_TICKS;
+    for _ in 1..WEATHER_TICKS {
+      EnvironmentUpdater::update_weather(&mut environment, &mut random);
+      EnvironmentUpdater::update_phase(&mut environment, 0);
+      assert!(environment.weather == Weather::Boom);
+      assert!(
+        (environment.growth_factor - WEATHER_BOOM_FACTOR).abs() < TOLERANCE
+      );
+    }
+    EnvironmentUpdater::update_weather(&mut environment, &mut random);
+    EnvironmentUpdater::update_phase(&mut environment, 0);
+    assert!(environment.weather == Weather::Normal);
+    assert_eq!(environment.weather_ticks, 0);
+    assert!((environment.growth_factor - 1.).abs() < TOLERANCE);
+    // Without random weather the weather stays normal
+    for _ in 0..1_000 {
+      EnvironmentUpdater::update_weather(&mut environment, &mut random);
+      assert!(environment.weather == Weather::Normal);
+    }
+  }
+}
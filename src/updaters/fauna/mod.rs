@@ -2,258 +2,1347 @@
 //! - Fauna Updater for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-01-25
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
 use crate::constants::{
-  BABY_ENERGY, BIRTH_ENERGY, BIRTH_ENERGY_COST, BUGS_MAX, FLORA_ENERGY,
-  GENES_MAX, LOCATION_COUNT, MAX_ENERGY, MOVE_COST, SPACE_HEIGHT, SPACE_WIDTH,
+  BABY_ENERGY, BIRTH_ENERGY, BIRTH_ENERGY_OVERHEAD, BUGS_MAX, CORPSE_ENERGY,
+  FLORA_ENERGY, GENES_MAX, LOCATION_COUNT, MAX_ENERGY, MOVE_COST,
+  MOVE_COST_MAX, SPACE_HEIGHT, SPACE_WIDTH,
 };
 
 use crate::engine::functions::location::{
   to_index_from_xy, to_x_from_index, to_y_from_index,
 };
-use crate::models::bug::{Bug, Species};
+use crate::engine::functions::species::classify;
+use crate::models::bug::Bug;
+use crate::models::classifier::Classifier;
 use crate::models::clock::Clock;
-use crate::models::fauna::Fauna;
+use crate::models::fauna::{Aging, Crowding, Fauna};
 use crate::models::flora::Flora;
+use crate::models::garden::Garden;
+use crate::models::genome::Genome;
+use crate::models::options::Schedule;
+use crate::models::random::Random;
+use crate::models::region::Region;
+use crate::models::reset::{GenomeSource, Placement, Reset, ResetSettings};
 use com_croftsoft_lib_role::Updater;
-use core::cell::{RefCell, RefMut};
+use core::cell::{Ref, RefCell, RefMut};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
 use std::rc::Rc;
 
-// TODO: Should I be using the js_sys random?
-use rand::{rngs::ThreadRng, Rng};
-
 pub trait FaunaUpdaterEvents {
   fn set_updated(&mut self);
 }
 
 pub trait FaunaUpdaterInputs {
+  fn get_aging_change_requested(&self) -> Option<Aging>;
   fn get_bug_requested(&self) -> Option<usize>;
+  fn get_crowding_change_requested(&self) -> Option<Crowding>;
+  fn get_genomes_import_requested(&self) -> Option<Vec<Genome>>;
   fn get_reset_requested(&self) -> bool;
+  fn get_reset_settings_change_requested(&self) -> Option<ResetSettings>;
+  fn get_start_region_change_requested(&self) -> Option<Region>;
   fn get_time_to_update(&self) -> bool;
+  fn get_traits_heritable_change_requested(&self) -> Option<bool>;
 }
 
 pub trait FaunaUpdaterOptions {
+  fn get_genome(&self) -> Option<Genome>;
   fn get_pause(&self) -> bool;
+  fn get_schedule(&self) -> Schedule;
 }
 
 pub struct FaunaUpdater {
+  classifier: Rc<RefCell<Classifier>>,
   clock: Rc<RefCell<Clock>>,
   events: Rc<RefCell<dyn FaunaUpdaterEvents>>,
   fauna: Rc<RefCell<Fauna>>,
   flora: Rc<RefCell<Flora>>,
   inputs: Rc<RefCell<dyn FaunaUpdaterInputs>>,
   options: Rc<RefCell<dyn FaunaUpdaterOptions>>,
+  random: Rc<RefCell<Random>>,
+  reset: Rc<RefCell<Reset>>,
 }
 
 impl FaunaUpdater {
-  fn classify(bug: &Bug) -> Species {
-    let mut x_sum: isize = 0;
-    let mut y_sum: isize = 0;
-    for i in 0..GENES_MAX {
-      if bug.genes_x[i] {
-        x_sum += 1;
-      } else {
-        x_sum -= 1;
-      }
-      if bug.genes_y[i] {
-        y_sum += 1;
-      } else {
-        y_sum -= 1;
-      }
-    }
-    let unscaled_speed: f64 =
-      ((x_sum as f64).powi(2) + (y_sum as f64).powi(2)).sqrt();
-    let scaling_factor: f64 = (2.0 * ((GENES_MAX as f64).powi(2))).sqrt();
-    let speed: f64 = unscaled_speed / scaling_factor;
-    if speed <= 0.30 {
-      return Species::Twirlie;
-    }
-    if speed >= 0.70 {
-      return Species::Cruiser;
+  // Starts a new run with one founder per genome which is kept for resets
+  fn import(
+    &self,
+    genomes: Vec<Genome>,
+  ) {
+    let population: usize = genomes.len();
+    self.reset.borrow_mut().genomes_supplied = genomes;
+    self.reset(GenomeSource::Supplied, population);
+  }
+
+  // Makes the founder of a new lineage
+  fn make_bug(
+    classifier: &Classifier,
+    genome: &Genome,
+    fauna: &mut Fauna,
+    position: usize,
+    tick: usize,
+  ) -> Bug {
+    let lineage: usize = fauna.lineage_next;
+    fauna.lineage_next += 1;
+    let species: usize = classify(classifier, &genome.genes_x, &genome.genes_y);
+    Bug {
+      baby_energy: BABY_ENERGY,
+      birth_energy: BIRTH_ENERGY,
+      birth_tick: tick,
+      energy: BABY_ENERGY,
+      genes_x: genome.genes_x,
+      genes_y: genome.genes_y,
+      generation: 0,
+      lineage,
+      move_cost: MOVE_COST,
+      position,
+      species,
     }
-    Species::Normal
   }
 
-  fn make_bug(position: usize) -> Bug {
-    let species = Species::Normal;
-    let energy: usize = BABY_ENERGY;
+  // The location of a founder scattered over the region of the placement
+  fn make_founder_position(
+    placement: Placement,
+    region_option: Option<Region>,
+    gardens: &[Garden],
+    rng: &mut StdRng,
+  ) -> usize {
+    let region: Region = match (placement, region_option) {
+      (Placement::Gardens, _) => match gardens.choose(rng) {
+        Some(garden) => garden.to_region(),
+        None => return to_index_from_xy(SPACE_WIDTH / 2, SPACE_HEIGHT / 2),
+      },
+      (Placement::Region, Some(region)) => region,
+      (Placement::Uniform, _) => Region {
+        height: SPACE_HEIGHT,
+        width: SPACE_WIDTH,
+        x: 0,
+        y: 0,
+      },
+      _ => return to_index_from_xy(SPACE_WIDTH / 2, SPACE_HEIGHT / 2),
+    };
+    region.to_index(
+      rng.gen_range(0..region.width),
+      rng.gen_range(0..region.height),
+    )
+  }
+
+  fn make_genome(rng: &mut StdRng) -> Genome {
     let mut genes_x: [bool; GENES_MAX] = [false; GENES_MAX];
     let mut genes_y: [bool; GENES_MAX] = [false; GENES_MAX];
     for index in 0..GENES_MAX {
-      genes_x[index] = rand::random();
-      genes_y[index] = rand::random();
+      genes_x[index] = rng.gen();
+      genes_y[index] = rng.gen();
     }
-    let mut bug = Bug {
-      energy,
+    Genome {
       genes_x,
       genes_y,
-      position,
-      species,
-    };
-    bug.species = Self::classify(&bug);
-    bug
+    }
+  }
+
+  // Shifts a trait up or down by one within its bounds one time in ten
+  fn mutate_trait(
+    value: usize,
+    min: usize,
+    max: usize,
+    rng: &mut StdRng,
+  ) -> usize {
+    if rng.gen_range(0..10) != 0 {
+      return value;
+    }
+    if rng.gen() {
+      (value + 1).min(max)
+    } else {
+      value.saturating_sub(1).max(min)
+    }
   }
 
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
+    classifier: Rc<RefCell<Classifier>>,
     clock: Rc<RefCell<Clock>>,
     events: Rc<RefCell<dyn FaunaUpdaterEvents>>,
     fauna: Rc<RefCell<Fauna>>,
     flora: Rc<RefCell<Flora>>,
     inputs: Rc<RefCell<dyn FaunaUpdaterInputs>>,
     options: Rc<RefCell<dyn FaunaUpdaterOptions>>,
+    random: Rc<RefCell<Random>>,
+    reset: Rc<RefCell<Reset>>,
   ) -> Self {
     Self {
+      classifier,
       clock,
       events,
       fauna,
       flora,
       inputs,
       options,
+      random,
+      reset,
+    }
+  }
+
+  // The highest energy or a random draw with ties in energy broken at random
+  fn select_winner(
+    candidates: &[usize],
+    energies: &[usize],
+    energy_contest: bool,
+    rng: &mut StdRng,
+  ) -> Option<usize> {
+    if !energy_contest {
+      return candidates.choose(rng).copied();
     }
+    let energy_max: usize =
+      candidates.iter().map(|index| energies[*index]).max()?;
+    let strongest: Vec<usize> = candidates
+      .iter()
+      .copied()
+      .filter(|index| energies[*index] == energy_max)
+      .collect();
+    strongest.choose(rng).copied()
   }
 
-  fn reset(&self) {
-    let position: usize = to_index_from_xy(SPACE_WIDTH / 2, SPACE_HEIGHT / 2);
+  // Replaces the population with founders placed per the reset settings
+  fn reset(
+    &self,
+    genome_source: GenomeSource,
+    population: usize,
+  ) {
+    let genome_option: Option<Genome> = self.options.borrow().get_genome();
+    let reset: Ref<Reset> = self.reset.borrow();
     let mut fauna: RefMut<Fauna> = self.fauna.borrow_mut();
-    fauna.bugs.clear();
-    for _i in 0..BUGS_MAX {
-      fauna.bugs.push(Self::make_bug(position));
+    let mut random: RefMut<Random> = self.random.borrow_mut();
+    let classifier: Ref<Classifier> = self.classifier.borrow();
+    let tick: usize = self.clock.borrow().tick;
+    // An empty source falls back to random genomes
+    let genomes: Vec<Genome> = match genome_source {
+      GenomeSource::Random => Vec::new(),
+      GenomeSource::Single => vec![
+        genome_option.unwrap_or_else(|| Self::make_genome(&mut random.rng)),
+      ],
+      GenomeSource::Supplied => reset.genomes_supplied.clone(),
+      GenomeSource::Survivors => (0..fauna.len())
+        .map(|index| Genome {
+          genes_x: fauna.genes_x[index],
+          genes_y: fauna.genes_y[index],
+        })
+        .collect(),
+    };
+    fauna.clear();
+    fauna.lineage_next = 0;
+    for index in 0..population.min(BUGS_MAX) {
+      let genome: Genome = if genomes.is_empty() {
+        Self::make_genome(&mut random.rng)
+      } else {
+        genomes[index % genomes.len()]
+      };
+      let position: usize = Self::make_founder_position(
+        reset.settings.placement,
+        reset.region,
+        &self.flora.borrow().gardens,
+        &mut random.rng,
+      );
+      let bug: Bug =
+        Self::make_bug(&classifier, &genome, &mut fauna, position, tick);
+      fauna.push(bug);
     }
+    fauna.rebuild_occupancy();
   }
 
+  // Charges the cost of age or kills by age as set by the aging option
+  fn update_aging(
+    fauna: &mut Fauna,
+    tick: usize,
+    rng: &mut StdRng,
+  ) {
+    let aging: Aging = fauna.aging;
+    if aging == Aging::Off {
+      return;
+    }
+    for index in 0..fauna.len() {
+      let age: usize = tick - fauna.birth_ticks[index];
+      let energy: &mut usize = &mut fauna.energies[index];
+      match aging {
+        Aging::Cost(ticks) => *energy = energy.saturating_sub(age / ticks),
+        Aging::Lifespan(ticks) => {
+          if age >= ticks {
+            *energy = 0;
+          }
+        },
+        Aging::Mortality(ticks) => {
+          if rng.gen_range(0..ticks) < age {
+            *energy = 0;
+          }
+        },
+        Aging::Off => {},
+      }
+    }
+  }
+
+  #[allow(clippy::too_many_arguments)]
   fn update_bug(
-    bug: &mut Bug,
+    fauna: &mut Fauna,
+    index: usize,
     bugs_length: usize,
-    flora_present: &mut [bool; LOCATION_COUNT],
+    classifier: &Classifier,
+    clock: &Clock,
+    coin_flips: u64,
+    flora: &mut Flora,
     new_bugs: &mut Vec<Bug>,
-    time: usize,
+    rng: &mut StdRng,
   ) {
-    Self::update_bug_graze(bug, flora_present);
-    Self::update_bug_spawn(bug, bugs_length, new_bugs);
-    Self::update_bug_move(bug, time);
+    Self::update_bug_graze(fauna, index, flora);
+    Self::update_bug_spawn(
+      fauna,
+      index,
+      bugs_length,
+      classifier,
+      new_bugs,
+      rng,
+      clock.tick,
+    );
+    Self::update_bug_move(fauna, index, coin_flips, clock.time);
   }
 
   fn update_bug_graze(
-    bug: &mut Bug,
-    flora_present: &mut [bool; LOCATION_COUNT],
+    fauna: &mut Fauna,
+    index: usize,
+    flora: &mut Flora,
   ) {
-    let bug_position: usize = bug.position;
-    if flora_present[bug_position] {
-      flora_present[bug_position] = false;
-      bug.energy = bug.energy.saturating_add(FLORA_ENERGY);
-      if bug.energy > MAX_ENERGY {
-        bug.energy = MAX_ENERGY;
-      }
+    let bug_position: usize = fauna.positions[index];
+    // At most one meal and no more than the room left for energy
+    let appetite: usize =
+      FLORA_ENERGY.min(MAX_ENERGY.saturating_sub(fauna.energies[index]));
+    let mut food_energy: usize = flora.graze(bug_position, appetite);
+    if flora.has_carrion(bug_position) {
+      flora.corpse_ticks[bug_position] = 0;
+      food_energy += CORPSE_ENERGY;
+    }
+    if food_energy > 0 {
+      fauna.grazed_positions.push(bug_position);
+      // A slower metabolism extracts less energy from the same food and no
+      // metabolism extracts more than the food holds
+      let energy_gained: usize = (food_energy * (1 + fauna.move_costs[index])
+        / (1 + MOVE_COST))
+        .min(food_energy);
+      let energy: &mut usize = &mut fauna.energies[index];
+      *energy = energy.saturating_add(energy_gained).min(MAX_ENERGY);
     }
   }
 
+  // The lowest two bits of the coin flips decide whether to move in x and y
   fn update_bug_move(
-    bug: &mut Bug,
+    fauna: &mut Fauna,
+    index: usize,
+    coin_flips: u64,
     time: usize,
   ) {
-    let bug_position: usize = bug.position;
-    let mut x = to_x_from_index(bug_position);
-    let mut y = to_y_from_index(bug_position);
-    if rand::random() {
-      if bug.genes_x[time] {
-        if x < SPACE_WIDTH - 1 {
-          x += 1;
-        } else {
-          x = 0;
+    let bug_position: usize = fauna.positions[index];
+    // Arithmetic instead of branches since the coin flips are unpredictable
+    let step_x: usize = if fauna.genes_x[index][time] {
+      1
+    } else {
+      SPACE_WIDTH - 1
+    };
+    let step_y: usize = if fauna.genes_y[index][time] {
+      1
+    } else {
+      SPACE_HEIGHT - 1
+    };
+    let x: usize = (to_x_from_index(bug_position)
+      + (coin_flips & 1) as usize * step_x)
+      % SPACE_WIDTH;
+    let y: usize = (to_y_from_index(bug_position)
+      + (coin_flips >> 1 & 1) as usize * step_y)
+      % SPACE_HEIGHT;
+    let mut position: usize = to_index_from_xy(x, y);
+    let mut energy_cost: usize = fauna.move_costs[index];
+    match fauna.crowding {
+      Crowding::Capacity(capacity) => {
+        if position != bug_position && fauna.crowd_counts[position] >= capacity
+        {
+          position = bug_position;
         }
-      } else if x > 0 {
-        x -= 1;
-      } else {
-        x = SPACE_WIDTH - 1;
-      }
+      },
+      Crowding::Cost(cost) => {
+        // The other bugs already there plus the bug itself if it stays put
+        let neighbors: usize =
+          fauna.crowd_counts[position] - usize::from(position == bug_position);
+        energy_cost += cost * neighbors;
+      },
+      Crowding::Off => {},
     }
-    if rand::random() {
-      if bug.genes_y[time] {
-        if y < SPACE_HEIGHT - 1 {
-          y += 1;
-        } else {
-          y = 0;
-        }
-      } else if y > 0 {
-        y -= 1;
-      } else {
-        y = SPACE_HEIGHT - 1;
-      }
+    if fauna.crowding != Crowding::Off {
+      fauna.crowd_counts[bug_position] -= 1;
+      fauna.crowd_counts[position] += 1;
     }
-    bug.position = to_index_from_xy(x, y);
-    bug.energy = bug.energy.saturating_sub(MOVE_COST);
+    fauna.record_trail(index, bug_position);
+    fauna.positions[index] = position;
+    fauna.energies[index] = fauna.energies[index].saturating_sub(energy_cost);
   }
 
   fn update_bug_spawn(
-    bug: &mut Bug,
+    fauna: &mut Fauna,
+    index: usize,
     bugs_length: usize,
+    classifier: &Classifier,
     new_bugs: &mut Vec<Bug>,
+    rng: &mut StdRng,
+    tick: usize,
   ) {
-    if bug.energy < BIRTH_ENERGY || bugs_length + new_bugs.len() >= BUGS_MAX {
+    if !fauna.can_spawn(index) || bugs_length + new_bugs.len() >= BUGS_MAX {
       return;
     }
-    bug.energy = bug.energy.saturating_sub(BIRTH_ENERGY_COST);
+    let position: usize = fauna.positions[index];
+    if let Crowding::Capacity(capacity) = fauna.crowding {
+      if fauna.crowd_counts[position] >= capacity {
+        return;
+      }
+    }
+    if fauna.crowding != Crowding::Off {
+      fauna.crowd_counts[position] += 1;
+    }
+    // The offspring energy comes out of the parent so energy is conserved
+    let baby_energy: usize = fauna.baby_energies[index];
+    fauna.energies[index] -= baby_energy + BIRTH_ENERGY_OVERHEAD;
+    fauna.offspring_counts[index] += 1;
+    if fauna.first_spawn_ticks[index].is_none() {
+      fauna.first_spawn_ticks[index] = Some(tick);
+    }
     let mut baby_bug = Bug {
-      energy: BABY_ENERGY,
-      genes_x: bug.genes_x,
-      genes_y: bug.genes_y,
-      position: bug.position,
-      species: bug.species,
+      baby_energy,
+      birth_energy: fauna.birth_energies[index],
+      birth_tick: tick,
+      energy: baby_energy,
+      genes_x: fauna.genes_x[index],
+      genes_y: fauna.genes_y[index],
+      generation: fauna.generations[index] + 1,
+      lineage: fauna.lineages[index],
+      move_cost: fauna.move_costs[index],
+      position,
+      species: fauna.species[index],
     };
-    let mut thread_rng: ThreadRng = rand::thread_rng();
-    let roll: usize = thread_rng.gen_range(0..10);
+    let roll: usize = rng.gen_range(0..10);
     if roll == 0 {
-      let mutant_gene_index: usize = thread_rng.gen_range(0..GENES_MAX);
-      if rand::random() {
-        baby_bug.genes_x[mutant_gene_index] = !bug.genes_x[mutant_gene_index];
+      let mutant_gene_index: usize = rng.gen_range(0..GENES_MAX);
+      if rng.gen() {
+        baby_bug.genes_x[mutant_gene_index] =
+          !baby_bug.genes_x[mutant_gene_index];
       } else {
-        baby_bug.genes_y[mutant_gene_index] = !bug.genes_y[mutant_gene_index];
+        baby_bug.genes_y[mutant_gene_index] =
+          !baby_bug.genes_y[mutant_gene_index];
       }
     }
-    baby_bug.species = Self::classify(&baby_bug);
+    if fauna.traits_heritable {
+      baby_bug.baby_energy =
+        Self::mutate_trait(baby_bug.baby_energy, 1, MAX_ENERGY, rng);
+      baby_bug.birth_energy =
+        Self::mutate_trait(baby_bug.birth_energy, 1, MAX_ENERGY, rng);
+      baby_bug.move_cost =
+        Self::mutate_trait(baby_bug.move_cost, 0, MOVE_COST_MAX, rng);
+    }
+    baby_bug.species =
+      classify(classifier, &baby_bug.genes_x, &baby_bug.genes_y);
     new_bugs.push(baby_bug);
   }
+
+  // All of the bugs graze, then all spawn, then all move so that no bug gains
+  // from its place in the order.  Contests decide which of the bugs on a
+  // location eats its food and which of the bugs ready to spawn get the
+  // remaining room when there is not enough for all of them.
+  #[allow(clippy::too_many_arguments)]
+  fn update_simultaneous(
+    fauna: &mut Fauna,
+    classifier: &Classifier,
+    clock: &Clock,
+    coin_flips: &[u64],
+    flora: &mut Flora,
+    new_bugs: &mut Vec<Bug>,
+    rng: &mut StdRng,
+    energy_contest: bool,
+  ) {
+    let bugs_length: usize = fauna.len();
+    for location in 0..LOCATION_COUNT {
+      if !flora.flora_present[location] && !flora.has_carrion(location) {
+        continue;
+      }
+      let winner_option: Option<usize> = Self::select_winner(
+        fauna.occupancy.get_bug_indices(location),
+        &fauna.energies,
+        energy_contest,
+        rng,
+      );
+      if let Some(winner) = winner_option {
+        Self::update_bug_graze(fauna, winner, flora);
+      }
+    }
+    let mut parents: Vec<usize> = (0..bugs_length)
+      .filter(|index| fauna.can_spawn(*index))
+      .collect();
+    // Shuffled so that ties in energy and for crowded locations are random
+    parents.shuffle(rng);
+    let room: usize = BUGS_MAX.saturating_sub(bugs_length + new_bugs.len());
+    if parents.len() > room {
+      if energy_contest {
+        parents.sort_by(|a, b| fauna.energies[*b].cmp(&fauna.energies[*a]));
+      }
+      parents.truncate(room);
+    }
+    for index in parents {
+      Self::update_bug_spawn(
+        fauna,
+        index,
+        bugs_length,
+        classifier,
+        new_bugs,
+        rng,
+        clock.tick,
+      );
+    }
+    let mut movers: Vec<usize> = (0..bugs_length).collect();
+    // The first to arrive at a crowded location would otherwise be favored
+    if fauna.crowding != Crowding::Off {
+      movers.shuffle(rng);
+    }
+    for index in movers {
+      Self::update_bug_move(
+        fauna,
+        index,
+        coin_flips[2 * index / 64] >> (2 * index % 64),
+        clock.time,
+      );
+    }
+  }
 }
 
 impl Updater for FaunaUpdater {
   fn update(&self) {
+    if let Some(reset_settings) =
+      self.inputs.borrow().get_reset_settings_change_requested()
+    {
+      self.reset.borrow_mut().settings = reset_settings;
+    }
+    if let Some(region) =
+      self.inputs.borrow().get_start_region_change_requested()
+    {
+      self.reset.borrow_mut().region = Some(region);
+      self.events.borrow_mut().set_updated();
+    }
     if self.inputs.borrow().get_reset_requested() {
-      self.reset();
+      let reset_settings: ResetSettings = self.reset.borrow().settings;
+      self.reset(reset_settings.genome_source, reset_settings.population);
+      self.events.borrow_mut().set_updated();
+      return;
+    }
+    if let Some(aging) = self.inputs.borrow().get_aging_change_requested() {
+      self.fauna.borrow_mut().aging = aging;
+    }
+    if let Some(crowding) = self.inputs.borrow().get_crowding_change_requested()
+    {
+      self.fauna.borrow_mut().crowding = crowding;
+    }
+    if let Some(traits_heritable) =
+      self.inputs.borrow().get_traits_heritable_change_requested()
+    {
+      let mut fauna: RefMut<Fauna> = self.fauna.borrow_mut();
+      fauna.traits_heritable = traits_heritable;
+      if !traits_heritable {
+        fauna.reset_traits();
+      }
+    }
+    if let Some(genomes) = self.inputs.borrow().get_genomes_import_requested() {
+      self.import(genomes);
       self.events.borrow_mut().set_updated();
       return;
     }
     let mut new_bugs = Vec::<Bug>::new();
-    let bugs_length = self.fauna.borrow().bugs.len();
+    let bugs_length = self.fauna.borrow().len();
     if bugs_length < BUGS_MAX {
       if let Some(position_index) = self.inputs.borrow().get_bug_requested() {
-        new_bugs.push(Self::make_bug(position_index));
+        let genome: Genome = match self.options.borrow().get_genome() {
+          Some(genome) => genome,
+          None => Self::make_genome(&mut self.random.borrow_mut().rng),
+        };
+        new_bugs.push(Self::make_bug(
+          &self.classifier.borrow(),
+          &genome,
+          &mut self.fauna.borrow_mut(),
+          position_index,
+          self.clock.borrow().tick,
+        ));
       }
     }
     let time_to_update: bool = self.inputs.borrow().get_time_to_update();
+    let mut fauna: RefMut<Fauna> = self.fauna.borrow_mut();
+    let fauna: &mut Fauna = &mut fauna;
+    let mut updated: bool = false;
     if time_to_update && !self.options.borrow().get_pause() {
-      for bug in self.fauna.borrow_mut().bugs.iter_mut() {
-        Self::update_bug(
-          bug,
-          bugs_length,
-          &mut self.flora.borrow_mut().flora_present,
-          &mut new_bugs,
-          self.clock.borrow().time,
-        );
+      // Borrowed once for the whole population instead of once per bug
+      let classifier: Ref<Classifier> = self.classifier.borrow();
+      let clock: Ref<Clock> = self.clock.borrow();
+      let mut flora: RefMut<Flora> = self.flora.borrow_mut();
+      let mut random: RefMut<Random> = self.random.borrow_mut();
+      fauna.deaths.clear();
+      fauna.grazed_positions.clear();
+      fauna.advance_trails();
+      // Two coin flips per bug drawn in bulk rather than one draw per flip
+      let mut coin_flips: Vec<u64> = vec![0; (2 * bugs_length + 63) / 64];
+      random.rng.fill(&mut coin_flips[..]);
+      if fauna.crowding != Crowding::Off {
+        fauna.count_crowds();
       }
-      self.fauna.borrow_mut().bugs.retain(|bug| bug.energy > 0);
-      self.events.borrow_mut().set_updated();
+      let schedule: Schedule = self.options.borrow().get_schedule();
+      match schedule {
+        Schedule::Permutation | Schedule::Sequential => {
+          let mut order: Vec<usize> = (0..bugs_length).collect();
+          if schedule == Schedule::Permutation {
+            order.shuffle(&mut random.rng);
+          }
+          for index in order {
+            Self::update_bug(
+              fauna,
+              index,
+              bugs_length,
+              &classifier,
+              &clock,
+              coin_flips[2 * index / 64] >> (2 * index % 64),
+              &mut flora,
+              &mut new_bugs,
+              &mut random.rng,
+            );
+          }
+        },
+        Schedule::SimultaneousEnergy | Schedule::SimultaneousRandom => {
+          Self::update_simultaneous(
+            fauna,
+            &classifier,
+            &clock,
+            &coin_flips,
+            &mut flora,
+            &mut new_bugs,
+            &mut random.rng,
+            schedule == Schedule::SimultaneousEnergy,
+          );
+        },
+      }
+      Self::update_aging(fauna, clock.tick, &mut random.rng);
+      fauna.remove_dead(clock.tick);
+      for death in fauna.deaths.iter() {
+        flora.decompose(death.position);
+      }
+      updated = true;
     }
     if !new_bugs.is_empty() {
-      self.fauna.borrow_mut().bugs.append(&mut new_bugs);
+      for bug in new_bugs {
+        fauna.push(bug);
+      }
+      updated = true;
+    }
+    if updated {
+      fauna.rebuild_occupancy();
       self.events.borrow_mut().set_updated();
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::{
+    FaunaUpdater, FaunaUpdaterEvents, FaunaUpdaterInputs, FaunaUpdaterOptions,
+  };
+  use crate::constants::{
+    BABY_ENERGY, BIOMASS_CAPACITY, BIRTH_ENERGY, BIRTH_ENERGY_COST,
+    BIRTH_ENERGY_OVERHEAD, BUGS_MAX, CORPSE_ENERGY, CORPSE_TICKS, FLORA_ENERGY,
+    GENES_MAX, LOCATION_COUNT, MAX_ENERGY, MOVE_COST, MOVE_COST_MAX,
+    SPACE_HEIGHT, SPACE_WIDTH, TRAIL_LENGTH,
+  };
+  use crate::engine::functions::location::{
+    to_index_from_xy, to_x_from_index, to_y_from_index,
+  };
+  use crate::engine::functions::species::classify;
+  use crate::models::bug::Bug;
+  use crate::models::classifier::Classifier;
+  use crate::models::clock::Clock;
+  use crate::models::fauna::{Aging, Crowding, Fauna};
+  use crate::models::flora::{Decomposition, Flora, FloraMode};
+  use crate::models::genome::Genome;
+  use crate::models::options::Schedule;
+  use crate::models::random::Random;
+  use crate::models::region::Region;
+  use crate::models::reset::{GenomeSource, Placement, Reset, ResetSettings};
+  use com_croftsoft_lib_role::Updater;
+  use core::cell::{RefCell, RefMut};
+  use rand::rngs::StdRng;
+  use rand::Rng;
+  use std::collections::VecDeque;
+  use std::rc::Rc;
+  use std::time::Instant;
+
+  // The bug structure from before the fauna was split into columns
+  #[allow(dead_code)]
+  struct StructBug {
+    birth_tick: usize,
+    energy: usize,
+    genes_x: [bool; GENES_MAX],
+    genes_y: [bool; GENES_MAX],
+    generation: usize,
+    lineage: usize,
+    position: usize,
+    species: usize,
+    trail: VecDeque<usize>,
+  }
+
+  const FLORA_SPACING: usize = 8;
+  const SEED: u64 = 0;
+  const TICKS: usize = 1_000;
+
+  struct Stub {
+    schedule: Schedule,
+  }
+
+  impl FaunaUpdaterEvents for Stub {
+    fn set_updated(&mut self) {}
+  }
+
+  impl FaunaUpdaterInputs for Stub {
+    fn get_aging_change_requested(&self) -> Option<Aging> {
+      None
+    }
+
+    fn get_bug_requested(&self) -> Option<usize> {
+      None
+    }
+
+    fn get_crowding_change_requested(&self) -> Option<Crowding> {
+      None
+    }
+
+    fn get_genomes_import_requested(&self) -> Option<Vec<Genome>> {
+      None
+    }
+
+    fn get_reset_requested(&self) -> bool {
+      false
+    }
+
+    fn get_reset_settings_change_requested(&self) -> Option<ResetSettings> {
+      None
+    }
+
+    fn get_start_region_change_requested(&self) -> Option<Region> {
+      None
+    }
+
+    fn get_time_to_update(&self) -> bool {
+      true
+    }
+
+    fn get_traits_heritable_change_requested(&self) -> Option<bool> {
+      None
+    }
+  }
+
+  impl FaunaUpdaterOptions for Stub {
+    fn get_genome(&self) -> Option<Genome> {
+      None
+    }
+
+    fn get_pause(&self) -> bool {
+      false
+    }
+
+    fn get_schedule(&self) -> Schedule {
+      self.schedule
+    }
+  }
+
+  // Sparse enough that bugs starve and spawn every update
+  fn grow(
+    flora: &mut Flora,
+    tick: usize,
+  ) {
+    for (location, present) in flora.flora_present.iter_mut().enumerate() {
+      *present = (location + tick) % FLORA_SPACING == 0;
+    }
+  }
+
+  // A full population scattered over the whole space
+  fn make_bugs(rng: &mut StdRng) -> Vec<Bug> {
+    (0..BUGS_MAX)
+      .map(|_| {
+        let genome: Genome = FaunaUpdater::make_genome(rng);
+        Bug {
+          baby_energy: BABY_ENERGY,
+          birth_energy: BIRTH_ENERGY,
+          birth_tick: 0,
+          energy: BIRTH_ENERGY,
+          genes_x: genome.genes_x,
+          genes_y: genome.genes_y,
+          generation: 0,
+          lineage: 0,
+          move_cost: MOVE_COST,
+          position: rng.gen_range(0..LOCATION_COUNT),
+          species: 0,
+        }
+      })
+      .collect()
+  }
+
+  // An updater of the first bugs of a full population over the whole space
+  #[allow(clippy::type_complexity)]
+  fn make_fauna_updater(
+    bug_count: usize,
+    reset: Reset,
+    schedule: Schedule,
+  ) -> (
+    FaunaUpdater,
+    Rc<RefCell<Clock>>,
+    Rc<RefCell<Fauna>>,
+    Rc<RefCell<Flora>>,
+    Rc<RefCell<Random>>,
+  ) {
+    let clock = Rc::new(RefCell::new(Clock::default()));
+    let fauna = Rc::new(RefCell::new(Fauna::default()));
+    let flora = Rc::new(RefCell::new(Flora::default()));
+    let random = Rc::new(RefCell::new(Random::default()));
+    random.borrow_mut().reseed(SEED);
+    {
+      let mut fauna: RefMut<Fauna> = fauna.borrow_mut();
+      for bug in make_bugs(&mut random.borrow_mut().rng)
+        .into_iter()
+        .take(bug_count)
+      {
+        fauna.push(bug);
+      }
+      fauna.rebuild_occupancy();
+    }
+    let stub = Rc::new(RefCell::new(Stub {
+      schedule,
+    }));
+    let fauna_updater = FaunaUpdater::new(
+      Rc::new(RefCell::new(Classifier::default())),
+      clock.clone(),
+      stub.clone(),
+      fauna.clone(),
+      flora.clone(),
+      stub.clone(),
+      stub,
+      random.clone(),
+      Rc::new(RefCell::new(reset)),
+    );
+    (fauna_updater, clock, fauna, flora, random)
+  }
+
+  // The index of which of two bugs of the given energies on a cell eats
+  fn to_graze_winner(
+    schedule: Schedule,
+    energies: [usize; 2],
+    seed: u64,
+  ) -> usize {
+    let (fauna_updater, _, fauna, flora, random) =
+      make_fauna_updater(2, Reset::default(), schedule);
+    random.borrow_mut().reseed(seed);
+    let position: usize = to_index_from_xy(0, 0);
+    {
+      let mut fauna: RefMut<Fauna> = fauna.borrow_mut();
+      fauna.energies = energies.to_vec();
+      fauna.positions = vec![position; 2];
+      fauna.rebuild_occupancy();
+    }
+    flora.borrow_mut().flora_present[position] = true;
+    fauna_updater.update();
+    let fauna = fauna.borrow();
+    assert_eq!(fauna.len(), 2);
+    // The winner gains the food and both pay for the move
+    let winner: usize = if fauna.energies[0] > energies[0] {
+      0
+    } else {
+      1
+    };
+    assert_eq!(
+      fauna.energies[winner],
+      energies[winner] + FLORA_ENERGY - MOVE_COST
+    );
+    assert_eq!(fauna.energies[1 - winner], energies[1 - winner] - MOVE_COST);
+    winner
+  }
+
+  // Which of the first and the last of a population one short of the cap
+  // spawn when both are ready to spawn with the given energies
+  fn to_spawned_at_cap(
+    schedule: Schedule,
+    energies: [usize; 2],
+    seed: u64,
+  ) -> [bool; 2] {
+    let (fauna_updater, _, fauna, _, random) =
+      make_fauna_updater(BUGS_MAX - 1, Reset::default(), schedule);
+    random.borrow_mut().reseed(seed);
+    let last: usize = BUGS_MAX - 2;
+    {
+      let mut fauna: RefMut<Fauna> = fauna.borrow_mut();
+      for energy in fauna.energies.iter_mut() {
+        *energy = BABY_ENERGY;
+      }
+      fauna.energies[0] = energies[0];
+      fauna.energies[last] = energies[1];
+    }
+    fauna_updater.update();
+    let fauna = fauna.borrow();
+    assert_eq!(fauna.len(), BUGS_MAX);
+    let spawned: [bool; 2] = [
+      fauna.energies[0] == energies[0] - BIRTH_ENERGY_COST - MOVE_COST,
+      fauna.energies[last] == energies[1] - BIRTH_ENERGY_COST - MOVE_COST,
+    ];
+    assert!(spawned[0] != spawned[1]);
+    spawned
+  }
+
+  // The array of structures update loop which the columns replaced
+  fn update_array_of_structures(
+    bugs: &mut Vec<StructBug>,
+    classifier: &RefCell<Classifier>,
+    clock: &RefCell<Clock>,
+    flora: &RefCell<Flora>,
+    random: &RefCell<Random>,
+  ) {
+    let bugs_length: usize = bugs.len();
+    let mut new_bugs = Vec::<StructBug>::new();
+    for bug in bugs.iter_mut() {
+      let flora_present: &mut [bool; LOCATION_COUNT] =
+        &mut flora.borrow_mut().flora_present;
+      if flora_present[bug.position] {
+        flora_present[bug.position] = false;
+        bug.energy = (bug.energy + FLORA_ENERGY).min(MAX_ENERGY);
+      }
+      let rng: &mut StdRng = &mut random.borrow_mut().rng;
+      if bug.energy >= BIRTH_ENERGY && bugs_length + new_bugs.len() < BUGS_MAX {
+        bug.energy -= BIRTH_ENERGY_COST;
+        let mut baby_bug = StructBug {
+          birth_tick: clock.borrow().tick,
+          energy: BABY_ENERGY,
+          genes_x: bug.genes_x,
+          genes_y: bug.genes_y,
+          generation: bug.generation + 1,
+          lineage: bug.lineage,
+          position: bug.position,
+          species: bug.species,
+          trail: VecDeque::with_capacity(TRAIL_LENGTH),
+        };
+        if rng.gen_range(0..10) == 0 {
+          let mutant_gene_index: usize = rng.gen_range(0..GENES_MAX);
+          if rng.gen() {
+            baby_bug.genes_x[mutant_gene_index] =
+              !bug.genes_x[mutant_gene_index];
+          } else {
+            baby_bug.genes_y[mutant_gene_index] =
+              !bug.genes_y[mutant_gene_index];
+          }
+        }
+        baby_bug.species =
+          classify(&classifier.borrow(), &baby_bug.genes_x, &baby_bug.genes_y);
+        new_bugs.push(baby_bug);
+      }
+      let time: usize = clock.borrow().time;
+      let mut x: usize = to_x_from_index(bug.position);
+      let mut y: usize = to_y_from_index(bug.position);
+      if rng.gen() {
+        x = if bug.genes_x[time] {
+          (x + 1) % SPACE_WIDTH
+        } else {
+          (x + SPACE_WIDTH - 1) % SPACE_WIDTH
+        };
+      }
+      if rng.gen() {
+        y = if bug.genes_y[time] {
+          (y + 1) % SPACE_HEIGHT
+        } else {
+          (y + SPACE_HEIGHT - 1) % SPACE_HEIGHT
+        };
+      }
+      if bug.trail.len() >= TRAIL_LENGTH {
+        bug.trail.pop_back();
+      }
+      bug.trail.push_front(bug.position);
+      bug.position = to_index_from_xy(x, y);
+      bug.energy = bug.energy.saturating_sub(MOVE_COST);
+    }
+    bugs.retain(|bug| bug.energy > 0);
+    bugs.append(&mut new_bugs);
+  }
+
+  // Compares the columns with the array of structures natively:
+  // cargo test --release bench_update -- --ignored --nocapture
+  #[test]
+  #[ignore]
+  fn bench_update() {
+    let classifier = Rc::new(RefCell::new(Classifier::default()));
+    let clock = Rc::new(RefCell::new(Clock::default()));
+    let flora = Rc::new(RefCell::new(Flora::default()));
+    let random = Rc::new(RefCell::new(Random::default()));
+    random.borrow_mut().reseed(SEED);
+    let mut bugs: Vec<StructBug> = make_bugs(&mut random.borrow_mut().rng)
+      .into_iter()
+      .map(|bug| StructBug {
+        birth_tick: bug.birth_tick,
+        energy: bug.energy,
+        genes_x: bug.genes_x,
+        genes_y: bug.genes_y,
+        generation: bug.generation,
+        lineage: bug.lineage,
+        position: bug.position,
+        species: bug.species,
+        trail: VecDeque::with_capacity(TRAIL_LENGTH),
+      })
+      .collect();
+    let mut array_of_structures_seconds: f64 = 0.;
+    for tick in 0..TICKS {
+      grow(&mut flora.borrow_mut(), tick);
+      let start = Instant::now();
+      update_array_of_structures(
+        &mut bugs,
+        &classifier,
+        &clock,
+        &flora,
+        &random,
+      );
+      array_of_structures_seconds += start.elapsed().as_secs_f64();
+    }
+    let (fauna_updater, _, fauna, flora, _) =
+      make_fauna_updater(BUGS_MAX, Reset::default(), Schedule::Sequential);
+    let mut columns_seconds: f64 = 0.;
+    for tick in 0..TICKS {
+      grow(&mut flora.borrow_mut(), tick);
+      let start = Instant::now();
+      fauna_updater.update();
+      columns_seconds += start.elapsed().as_secs_f64();
+    }
+    println!(
+      "{} bugs for {} ticks: array of structures {:.1} ms, columns {:.1} ms \
+      ({} and {} bugs remaining)",
+      BUGS_MAX,
+      TICKS,
+      1e3 * array_of_structures_seconds,
+      1e3 * columns_seconds,
+      bugs.len(),
+      fauna.borrow().len(),
+    );
+    assert!(fauna.borrow().len() <= BUGS_MAX);
+  }
+
+  #[test]
+  fn test_aging_lifespan() {
+    let lifespan: usize = 5;
+    let (fauna_updater, clock, fauna, flora, _) =
+      make_fauna_updater(BUGS_MAX, Reset::default(), Schedule::Sequential);
+    fauna.borrow_mut().aging = Aging::Lifespan(lifespan);
+    for tick in 1..=2 * lifespan {
+      clock.borrow_mut().tick = tick;
+      grow(&mut flora.borrow_mut(), tick);
+      fauna_updater.update();
+      let fauna = fauna.borrow();
+      assert!(fauna
+        .birth_ticks
+        .iter()
+        .all(|birth_tick| tick - birth_tick < lifespan));
+      assert!(fauna.deaths.iter().all(|death| death.age <= lifespan));
+      if tick == lifespan {
+        // Every founder that did not starve first dies of old age together
+        assert!(fauna.deaths.iter().any(|death| death.age == lifespan));
+      }
+    }
+  }
+
+  #[test]
+  fn test_biomass_appetite() {
+    let appetite: usize = 5;
+    let (fauna_updater, _, fauna, flora, _) =
+      make_fauna_updater(1, Reset::default(), Schedule::Sequential);
+    let position: usize = to_index_from_xy(0, 0);
+    {
+      let mut fauna: RefMut<Fauna> = fauna.borrow_mut();
+      fauna.energies[0] = MAX_ENERGY - appetite;
+      fauna.positions[0] = position;
+      fauna.rebuild_occupancy();
+    }
+    flora.borrow_mut().set_flora_mode(FloraMode::Biomass);
+    flora.borrow_mut().plant(position, BIOMASS_CAPACITY);
+    fauna_updater.update();
+    // Only as much as the bug had room for is eaten and the rest remains
+    assert_eq!(
+      flora.borrow().biomass[position],
+      BIOMASS_CAPACITY - appetite
+    );
+    assert!(flora.borrow().flora_present[position]);
+  }
+
+  #[test]
+  fn test_crowding_capacity() {
+    let capacity: usize = 2;
+    let (fauna_updater, _, fauna, flora, _) = make_fauna_updater(
+      capacity * LOCATION_COUNT / 4,
+      Reset::default(),
+      Schedule::Sequential,
+    );
+    {
+      let mut fauna: RefMut<Fauna> = fauna.borrow_mut();
+      for (index, position) in fauna.positions.iter_mut().enumerate() {
+        *position = index % LOCATION_COUNT;
+      }
+      fauna.rebuild_occupancy();
+      fauna.crowding = Crowding::Capacity(capacity);
+    }
+    for tick in 0..100 {
+      grow(&mut flora.borrow_mut(), tick);
+      fauna_updater.update();
+      let fauna = fauna.borrow();
+      assert!((0..LOCATION_COUNT).all(|location| fauna
+        .occupancy
+        .get_bug_indices(location)
+        .len()
+        <= capacity));
+    }
+  }
+
+  #[test]
+  fn test_decomposition_scavenging() {
+    let (fauna_updater, clock, fauna, flora, random) =
+      make_fauna_updater(BUGS_MAX, Reset::default(), Schedule::Sequential);
+    flora.borrow_mut().decomposition = Decomposition::Corpse;
+    flora.borrow_mut().scavenging = true;
+    for tick in 1..=10 {
+      clock.borrow_mut().tick = tick;
+      grow(&mut flora.borrow_mut(), tick);
+      fauna_updater.update();
+      let flora = flora.borrow();
+      assert!(fauna
+        .borrow()
+        .deaths
+        .iter()
+        .all(|death| flora.corpse_ticks[death.position] == CORPSE_TICKS));
+    }
+    // A lone bug on a corpse with no food eats the corpse
+    let position: usize = to_index_from_xy(0, 0);
+    let bug: Bug = make_bugs(&mut random.borrow_mut().rng).remove(0);
+    fauna.borrow_mut().clear();
+    fauna.borrow_mut().push(Bug {
+      energy: BABY_ENERGY,
+      position,
+      ..bug
+    });
+    {
+      let mut flora: RefMut<Flora> = flora.borrow_mut();
+      flora.clear_remains();
+      flora.flora_present = [false; LOCATION_COUNT];
+      flora.corpse_ticks[position] = CORPSE_TICKS;
+    }
+    fauna_updater.update();
+    assert_eq!(flora.borrow().corpse_ticks[position], 0);
+    assert_eq!(
+      fauna.borrow().energies[0],
+      BABY_ENERGY + CORPSE_ENERGY - MOVE_COST
+    );
+  }
+
+  #[test]
+  fn test_reset() {
+    let region = Region {
+      height: 2,
+      width: 3,
+      x: 1,
+      y: 5,
+    };
+    let (fauna_updater, _, fauna, _, _) = make_fauna_updater(
+      0,
+      Reset {
+        region: Some(region),
+        settings: ResetSettings {
+          placement: Placement::Region,
+          ..ResetSettings::default()
+        },
+        ..Reset::default()
+      },
+      Schedule::Sequential,
+    );
+    fauna_updater.reset(GenomeSource::Single, 10);
+    let genes_x: [bool; GENES_MAX] = fauna.borrow().genes_x[0];
+    {
+      let fauna = fauna.borrow();
+      assert_eq!(fauna.len(), 10);
+      assert!(fauna.genes_x.iter().all(|genes| *genes == genes_x));
+      assert!(fauna.positions.iter().all(|position| {
+        let x: usize = to_x_from_index(*position);
+        let y: usize = to_y_from_index(*position);
+        (1..=3).contains(&x) && (5..=6).contains(&y)
+      }));
+    }
+    // The survivors of a run seed the next even when outnumbered
+    fauna.borrow_mut().swap_remove(9);
+    fauna_updater.reset(GenomeSource::Survivors, 20);
+    let fauna = fauna.borrow();
+    assert_eq!(fauna.len(), 20);
+    assert!(fauna.genes_x.iter().all(|genes| *genes == genes_x));
+  }
+
+  #[test]
+  fn test_schedule_energy_contest() {
+    for seed in 0..4 {
+      // Contested food goes to the bug with the most energy
+      assert_eq!(
+        to_graze_winner(Schedule::SimultaneousEnergy, [5, 8], seed),
+        1
+      );
+      assert_eq!(
+        to_graze_winner(Schedule::SimultaneousEnergy, [8, 5], seed),
+        0
+      );
+      // The one remaining place goes to the parent with the most energy
+      assert_eq!(
+        to_spawned_at_cap(Schedule::SimultaneousEnergy, [30, 40], seed),
+        [false, true]
+      );
+      assert_eq!(
+        to_spawned_at_cap(Schedule::SimultaneousEnergy, [40, 30], seed),
+        [true, false]
+      );
+    }
+  }
+
+  #[test]
+  fn test_schedule_index_fairness() {
+    for schedule in [
+      Schedule::Permutation,
+      Schedule::SimultaneousRandom,
+    ] {
+      let mut graze_winners: [bool; 2] = [false; 2];
+      let mut spawners: [bool; 2] = [false; 2];
+      for seed in 0..8 {
+        graze_winners[to_graze_winner(
+          schedule,
+          [
+            5, 5,
+          ],
+          seed,
+        )] = true;
+        let spawned: [bool; 2] = to_spawned_at_cap(
+          schedule,
+          [
+            30, 30,
+          ],
+          seed,
+        );
+        spawners[0] |= spawned[0];
+        spawners[1] |= spawned[1];
+      }
+      // The lower index does not always win the food or the last place
+      assert_eq!(graze_winners, [true, true]);
+      assert_eq!(spawners, [true, true]);
+    }
+    // Whereas the sequential schedule always favors the lower index
+    for seed in 0..4 {
+      assert_eq!(to_graze_winner(Schedule::Sequential, [5, 5], seed), 0);
+      assert_eq!(
+        to_spawned_at_cap(Schedule::Sequential, [30, 30], seed),
+        [true, false]
+      );
+    }
+  }
+
+  #[test]
+  fn test_select_winner() {
+    let mut random = Random::default();
+    random.reseed(SEED);
+    let candidates: [usize; 3] = [
+      0, 1, 2,
+    ];
+    let energies: [usize; 3] = [
+      9, 5, 9,
+    ];
+    let mut winners: [bool; 3] = [false; 3];
+    for _ in 0..32 {
+      let winner: usize = FaunaUpdater::select_winner(
+        &candidates,
+        &energies,
+        true,
+        &mut random.rng,
+      )
+      .unwrap();
+      winners[winner] = true;
+    }
+    // Ties in energy are broken at random
+    assert_eq!(winners, [true, false, true]);
+    winners = [false; 3];
+    for _ in 0..32 {
+      let winner: usize = FaunaUpdater::select_winner(
+        &candidates,
+        &energies,
+        false,
+        &mut random.rng,
+      )
+      .unwrap();
+      winners[winner] = true;
+    }
+    assert_eq!(winners, [true; 3]);
+    assert!(
+      FaunaUpdater::select_winner(&[], &energies, true, &mut random.rng)
+        .is_none()
+    );
+  }
+
+  #[test]
+  fn test_traits_conserve_energy() {
+    let classifier = Classifier::default();
+    let (_, _, fauna, flora, random) =
+      make_fauna_updater(1, Reset::default(), Schedule::Sequential);
+    let mut fauna: RefMut<Fauna> = fauna.borrow_mut();
+    let mut flora: RefMut<Flora> = flora.borrow_mut();
+    let rng: &mut StdRng = &mut random.borrow_mut().rng;
+    fauna.traits_heritable = true;
+    fauna.baby_energies[0] = 15;
+    fauna.birth_energies[0] = 40;
+    fauna.energies[0] = 50;
+    let mut new_bugs = Vec::<Bug>::new();
+    for _ in 0..2 {
+      FaunaUpdater::update_bug_spawn(
+        &mut fauna,
+        0,
+        1,
+        &classifier,
+        &mut new_bugs,
+        rng,
+        0,
+      );
+    }
+    // The second attempt fails since the parent is below its birth energy
+    assert_eq!(new_bugs.len(), 1);
+    let baby_bug: &Bug = &new_bugs[0];
+    assert_eq!(
+      fauna.energies[0] + baby_bug.energy + BIRTH_ENERGY_OVERHEAD,
+      50
+    );
+    assert!((14..=16).contains(&baby_bug.baby_energy));
+    assert!((39..=41).contains(&baby_bug.birth_energy));
+    assert!((MOVE_COST - 1..=MOVE_COST + 1).contains(&baby_bug.move_cost));
+    // The fastest metabolism gains no more than the energy of the food
+    let position: usize = to_index_from_xy(0, 0);
+    flora.flora_present[position] = true;
+    fauna.push(Bug {
+      energy: BABY_ENERGY,
+      move_cost: MOVE_COST_MAX,
+      position,
+      ..make_bugs(rng).remove(0)
+    });
+    FaunaUpdater::update_bug_graze(&mut fauna, 1, &mut flora);
+    assert!(!flora.flora_present[position]);
+    assert_eq!(fauna.energies[1], BABY_ENERGY + FLORA_ENERGY);
+  }
+}
@@ -2,35 +2,41 @@
 //! - Flora Updater for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-01-25
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
 use crate::constants::{
-  EDEN_X0, EDEN_X1, EDEN_Y0, EDEN_Y1, FLORA_GROWTH_RATE_MAX,
+  BIOMASS_CAPACITY, BIOMASS_SEED, BLIGHT_CONTAGION_TICKS, BLIGHT_SPREAD_RATE,
+  FERTILITY_REGROWTH_ODDS, FLORA_GROWTH_RATE_MAX, LOCATION_COUNT,
 };
-use crate::engine::functions::location::to_index_from_xy;
-use crate::models::flora::Flora;
+use crate::models::blight::{Blight, Contagion};
+use crate::models::environment::Environment;
+use crate::models::flora::{Decomposition, Flora, FloraMode};
+use crate::models::random::Random;
 use com_croftsoft_lib_role::Updater;
 use core::cell::{RefCell, RefMut};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
 use std::rc::Rc;
-// TODO: Should I be using the js_sys random?
-use rand::{rngs::ThreadRng, Rng};
 
 pub trait FloraUpdaterEvents {
   fn set_updated(&mut self);
 }
 
 pub trait FloraUpdaterInputs {
-  fn get_blight_requested(&self) -> bool;
+  fn get_blight_requested(&self) -> Option<Blight>;
+  fn get_decomposition_change_requested(&self) -> Option<Decomposition>;
   fn get_flora_growth_rate_change_requested(&self) -> Option<usize>;
-  fn get_garden_change_requested(&self) -> Option<bool>;
+  fn get_flora_mode_change_requested(&self) -> Option<FloraMode>;
   fn get_reset_requested(&self) -> bool;
+  fn get_scavenging_change_requested(&self) -> Option<bool>;
   fn get_time_to_update(&self) -> bool;
 }
 
@@ -39,24 +45,30 @@ pub trait FloraUpdaterOptions {
 }
 
 pub struct FloraUpdater {
+  environment: Rc<RefCell<Environment>>,
   events: Rc<RefCell<dyn FloraUpdaterEvents>>,
   flora: Rc<RefCell<Flora>>,
   inputs: Rc<RefCell<dyn FloraUpdaterInputs>>,
   options: Rc<RefCell<dyn FloraUpdaterOptions>>,
+  random: Rc<RefCell<Random>>,
 }
 
 impl FloraUpdater {
   pub fn new(
+    environment: Rc<RefCell<Environment>>,
     events: Rc<RefCell<dyn FloraUpdaterEvents>>,
     flora: Rc<RefCell<Flora>>,
     inputs: Rc<RefCell<dyn FloraUpdaterInputs>>,
     options: Rc<RefCell<dyn FloraUpdaterOptions>>,
+    random: Rc<RefCell<Random>>,
   ) -> Self {
     Self {
+      environment,
       events,
       flora,
       inputs,
       options,
+      random,
     }
   }
 
@@ -64,52 +76,140 @@ impl FloraUpdater {
     &self,
     present: bool,
   ) {
-    self
-      .flora
-      .borrow_mut()
-      .flora_present
-      .iter_mut()
-      .for_each(|location: &mut bool| *location = present);
+    let mut flora: RefMut<Flora> = self.flora.borrow_mut();
+    for index in 0..flora.flora_present.len() {
+      if present {
+        flora.plant(index, BIOMASS_CAPACITY);
+      } else {
+        flora.uproot(index);
+      }
+    }
   }
 
-  fn set_garden_values(
-    &self,
-    value: bool,
+  fn update_blight(
+    flora: &mut Flora,
+    blight: Blight,
+    rng: &mut StdRng,
+  ) {
+    match blight {
+      Blight::All => {
+        for index in 0..LOCATION_COUNT {
+          flora.uproot(index);
+        }
+        flora.contagion = None;
+      },
+      Blight::Contagion => {
+        let locations: Vec<usize> = (0..LOCATION_COUNT)
+          .filter(|index| flora.flora_present[*index])
+          .collect();
+        if let Some(location) = locations.choose(rng) {
+          flora.contagion = Some(Contagion {
+            front: vec![*location],
+            ticks: BLIGHT_CONTAGION_TICKS,
+          });
+        }
+      },
+      Blight::Fraction(percent) => {
+        for index in 0..LOCATION_COUNT {
+          if rng.gen_range(0..100) < percent {
+            flora.uproot(index);
+          }
+        }
+      },
+      Blight::Region(region) => {
+        for index in region.to_indices() {
+          flora.uproot(index);
+        }
+      },
+    }
+  }
+
+  // Burns the front and spreads to the neighboring food until it burns out
+  fn update_contagion(
+    flora: &mut Flora,
+    rng: &mut StdRng,
   ) {
+    let contagion: Contagion = match flora.contagion.take() {
+      Some(contagion) => contagion,
+      None => return,
+    };
+    for location in contagion.front.iter() {
+      flora.uproot(*location);
+    }
+    if contagion.ticks == 0 {
+      return;
+    }
+    let front: Vec<usize> = contagion
+      .to_neighbors()
+      .into_iter()
+      .filter(|location| {
+        flora.flora_present[*location]
+          && rng.gen_range(0..100) < BLIGHT_SPREAD_RATE
+      })
+      .collect();
+    if !front.is_empty() {
+      flora.contagion = Some(Contagion {
+        front,
+        ticks: contagion.ticks - 1,
+      });
+    }
+  }
+
+  // Corpses rot into fertile soil and fertile soil regrows food sooner
+  fn update_decomposition(&self) {
     let mut flora: RefMut<Flora> = self.flora.borrow_mut();
-    for x in EDEN_X0..=EDEN_X1 {
-      for y in EDEN_Y0..=EDEN_Y1 {
-        let index: usize = to_index_from_xy(x, y);
-        flora.flora_present[index] = value;
+    if flora.decomposition == Decomposition::Off {
+      return;
+    }
+    let mut random: RefMut<Random> = self.random.borrow_mut();
+    for index in 0..flora.flora_present.len() {
+      if flora.corpse_ticks[index] > 0 {
+        flora.corpse_ticks[index] -= 1;
+        if flora.corpse_ticks[index] == 0 {
+          flora.fertilize(index);
+        }
+      }
+      if flora.fertility_ticks[index] > 0 {
+        flora.fertility_ticks[index] -= 1;
+        if !flora.flora_present[index]
+          && random.rng.gen_range(0..FERTILITY_REGROWTH_ODDS) == 0
+        {
+          flora.plant(index, BIOMASS_SEED);
+        }
       }
     }
   }
+}
 
-  // TODO: move this
-  fn update_garden(&self) {
-    let garden_change_requested: Option<bool> =
-      self.inputs.borrow().get_garden_change_requested();
-    if let Some(enabled) = garden_change_requested {
-      self.flora.borrow_mut().enabled_garden = enabled;
-      if !self.flora.borrow().enabled_garden {
-        self.set_garden_values(false);
+impl Updater for FloraUpdater {
+  fn update(&self) {
+    if let Some(decomposition) =
+      self.inputs.borrow().get_decomposition_change_requested()
+    {
+      let mut flora: RefMut<Flora> = self.flora.borrow_mut();
+      flora.decomposition = decomposition;
+      if decomposition == Decomposition::Off {
+        flora.clear_remains();
         self.events.borrow_mut().set_updated();
       }
     }
-    let time_to_update: bool = self.inputs.borrow().get_time_to_update();
-    if time_to_update
-      && !self.options.borrow().get_pause()
-      && self.flora.borrow().enabled_garden
+    if let Some(scavenging) =
+      self.inputs.borrow().get_scavenging_change_requested()
+    {
+      self.flora.borrow_mut().scavenging = scavenging;
+    }
+    if let Some(flora_mode) =
+      self.inputs.borrow().get_flora_mode_change_requested()
     {
-      self.set_garden_values(true);
+      self.flora.borrow_mut().set_flora_mode(flora_mode);
       self.events.borrow_mut().set_updated();
     }
-  }
-}
-
-impl Updater for FloraUpdater {
-  fn update(&self) {
     if self.inputs.borrow().get_reset_requested() {
+      {
+        let mut flora: RefMut<Flora> = self.flora.borrow_mut();
+        flora.clear_remains();
+        flora.contagion = None;
+      }
       self.set_flora_present_for_all_locations(true);
       self.events.borrow_mut().set_updated();
       return;
@@ -126,22 +226,99 @@ impl Updater for FloraUpdater {
         flora.flora_growth_rate = FLORA_GROWTH_RATE_MAX;
       }
     }
-    if self.inputs.borrow().get_blight_requested() {
-      self.set_flora_present_for_all_locations(false);
+    if let Some(blight) = self.inputs.borrow().get_blight_requested() {
+      Self::update_blight(
+        &mut self.flora.borrow_mut(),
+        blight,
+        &mut self.random.borrow_mut().rng,
+      );
       self.events.borrow_mut().set_updated();
     } else {
       let time_to_update: bool = self.inputs.borrow().get_time_to_update();
       if time_to_update && !self.options.borrow().get_pause() {
-        let mut thread_rng: ThreadRng = rand::thread_rng();
+        self.update_decomposition();
+        let mut random: RefMut<Random> = self.random.borrow_mut();
         let mut flora: RefMut<Flora> = self.flora.borrow_mut();
-        for _i in 0..flora.flora_growth_rate {
+        Self::update_contagion(&mut flora, &mut random.rng);
+        if flora.flora_mode == FloraMode::Biomass {
+          flora.regrow_biomass();
+        }
+        // Rounded at random so that the mean matches the modulated rate
+        let growth_rate: f64 = flora.flora_growth_rate as f64
+          * self.environment.borrow().growth_factor;
+        let growth_count: usize = growth_rate as usize
+          + usize::from(random.rng.gen::<f64>() < growth_rate.fract());
+        for _i in 0..growth_count {
           // Randomly position food flora
-          let index: usize = thread_rng.gen_range(0..flora.flora_present.len());
-          flora.flora_present[index] = true;
+          let index: usize = random.rng.gen_range(0..flora.flora_present.len());
+          flora.plant(index, BIOMASS_SEED);
         }
         self.events.borrow_mut().set_updated();
       }
     }
-    self.update_garden();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::FloraUpdater;
+  use crate::constants::{LOCATION_COUNT, SPACE_WIDTH};
+  use crate::engine::functions::location::to_index_from_xy;
+  use crate::models::blight::Blight;
+  use crate::models::flora::Flora;
+  use crate::models::random::Random;
+  use crate::models::region::Region;
+
+  const SEED: u64 = 0;
+
+  fn make_flora() -> Flora {
+    let mut flora = Flora::default();
+    for index in 0..LOCATION_COUNT {
+      flora.plant(index, 1);
+    }
+    flora
+  }
+
+  #[test]
+  fn test_blight_fraction() {
+    let mut random = Random::default();
+    random.reseed(SEED);
+    let mut flora: Flora = make_flora();
+    FloraUpdater::update_blight(
+      &mut flora,
+      Blight::Fraction(0),
+      &mut random.rng,
+    );
+    assert!(flora.flora_present.iter().all(|present| *present));
+    FloraUpdater::update_blight(
+      &mut flora,
+      Blight::Fraction(100),
+      &mut random.rng,
+    );
+    assert!(flora.flora_present.iter().all(|present| !*present));
+  }
+
+  #[test]
+  fn test_blight_region_across_the_edge() {
+    let mut random = Random::default();
+    random.reseed(SEED);
+    let mut flora: Flora = make_flora();
+    let region = Region::from_drag(to_index_from_xy(SPACE_WIDTH - 1, 0), 2, 0);
+    FloraUpdater::update_blight(
+      &mut flora,
+      Blight::Region(region),
+      &mut random.rng,
+    );
+    let blighted: Vec<usize> = (0..LOCATION_COUNT)
+      .filter(|index| !flora.flora_present[*index])
+      .collect();
+    assert_eq!(
+      blighted,
+      vec![
+        to_index_from_xy(0, 0),
+        to_index_from_xy(1, 0),
+        to_index_from_xy(SPACE_WIDTH - 1, 0),
+      ]
+    );
   }
 }
@@ -0,0 +1,269 @@
+// =============================================================================
+//! - Garden updater for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{BIOMASS_CAPACITY, SPACE_HEIGHT, SPACE_WIDTH};
+use crate::models::clock::Clock;
+use crate::models::flora::Flora;
+use crate::models::garden::{
+  Garden, GardenDrag, GardenEdit, GardenMotion, GardenSettings,
+};
+use crate::models::random::Random;
+use crate::models::region::Region;
+use com_croftsoft_lib_role::Updater;
+use core::cell::{RefCell, RefMut};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::rc::Rc;
+
+// The headings of a drifting garden as steps added with wrapping
+const GARDEN_DRIFTS: [(usize, usize); 8] = [
+  (1, 0),
+  (1, 1),
+  (0, 1),
+  (SPACE_WIDTH - 1, 1),
+  (SPACE_WIDTH - 1, 0),
+  (SPACE_WIDTH - 1, SPACE_HEIGHT - 1),
+  (0, SPACE_HEIGHT - 1),
+  (1, SPACE_HEIGHT - 1),
+];
+
+pub trait GardenUpdaterEvents {
+  fn set_updated(&mut self);
+}
+
+pub trait GardenUpdaterInputs {
+  fn get_garden_change_requested(&self) -> Option<bool>;
+  fn get_garden_drag_requested(&self) -> Option<GardenDrag>;
+  fn get_garden_settings_change_requested(&self) -> Option<GardenSettings>;
+  fn get_gardens_clear_requested(&self) -> bool;
+  fn get_reset_requested(&self) -> bool;
+  fn get_time_to_update(&self) -> bool;
+}
+
+pub trait GardenUpdaterOptions {
+  fn get_pause(&self) -> bool;
+}
+
+// Draws, moves, and replenishes the gardens in the flora
+pub struct GardenUpdater {
+  clock: Rc<RefCell<Clock>>,
+  events: Rc<RefCell<dyn GardenUpdaterEvents>>,
+  flora: Rc<RefCell<Flora>>,
+  inputs: Rc<RefCell<dyn GardenUpdaterInputs>>,
+  options: Rc<RefCell<dyn GardenUpdaterOptions>>,
+  random: Rc<RefCell<Random>>,
+}
+
+impl GardenUpdater {
+  pub fn new(
+    clock: Rc<RefCell<Clock>>,
+    events: Rc<RefCell<dyn GardenUpdaterEvents>>,
+    flora: Rc<RefCell<Flora>>,
+    inputs: Rc<RefCell<dyn GardenUpdaterInputs>>,
+    options: Rc<RefCell<dyn GardenUpdaterOptions>>,
+    random: Rc<RefCell<Random>>,
+  ) -> Self {
+    Self {
+      clock,
+      events,
+      flora,
+      inputs,
+      options,
+      random,
+    }
+  }
+
+  fn set_garden_values(
+    &self,
+    value: bool,
+  ) {
+    let mut flora: RefMut<Flora> = self.flora.borrow_mut();
+    let indices: Vec<usize> = flora
+      .gardens
+      .iter()
+      .flat_map(|garden| garden.to_indices())
+      .collect();
+    for index in indices {
+      if value {
+        flora.plant(index, BIOMASS_CAPACITY);
+      } else {
+        flora.uproot(index);
+      }
+    }
+  }
+
+  // A drag starting in a garden moves or resizes it and any other drag draws
+  // a new one
+  fn update_garden_drag(
+    flora: &mut Flora,
+    garden_drag: GardenDrag,
+    rng: &mut StdRng,
+  ) {
+    if garden_drag.started {
+      let index_option: Option<usize> = flora
+        .gardens
+        .iter()
+        .position(|garden| garden.contains(garden_drag.location_from));
+      let garden_edit: GardenEdit = match index_option {
+        Some(index) if garden_drag.resize => {
+          flora.gardens[index].to_resize_edit(index, garden_drag.location_from)
+        },
+        Some(index) => GardenEdit::Move {
+          index,
+          x: flora.gardens[index].x,
+          y: flora.gardens[index].y,
+        },
+        None => {
+          let (drift_x, drift_y) = *GARDEN_DRIFTS.choose(rng).unwrap();
+          flora.gardens.push(Garden {
+            drift_x,
+            drift_y,
+            replenish_rate: flora.garden_settings.replenish_rate,
+            ..Garden::default()
+          });
+          GardenEdit::Resize {
+            anchor: garden_drag.location_from,
+            index: flora.gardens.len() - 1,
+            offset_x: 0,
+            offset_y: 0,
+          }
+        },
+      };
+      flora.garden_selected = match garden_edit {
+        GardenEdit::Move {
+          index,
+          ..
+        }
+        | GardenEdit::Resize {
+          index,
+          ..
+        } => Some(index),
+      };
+      flora.garden_edit = Some(garden_edit);
+    }
+    match flora.garden_edit {
+      Some(GardenEdit::Move {
+        index,
+        x,
+        y,
+      }) => {
+        if let Some(garden) = flora.gardens.get_mut(index) {
+          garden.x = x;
+          garden.y = y;
+          garden.translate(
+            garden_drag.delta_x.rem_euclid(SPACE_WIDTH as isize) as usize,
+            garden_drag.delta_y.rem_euclid(SPACE_HEIGHT as isize) as usize,
+          );
+        }
+      },
+      Some(GardenEdit::Resize {
+        anchor,
+        index,
+        offset_x,
+        offset_y,
+      }) => {
+        if let Some(garden) = flora.gardens.get_mut(index) {
+          garden.set_region(Region::from_drag(
+            anchor,
+            offset_x + garden_drag.delta_x,
+            offset_y + garden_drag.delta_y,
+          ));
+        }
+      },
+      None => {},
+    }
+  }
+
+  fn update_garden_motion(
+    flora: &mut Flora,
+    rng: &mut StdRng,
+  ) {
+    let settings: GardenSettings = flora.garden_settings;
+    for garden in flora.gardens.iter_mut() {
+      match settings.motion {
+        GardenMotion::Drift => garden.translate(garden.drift_x, garden.drift_y),
+        GardenMotion::Jump => {
+          garden.x = rng.gen_range(0..SPACE_WIDTH);
+          garden.y = rng.gen_range(0..SPACE_HEIGHT);
+        },
+        GardenMotion::Still => {},
+      }
+    }
+  }
+}
+
+impl Updater for GardenUpdater {
+  fn update(&self) {
+    // Skipped like the rest of the flora update when the flora is replanted
+    if self.inputs.borrow().get_reset_requested() {
+      return;
+    }
+    let garden_change_requested: Option<bool> =
+      self.inputs.borrow().get_garden_change_requested();
+    if let Some(enabled) = garden_change_requested {
+      self.flora.borrow_mut().enabled_garden = enabled;
+      if !self.flora.borrow().enabled_garden {
+        self.set_garden_values(false);
+        self.events.borrow_mut().set_updated();
+      }
+    }
+    if let Some(garden_settings) =
+      self.inputs.borrow().get_garden_settings_change_requested()
+    {
+      let mut flora: RefMut<Flora> = self.flora.borrow_mut();
+      flora.garden_settings = garden_settings;
+      if let Some(index) = flora.garden_selected {
+        flora.gardens[index].replenish_rate = garden_settings.replenish_rate;
+      }
+    }
+    if self.inputs.borrow().get_gardens_clear_requested() {
+      let mut flora: RefMut<Flora> = self.flora.borrow_mut();
+      flora.garden_edit = None;
+      flora.garden_selected = None;
+      flora.gardens.clear();
+      self.events.borrow_mut().set_updated();
+    }
+    if let Some(garden_drag) = self.inputs.borrow().get_garden_drag_requested()
+    {
+      Self::update_garden_drag(
+        &mut self.flora.borrow_mut(),
+        garden_drag,
+        &mut self.random.borrow_mut().rng,
+      );
+      self.events.borrow_mut().set_updated();
+    }
+    let time_to_update: bool = self.inputs.borrow().get_time_to_update();
+    if !time_to_update
+      || self.options.borrow().get_pause()
+      || !self.flora.borrow().enabled_garden
+    {
+      return;
+    }
+    let mut flora: RefMut<Flora> = self.flora.borrow_mut();
+    let mut random: RefMut<Random> = self.random.borrow_mut();
+    let period: usize = flora.garden_settings.period;
+    if period > 0 && self.clock.borrow().tick % period == 0 {
+      Self::update_garden_motion(&mut flora, &mut random.rng);
+    }
+    for garden_index in 0..flora.gardens.len() {
+      let garden: Garden = flora.gardens[garden_index];
+      for index in garden.to_indices() {
+        if random.rng.gen_range(0..100) < garden.replenish_rate {
+          flora.plant(index, BIOMASS_CAPACITY);
+        }
+      }
+    }
+    self.events.borrow_mut().set_updated();
+  }
+}
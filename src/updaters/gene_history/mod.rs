@@ -0,0 +1,109 @@
+// =============================================================================
+//! - Gene History Updater for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{
+  GENE_HEAT_MAP_BUCKET_SIZE, GENE_HEAT_MAP_COLUMNS_MAX, LOCUS_COUNT,
+};
+use crate::engine::functions::genes::to_gene_frequencies;
+use crate::models::fauna::Fauna;
+use crate::models::gene_history::GeneHistory;
+use com_croftsoft_lib_role::Updater;
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+pub trait GeneHistoryUpdaterEvents {
+  fn set_updated(&mut self);
+}
+
+pub trait GeneHistoryUpdaterInputs {
+  fn get_gene_heat_map_display_change_requested(&self) -> Option<bool>;
+  fn get_reset_requested(&self) -> bool;
+  fn get_time_to_update(&self) -> bool;
+}
+
+pub trait GeneHistoryUpdaterOptions {
+  fn get_pause(&self) -> bool;
+}
+
+pub struct GeneHistoryUpdater {
+  events: Rc<RefCell<dyn GeneHistoryUpdaterEvents>>,
+  fauna: Rc<RefCell<Fauna>>,
+  gene_history: Rc<RefCell<GeneHistory>>,
+  inputs: Rc<RefCell<dyn GeneHistoryUpdaterInputs>>,
+  options: Rc<RefCell<dyn GeneHistoryUpdaterOptions>>,
+}
+
+impl GeneHistoryUpdater {
+  pub fn new(
+    events: Rc<RefCell<dyn GeneHistoryUpdaterEvents>>,
+    fauna: Rc<RefCell<Fauna>>,
+    gene_history: Rc<RefCell<GeneHistory>>,
+    inputs: Rc<RefCell<dyn GeneHistoryUpdaterInputs>>,
+    options: Rc<RefCell<dyn GeneHistoryUpdaterOptions>>,
+  ) -> Self {
+    Self {
+      events,
+      fauna,
+      gene_history,
+      inputs,
+      options,
+    }
+  }
+
+  fn sample(&self) {
+    let gene_frequencies: [f64; LOCUS_COUNT] =
+      to_gene_frequencies(&self.fauna.borrow());
+    let mut gene_history: RefMut<GeneHistory> = self.gene_history.borrow_mut();
+    for (sum, frequency) in gene_history
+      .bucket_sums
+      .iter_mut()
+      .zip(gene_frequencies.iter())
+    {
+      *sum += frequency;
+    }
+    gene_history.bucket_sample_count += 1;
+    if gene_history.bucket_sample_count < GENE_HEAT_MAP_BUCKET_SIZE {
+      return;
+    }
+    if let Some(column) = gene_history.get_bucket_average() {
+      gene_history.columns.push_back(column);
+    }
+    if gene_history.columns.len() > GENE_HEAT_MAP_COLUMNS_MAX {
+      gene_history.columns.pop_front();
+    }
+    gene_history.bucket_sample_count = 0;
+    gene_history.bucket_sums = [0.; LOCUS_COUNT];
+  }
+}
+
+impl Updater for GeneHistoryUpdater {
+  fn update(&self) {
+    let inputs: Ref<dyn GeneHistoryUpdaterInputs> = self.inputs.borrow();
+    if inputs.get_reset_requested() {
+      self.gene_history.borrow_mut().clear();
+      self.events.borrow_mut().set_updated();
+      return;
+    }
+    if inputs
+      .get_gene_heat_map_display_change_requested()
+      .is_some()
+    {
+      self.events.borrow_mut().set_updated();
+    }
+    if !inputs.get_time_to_update() || self.options.borrow().get_pause() {
+      return;
+    }
+    self.sample();
+    self.events.borrow_mut().set_updated();
+  }
+}
@@ -0,0 +1,94 @@
+// =============================================================================
+//! - Heat Maps Updater for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::HEAT_MAP_WINDOW_MAX;
+use crate::models::fauna::Fauna;
+use crate::models::heat_maps::{HeatMap, HeatMaps};
+use com_croftsoft_lib_role::Updater;
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+pub trait HeatMapsUpdaterInputs {
+  fn get_density_heat_map_window_change_requested(&self) -> Option<usize>;
+  fn get_grazing_heat_map_window_change_requested(&self) -> Option<usize>;
+  fn get_reset_requested(&self) -> bool;
+  fn get_time_to_update(&self) -> bool;
+}
+
+pub trait HeatMapsUpdaterOptions {
+  fn get_pause(&self) -> bool;
+}
+
+pub struct HeatMapsUpdater {
+  fauna: Rc<RefCell<Fauna>>,
+  heat_maps: Rc<RefCell<HeatMaps>>,
+  inputs: Rc<RefCell<dyn HeatMapsUpdaterInputs>>,
+  options: Rc<RefCell<dyn HeatMapsUpdaterOptions>>,
+}
+
+impl HeatMapsUpdater {
+  // Decays the old values and then adds one for each event at a location
+  fn accumulate(
+    heat_map: &mut HeatMap,
+    positions: impl Iterator<Item = usize>,
+  ) {
+    let decay: f64 = (-1. / heat_map.window as f64).exp();
+    heat_map.values.iter_mut().for_each(|value| *value *= decay);
+    for position in positions {
+      heat_map.values[position] += 1.;
+    }
+  }
+
+  pub fn new(
+    fauna: Rc<RefCell<Fauna>>,
+    heat_maps: Rc<RefCell<HeatMaps>>,
+    inputs: Rc<RefCell<dyn HeatMapsUpdaterInputs>>,
+    options: Rc<RefCell<dyn HeatMapsUpdaterOptions>>,
+  ) -> Self {
+    Self {
+      fauna,
+      heat_maps,
+      inputs,
+      options,
+    }
+  }
+}
+
+impl Updater for HeatMapsUpdater {
+  fn update(&self) {
+    let inputs: Ref<dyn HeatMapsUpdaterInputs> = self.inputs.borrow();
+    let mut heat_maps: RefMut<HeatMaps> = self.heat_maps.borrow_mut();
+    if inputs.get_reset_requested() {
+      heat_maps.density.clear();
+      heat_maps.grazing.clear();
+      return;
+    }
+    if let Some(window) = inputs.get_density_heat_map_window_change_requested()
+    {
+      heat_maps.density.window = window.clamp(1, HEAT_MAP_WINDOW_MAX);
+    }
+    if let Some(window) = inputs.get_grazing_heat_map_window_change_requested()
+    {
+      heat_maps.grazing.window = window.clamp(1, HEAT_MAP_WINDOW_MAX);
+    }
+    if !inputs.get_time_to_update() || self.options.borrow().get_pause() {
+      return;
+    }
+    let fauna: Ref<Fauna> = self.fauna.borrow();
+    Self::accumulate(&mut heat_maps.density, fauna.positions.iter().cloned());
+    Self::accumulate(
+      &mut heat_maps.grazing,
+      fauna.grazed_positions.iter().cloned(),
+    );
+  }
+}
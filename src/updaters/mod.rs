@@ -1,6 +1,15 @@
+pub mod classifier;
 pub mod clock;
+pub mod environment;
 pub mod fauna;
 pub mod flora;
+pub mod garden;
+pub mod gene_history;
+pub mod heat_maps;
 pub mod options;
 pub mod overlay;
+pub mod random;
 pub mod root;
+pub mod selection;
+pub mod statistics;
+pub mod viewport;
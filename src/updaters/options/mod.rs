@@ -2,23 +2,42 @@
 //! - Options Updater for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-02-27
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
-use crate::models::options::Options;
+use crate::models::genome::Genome;
+use crate::models::options::{ColorMode, DragMode, Options, Palette, Schedule};
 use com_croftsoft_lib_role::Updater;
 use core::cell::{Ref, RefCell};
 use std::rc::Rc;
 
 pub trait OptionsUpdaterInputs {
+  fn get_color_mode_change_requested(&self) -> Option<ColorMode>;
+  fn get_density_heat_map_display_change_requested(&self) -> Option<bool>;
+  fn get_diversity_display_change_requested(&self) -> Option<bool>;
+  fn get_drag_mode_change_requested(&self) -> Option<DragMode>;
+  fn get_gene_heat_map_display_change_requested(&self) -> Option<bool>;
+  fn get_genome_change_requested(&self) -> Option<Option<Genome>>;
+  fn get_grazing_heat_map_display_change_requested(&self) -> Option<bool>;
+  fn get_heading_display_change_requested(&self) -> Option<bool>;
+  fn get_interpolation_change_requested(&self) -> Option<bool>;
+  fn get_layer_visibility_change_requested(
+    &self
+  ) -> Option<(&'static str, bool)>;
+  fn get_life_history_display_change_requested(&self) -> Option<bool>;
+  fn get_palette_change_requested(&self) -> Option<Palette>;
   fn get_pause_change_requested(&self) -> Option<bool>;
+  fn get_profile_display_change_requested(&self) -> Option<bool>;
+  fn get_schedule_change_requested(&self) -> Option<Schedule>;
   fn get_time_display_change_requested(&self) -> Option<bool>;
+  fn get_trail_display_change_requested(&self) -> Option<bool>;
+  fn get_traits_display_change_requested(&self) -> Option<bool>;
   fn get_update_rate_display_change_requested(&self) -> Option<bool>;
 }
 
@@ -47,11 +66,79 @@ impl Updater for OptionsUpdater {
     {
       self.options.borrow_mut().update_rate_display = frame_rate_display;
     }
+    if let Some(color_mode) = inputs.get_color_mode_change_requested() {
+      self.options.borrow_mut().color_mode = color_mode;
+    }
+    if let Some(density_heat_map_display) =
+      inputs.get_density_heat_map_display_change_requested()
+    {
+      self.options.borrow_mut().density_heat_map_display =
+        density_heat_map_display;
+    }
+    if let Some(diversity_display) =
+      inputs.get_diversity_display_change_requested()
+    {
+      self.options.borrow_mut().diversity_display = diversity_display;
+    }
+    if let Some(drag_mode) = inputs.get_drag_mode_change_requested() {
+      self.options.borrow_mut().drag_mode = drag_mode;
+    }
+    if let Some(gene_heat_map_display) =
+      inputs.get_gene_heat_map_display_change_requested()
+    {
+      self.options.borrow_mut().gene_heat_map_display = gene_heat_map_display;
+    }
+    if let Some(genome) = inputs.get_genome_change_requested() {
+      self.options.borrow_mut().genome = genome;
+    }
+    if let Some(grazing_heat_map_display) =
+      inputs.get_grazing_heat_map_display_change_requested()
+    {
+      self.options.borrow_mut().grazing_heat_map_display =
+        grazing_heat_map_display;
+    }
+    if let Some(heading_display) = inputs.get_heading_display_change_requested()
+    {
+      self.options.borrow_mut().heading_display = heading_display;
+    }
+    if let Some(interpolation) = inputs.get_interpolation_change_requested() {
+      self.options.borrow_mut().interpolation = interpolation;
+    }
+    if let Some(life_history_display) =
+      inputs.get_life_history_display_change_requested()
+    {
+      self.options.borrow_mut().life_history_display = life_history_display;
+    }
+    if let Some((layer, visible)) =
+      inputs.get_layer_visibility_change_requested()
+    {
+      self
+        .options
+        .borrow_mut()
+        .layer_visibilities
+        .insert(layer, visible);
+    }
+    if let Some(palette) = inputs.get_palette_change_requested() {
+      self.options.borrow_mut().palette = palette;
+    }
     if let Some(pause) = inputs.get_pause_change_requested() {
       self.options.borrow_mut().pause = pause;
     }
+    if let Some(profile_display) = inputs.get_profile_display_change_requested()
+    {
+      self.options.borrow_mut().profile_display = profile_display;
+    }
+    if let Some(schedule) = inputs.get_schedule_change_requested() {
+      self.options.borrow_mut().schedule = schedule;
+    }
     if let Some(time_display) = inputs.get_time_display_change_requested() {
       self.options.borrow_mut().time_display = time_display;
     }
+    if let Some(trail_display) = inputs.get_trail_display_change_requested() {
+      self.options.borrow_mut().trail_display = trail_display;
+    }
+    if let Some(traits_display) = inputs.get_traits_display_change_requested() {
+      self.options.borrow_mut().traits_display = traits_display;
+    }
   }
 }
@@ -2,19 +2,28 @@
 //! - Overlay Updater for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-02-09
-//! - Updated: 2023-09-02
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
-use crate::constants::{GENES_MAX, OVERLAY_REFRESH_PERIOD_MILLIS};
+use crate::constants::{
+  GENES_MAX, OVERLAY_LINE_LENGTH_MAX, OVERLAY_REFRESH_PERIOD_MILLIS,
+};
+use crate::engine::functions::diversity::{to_diversity, Diversity};
+use crate::engine::functions::genes::to_genome_string;
+use crate::models::classifier::{Classifier, ClassifierSettings};
 use crate::models::clock::Clock;
+use crate::models::environment::{Environment, EnvironmentSettings};
 use crate::models::fauna::Fauna;
+use crate::models::options::{ColorMode, DragMode, Palette};
 use crate::models::overlay::Overlay;
+use crate::models::reset::ResetSettings;
+use crate::models::statistics::Statistics;
 use com_croftsoft_lib_animation::frame_rater::FrameRater;
 use com_croftsoft_lib_animation::metronome::delta::DeltaMetronome;
 use com_croftsoft_lib_animation::metronome::Metronome;
@@ -29,22 +38,46 @@ pub trait OverlayUpdaterEvents {
 
 pub trait OverlayUpdaterInputs {
   fn get_bug_requested(&self) -> Option<usize>;
+  fn get_classifier_change_requested(&self) -> Option<ClassifierSettings>;
+  fn get_color_mode_change_requested(&self) -> Option<ColorMode>;
   fn get_current_time_millis(&self) -> f64;
+  fn get_density_heat_map_display_change_requested(&self) -> Option<bool>;
+  fn get_diversity_display_change_requested(&self) -> Option<bool>;
+  fn get_drag_mode_change_requested(&self) -> Option<DragMode>;
+  fn get_environment_settings_change_requested(
+    &self
+  ) -> Option<EnvironmentSettings>;
+  fn get_grazing_heat_map_display_change_requested(&self) -> Option<bool>;
+  fn get_heading_display_change_requested(&self) -> Option<bool>;
+  fn get_interpolation_change_requested(&self) -> Option<bool>;
+  fn get_layer_visibility_change_requested(
+    &self
+  ) -> Option<(&'static str, bool)>;
+  fn get_life_history_display_change_requested(&self) -> Option<bool>;
+  fn get_palette_change_requested(&self) -> Option<Palette>;
   fn get_pause_change_requested(&self) -> Option<bool>;
+  fn get_profile_display_change_requested(&self) -> Option<bool>;
   fn get_reset_requested(&self) -> bool;
+  fn get_reset_settings_change_requested(&self) -> Option<ResetSettings>;
   fn get_time_display_change_requested(&self) -> Option<bool>;
   fn get_time_to_update(&self) -> bool;
+  fn get_trail_display_change_requested(&self) -> Option<bool>;
+  fn get_traits_display_change_requested(&self) -> Option<bool>;
   fn get_update_rate_display_change_requested(&self) -> Option<bool>;
 }
 
 pub trait OverlayUpdaterOptions {
+  fn get_diversity_display(&self) -> bool;
+  fn get_life_history_display(&self) -> bool;
   fn get_pause(&self) -> bool;
   fn get_time_display(&self) -> bool;
   fn get_update_rate_display(&self) -> bool;
 }
 
 pub struct OverlayUpdater {
+  classifier: Rc<RefCell<Classifier>>,
   clock: Rc<RefCell<Clock>>,
+  environment: Rc<RefCell<Environment>>,
   events: Rc<RefCell<dyn OverlayUpdaterEvents>>,
   fauna: Rc<RefCell<Fauna>>,
   frame_rater: Rc<RefCell<dyn FrameRater>>,
@@ -52,57 +85,153 @@ pub struct OverlayUpdater {
   metronome: RefCell<DeltaMetronome>,
   options: Rc<RefCell<dyn OverlayUpdaterOptions>>,
   overlay: Rc<RefCell<Overlay>>,
+  statistics: Rc<RefCell<Statistics>>,
 }
 
 impl OverlayUpdater {
-  fn make_genes_average_string(&self) -> String {
-    let mut gene_x_string = String::from("X:");
-    let mut gene_y_string = String::from("Y:");
-    let mut bugs_alive: usize = 0;
-    for bug in self.fauna.borrow().bugs.iter() {
-      if bug.energy > 0 {
-        bugs_alive += 1;
-      }
+  fn make_diversity_string(&self) -> String {
+    let diversity: Diversity = to_diversity(&self.fauna.borrow());
+    format!(
+      "Genotypes:{} Shannon:{:.2} Simpson:{:.3} Hamming:{:.2} H:{:.3}",
+      diversity.genotype_count,
+      diversity.shannon_index,
+      diversity.simpson_index,
+      diversity.hamming_distance_mean,
+      diversity.get_heterozygosity_mean(),
+    )
+  }
+
+  // Empty when the environment is constant
+  fn make_environment_string(&self) -> String {
+    let environment: Ref<Environment> = self.environment.borrow();
+    if !environment.settings.is_enabled() {
+      return String::new();
+    }
+    let mut environment_string = String::from("Environment:");
+    if let Some(season) = environment.season {
+      environment_string.push(' ');
+      environment_string.push_str(season.name());
+    }
+    if let Some(daytime) = environment.daytime {
+      environment_string.push_str(if daytime {
+        " Day"
+      } else {
+        " Night"
+      });
+    }
+    if environment.settings.weather_random {
+      environment_string.push(' ');
+      environment_string.push_str(environment.weather.name());
     }
+    format!(
+      "{} Food growth x{:.2}",
+      environment_string, environment.growth_factor
+    )
+  }
+
+  fn make_genes_average_string(&self) -> String {
+    let mut genes_x: [bool; GENES_MAX] = [false; GENES_MAX];
+    let mut genes_y: [bool; GENES_MAX] = [false; GENES_MAX];
+    let fauna: Ref<Fauna> = self.fauna.borrow();
+    let bugs_alive: usize =
+      fauna.energies.iter().filter(|energy| **energy > 0).count();
     for i in 0..GENES_MAX {
       let mut x_sum: usize = 0;
       let mut y_sum: usize = 0;
-      for bug in self.fauna.borrow().bugs.iter() {
-        if bug.energy > 0 {
-          if bug.genes_x[i] {
+      for index in 0..fauna.len() {
+        if fauna.energies[index] > 0 {
+          if fauna.genes_x[index][i] {
             x_sum += 1;
           }
-          if bug.genes_y[i] {
+          if fauna.genes_y[index][i] {
             y_sum += 1;
           }
         }
       }
-      if x_sum as f64 / bugs_alive as f64 >= 0.5 {
-        gene_x_string.push('1');
-      } else {
-        gene_x_string.push('0');
+      genes_x[i] = x_sum as f64 / bugs_alive as f64 >= 0.5;
+      genes_y[i] = y_sum as f64 / bugs_alive as f64 >= 0.5;
+    }
+    to_genome_string(&genes_x, &genes_y)
+  }
+
+  // Wrapped to fit the canvas since some classifiers define many classes
+  fn make_life_history_strings(&self) -> Vec<String> {
+    let statistics: Ref<Statistics> = self.statistics.borrow();
+    let mut life_history_strings: Vec<String> =
+      vec![String::from("Deaths, ages at 10/50/90%, mean 1st birth, mean LRS")];
+    for (species_name, life_history) in statistics
+      .species_names
+      .iter()
+      .zip(&statistics.life_histories)
+    {
+      if life_history.deaths == 0 {
+        continue;
       }
-      if y_sum as f64 / bugs_alive as f64 >= 0.5 {
-        gene_y_string.push('1');
+      let first_spawn_age_string: String = if life_history.parents == 0 {
+        String::from("-")
       } else {
-        gene_y_string.push('0');
+        format!(
+          "{:.0}",
+          life_history.first_spawn_age_sum as f64 / life_history.parents as f64
+        )
+      };
+      life_history_strings.push(format!(
+        "{} {} {}/{}/{} {} {:.2}",
+        species_name,
+        life_history.deaths,
+        life_history.to_lifespan_quantile(0.1),
+        life_history.to_lifespan_quantile(0.5),
+        life_history.to_lifespan_quantile(0.9),
+        first_spawn_age_string,
+        life_history.offspring_sum as f64 / life_history.deaths as f64,
+      ));
+    }
+    life_history_strings
+  }
+
+  fn make_species_strings(&self) -> Vec<String> {
+    let classifier: Ref<Classifier> = self.classifier.borrow();
+    let mut counts: Vec<usize> = vec![0; classifier.classes.len()];
+    let fauna: Ref<Fauna> = self.fauna.borrow();
+    for (energy, species) in fauna.energies.iter().zip(&fauna.species) {
+      if *energy > 0 {
+        counts[*species] += 1;
+      }
+    }
+    let mut species_strings = Vec::<String>::new();
+    let mut line = String::new();
+    for (class, count) in classifier.classes.iter().zip(counts) {
+      let entry: String = format!("{}:{}", class.name, count);
+      if !line.is_empty()
+        && line.len() + 1 + entry.len() > OVERLAY_LINE_LENGTH_MAX
+      {
+        species_strings.push(line);
+        line = String::new();
+      }
+      if !line.is_empty() {
+        line.push(' ');
       }
+      line.push_str(&entry);
     }
-    let mut result = String::from(&gene_x_string);
-    result.push(' ');
-    result.push_str(&gene_y_string);
-    result
+    species_strings.push(line);
+    species_strings
   }
 
   fn make_status_string(&self) -> String {
     let genes_average_string = self.make_genes_average_string();
-    let bugs_alive = self.fauna.borrow().bugs.iter().fold(0, |count, bug| {
-      if bug.energy > 0 {
-        count + 1
-      } else {
-        count
-      }
-    });
+    let bugs_alive =
+      self
+        .fauna
+        .borrow()
+        .energies
+        .iter()
+        .fold(0, |count, energy| {
+          if *energy > 0 {
+            count + 1
+          } else {
+            count
+          }
+        });
     let time = self.clock.borrow().time;
     format!(
       "Average Movement Genes {} Time:{} Alive:{}",
@@ -122,21 +251,27 @@ impl OverlayUpdater {
     )
   }
 
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
+    classifier: Rc<RefCell<Classifier>>,
     clock: Rc<RefCell<Clock>>,
+    environment: Rc<RefCell<Environment>>,
     events: Rc<RefCell<dyn OverlayUpdaterEvents>>,
     fauna: Rc<RefCell<Fauna>>,
     frame_rater: Rc<RefCell<dyn FrameRater>>,
     inputs: Rc<RefCell<dyn OverlayUpdaterInputs>>,
     options: Rc<RefCell<dyn OverlayUpdaterOptions>>,
     overlay: Rc<RefCell<Overlay>>,
+    statistics: Rc<RefCell<Statistics>>,
   ) -> Self {
     let metronome = RefCell::new(DeltaMetronome {
       period_millis: OVERLAY_REFRESH_PERIOD_MILLIS,
       time_millis_next_tick: 0.,
     });
     Self {
+      classifier,
       clock,
+      environment,
       events,
       fauna,
       frame_rater,
@@ -144,16 +279,25 @@ impl OverlayUpdater {
       metronome,
       options,
       overlay,
+      statistics,
     }
   }
 
   fn update_overlay(&self) {
     let options: Ref<dyn OverlayUpdaterOptions> = self.options.borrow();
     let mut overlay: RefMut<Overlay> = self.overlay.borrow_mut();
+    overlay.species_strings = self.make_species_strings();
     overlay.status_string = self.make_status_string();
+    overlay.environment_string = self.make_environment_string();
     if !options.get_pause() && options.get_update_rate_display() {
       overlay.update_rate_string = self.make_update_rate_string();
     }
+    if options.get_diversity_display() {
+      overlay.diversity_string = self.make_diversity_string();
+    }
+    if options.get_life_history_display() {
+      overlay.life_history_strings = self.make_life_history_strings();
+    }
     if options.get_time_display() {
       overlay.time_string = self.make_time_string();
     }
@@ -166,9 +310,29 @@ impl Updater for OverlayUpdater {
   fn update(&self) {
     let inputs: Ref<dyn OverlayUpdaterInputs> = self.inputs.borrow();
     if inputs.get_bug_requested().is_some()
+      || inputs.get_classifier_change_requested().is_some()
+      || inputs.get_color_mode_change_requested().is_some()
+      || inputs
+        .get_density_heat_map_display_change_requested()
+        .is_some()
+      || inputs.get_diversity_display_change_requested().is_some()
+      || inputs.get_drag_mode_change_requested().is_some()
+      || inputs.get_environment_settings_change_requested().is_some()
+      || inputs
+        .get_grazing_heat_map_display_change_requested()
+        .is_some()
+      || inputs.get_heading_display_change_requested().is_some()
+      || inputs.get_interpolation_change_requested().is_some()
+      || inputs.get_layer_visibility_change_requested().is_some()
+      || inputs.get_life_history_display_change_requested().is_some()
+      || inputs.get_palette_change_requested().is_some()
       || inputs.get_pause_change_requested().is_some()
+      || inputs.get_profile_display_change_requested().is_some()
       || inputs.get_reset_requested()
+      || inputs.get_reset_settings_change_requested().is_some()
       || inputs.get_time_display_change_requested().is_some()
+      || inputs.get_trail_display_change_requested().is_some()
+      || inputs.get_traits_display_change_requested().is_some()
       || inputs.get_update_rate_display_change_requested().is_some()
     {
       self.update_overlay();
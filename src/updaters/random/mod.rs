@@ -0,0 +1,47 @@
+// =============================================================================
+//! - Random Updater for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::models::random::Random;
+use com_croftsoft_lib_role::Updater;
+use core::cell::RefCell;
+use std::rc::Rc;
+
+pub trait RandomUpdaterInputs {
+  fn get_reset_requested(&self) -> bool;
+}
+
+pub struct RandomUpdater {
+  inputs: Rc<RefCell<dyn RandomUpdaterInputs>>,
+  random: Rc<RefCell<Random>>,
+}
+
+impl RandomUpdater {
+  pub fn new(
+    inputs: Rc<RefCell<dyn RandomUpdaterInputs>>,
+    random: Rc<RefCell<Random>>,
+  ) -> Self {
+    Self {
+      inputs,
+      random,
+    }
+  }
+}
+
+impl Updater for RandomUpdater {
+  fn update(&self) {
+    if self.inputs.borrow().get_reset_requested() {
+      // Each run gets a fresh seed which is recorded for the export
+      self.random.borrow_mut().reseed(rand::random());
+    }
+  }
+}
@@ -2,35 +2,78 @@
 //! - Root Updater for CroftSoft Evolve
 //!
 //! # Metadata
-//! - Copyright: &copy; 2023-2024 [`CroftSoft Inc`]
+//! - Copyright: &copy; 2023-2026 [`CroftSoft Inc`]
 //! - Author: [`David Wallace Croft`]
 //! - Created: 2023-01-25
-//! - Updated: 2024-05-05
+//! - Updated: 2026-10-19
 //!
 //! [`CroftSoft Inc`]: https://www.croftsoft.com/
 //! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
 // =============================================================================
 
+use super::classifier::{
+  ClassifierUpdater, ClassifierUpdaterEvents, ClassifierUpdaterInputs,
+  ClassifierUpdaterOptions,
+};
 use super::clock::{
   ClockUpdater, ClockUpdaterEvents, ClockUpdaterInputs, ClockUpdaterOptions,
 };
+use super::environment::{
+  EnvironmentUpdater, EnvironmentUpdaterEvents, EnvironmentUpdaterInputs,
+  EnvironmentUpdaterOptions,
+};
 use super::fauna::{
   FaunaUpdater, FaunaUpdaterEvents, FaunaUpdaterInputs, FaunaUpdaterOptions,
 };
 use super::flora::{
   FloraUpdater, FloraUpdaterEvents, FloraUpdaterInputs, FloraUpdaterOptions,
 };
+use super::garden::{
+  GardenUpdater, GardenUpdaterEvents, GardenUpdaterInputs, GardenUpdaterOptions,
+};
+use super::gene_history::{
+  GeneHistoryUpdater, GeneHistoryUpdaterEvents, GeneHistoryUpdaterInputs,
+  GeneHistoryUpdaterOptions,
+};
+use super::heat_maps::{
+  HeatMapsUpdater, HeatMapsUpdaterInputs, HeatMapsUpdaterOptions,
+};
 use super::options::{OptionsUpdater, OptionsUpdaterInputs};
 use super::overlay::{
   OverlayUpdater, OverlayUpdaterEvents, OverlayUpdaterInputs,
   OverlayUpdaterOptions,
 };
+use super::random::{RandomUpdater, RandomUpdaterInputs};
+use super::selection::{
+  SelectionUpdater, SelectionUpdaterEvents, SelectionUpdaterInputs,
+};
+use super::statistics::{
+  StatisticsUpdater, StatisticsUpdaterInputs, StatisticsUpdaterOptions,
+};
+use super::viewport::{
+  ViewportUpdater, ViewportUpdaterEvents, ViewportUpdaterInputs,
+};
+use crate::engine::functions::web_sys::get_performance_now;
+use crate::models::blight::Blight;
+use crate::models::classifier::{Classifier, ClassifierSettings};
 use crate::models::clock::Clock;
-use crate::models::fauna::Fauna;
-use crate::models::flora::Flora;
-use crate::models::options::Options;
+use crate::models::environment::{Environment, EnvironmentSettings};
+use crate::models::fauna::{Aging, Crowding, Fauna};
+use crate::models::flora::{Decomposition, Flora, FloraMode};
+use crate::models::garden::{GardenDrag, GardenSettings};
+use crate::models::gene_history::GeneHistory;
+use crate::models::genome::Genome;
+use crate::models::heat_maps::HeatMaps;
+use crate::models::options::{ColorMode, DragMode, Options, Palette, Schedule};
 use crate::models::overlay::Overlay;
+use crate::models::profiler::{Profiler, Timing};
+use crate::models::random::Random;
+use crate::models::region::Region;
+use crate::models::reset::{Reset, ResetSettings};
 use crate::models::root::Root;
+use crate::models::selection::Selection;
+use crate::models::statistics::Statistics;
+use crate::models::viewport::Viewport;
 use com_croftsoft_lib_animation::frame_rater::updater::FrameRaterUpdater;
 use com_croftsoft_lib_animation::frame_rater::updater::FrameRaterUpdaterInputs;
 use com_croftsoft_lib_animation::frame_rater::FrameRater;
@@ -48,6 +91,7 @@ pub struct RootUpdaterConfiguration {
 
 pub trait RootUpdaterEvents {
   // TODO: clippy is flagging this as not being used
+  #[allow(dead_code)]
   fn get_updated(&self) -> bool;
   fn get_time_to_update(&self) -> bool;
   fn get_update_period_millis_changed(&self) -> Option<f64>;
@@ -71,12 +115,24 @@ impl RootUpdaterEventsAdapter {
   }
 }
 
+impl ClassifierUpdaterEvents for RootUpdaterEventsAdapter {
+  fn set_updated(&mut self) {
+    self.events.borrow_mut().set_updated();
+  }
+}
+
 impl ClockUpdaterEvents for RootUpdaterEventsAdapter {
   fn set_updated(&mut self) {
     self.events.borrow_mut().set_updated();
   }
 }
 
+impl EnvironmentUpdaterEvents for RootUpdaterEventsAdapter {
+  fn set_updated(&mut self) {
+    self.events.borrow_mut().set_updated();
+  }
+}
+
 impl FaunaUpdaterEvents for RootUpdaterEventsAdapter {
   fn set_updated(&mut self) {
     self.events.borrow_mut().set_updated();
@@ -89,6 +145,18 @@ impl FloraUpdaterEvents for RootUpdaterEventsAdapter {
   }
 }
 
+impl GardenUpdaterEvents for RootUpdaterEventsAdapter {
+  fn set_updated(&mut self) {
+    self.events.borrow_mut().set_updated();
+  }
+}
+
+impl GeneHistoryUpdaterEvents for RootUpdaterEventsAdapter {
+  fn set_updated(&mut self) {
+    self.events.borrow_mut().set_updated();
+  }
+}
+
 impl MetronomeUpdaterEvents for RootUpdaterEventsAdapter {
   fn set_period_millis_changed(
     &mut self,
@@ -111,17 +179,69 @@ impl OverlayUpdaterEvents for RootUpdaterEventsAdapter {
   }
 }
 
+impl SelectionUpdaterEvents for RootUpdaterEventsAdapter {
+  fn set_updated(&mut self) {
+    self.events.borrow_mut().set_updated();
+  }
+}
+
+impl ViewportUpdaterEvents for RootUpdaterEventsAdapter {
+  fn set_updated(&mut self) {
+    self.events.borrow_mut().set_updated();
+  }
+}
+
 pub trait RootUpdaterInputs {
-  fn get_blight_requested(&self) -> bool;
+  fn get_aging_change_requested(&self) -> Option<Aging>;
+  fn get_blight_requested(&self) -> Option<Blight>;
   fn get_bug_requested(&self) -> Option<usize>;
+  fn get_classifier_change_requested(&self) -> Option<ClassifierSettings>;
+  fn get_color_mode_change_requested(&self) -> Option<ColorMode>;
+  fn get_crowding_change_requested(&self) -> Option<Crowding>;
   fn get_current_time_millis(&self) -> f64;
+  fn get_decomposition_change_requested(&self) -> Option<Decomposition>;
+  fn get_density_heat_map_display_change_requested(&self) -> Option<bool>;
+  fn get_density_heat_map_window_change_requested(&self) -> Option<usize>;
+  fn get_diversity_display_change_requested(&self) -> Option<bool>;
+  fn get_drag_mode_change_requested(&self) -> Option<DragMode>;
+  fn get_environment_settings_change_requested(
+    &self
+  ) -> Option<EnvironmentSettings>;
   fn get_flora_growth_rate_change_requested(&self) -> Option<usize>;
+  fn get_flora_mode_change_requested(&self) -> Option<FloraMode>;
   fn get_frame_rate_display_change_requested(&self) -> Option<bool>;
   fn get_garden_change_requested(&self) -> Option<bool>;
+  fn get_garden_drag_requested(&self) -> Option<GardenDrag>;
+  fn get_garden_settings_change_requested(&self) -> Option<GardenSettings>;
+  fn get_gardens_clear_requested(&self) -> bool;
+  fn get_gene_heat_map_display_change_requested(&self) -> Option<bool>;
+  fn get_genome_change_requested(&self) -> Option<Option<Genome>>;
+  fn get_genomes_import_requested(&self) -> Option<Vec<Genome>>;
+  fn get_grazing_heat_map_display_change_requested(&self) -> Option<bool>;
+  fn get_grazing_heat_map_window_change_requested(&self) -> Option<usize>;
+  fn get_heading_display_change_requested(&self) -> Option<bool>;
+  fn get_interpolation_change_requested(&self) -> Option<bool>;
+  fn get_layer_visibility_change_requested(
+    &self
+  ) -> Option<(&'static str, bool)>;
+  fn get_life_history_display_change_requested(&self) -> Option<bool>;
+  fn get_palette_change_requested(&self) -> Option<Palette>;
   fn get_pause_change_requested(&self) -> Option<bool>;
   fn get_period_millis_change_requested(&self) -> Option<f64>;
+  fn get_profile_display_change_requested(&self) -> Option<bool>;
   fn get_reset_requested(&self) -> bool;
+  fn get_reset_settings_change_requested(&self) -> Option<ResetSettings>;
+  fn get_scavenging_change_requested(&self) -> Option<bool>;
+  fn get_schedule_change_requested(&self) -> Option<Schedule>;
+  fn get_selection_requested(&self) -> Option<usize>;
+  fn get_start_region_change_requested(&self) -> Option<Region>;
   fn get_time_display_change_requested(&self) -> Option<bool>;
+  fn get_trail_display_change_requested(&self) -> Option<bool>;
+  fn get_traits_display_change_requested(&self) -> Option<bool>;
+  fn get_traits_heritable_change_requested(&self) -> Option<bool>;
+  fn get_viewport_fit_requested(&self) -> bool;
+  fn get_viewport_pan_requested(&self) -> Option<(f64, f64)>;
+  fn get_viewport_zoom_requested(&self) -> Option<(f64, f64, f64)>;
 }
 
 struct RootUpdaterInputsAdapter {
@@ -141,7 +261,44 @@ impl RootUpdaterInputsAdapter {
   }
 }
 
+impl ClassifierUpdaterInputs for RootUpdaterInputsAdapter {
+  fn get_classifier_change_requested(&self) -> Option<ClassifierSettings> {
+    self.inputs.borrow().get_classifier_change_requested()
+  }
+
+  fn get_reset_requested(&self) -> bool {
+    self.inputs.borrow().get_reset_requested()
+  }
+
+  fn get_time_to_update(&self) -> bool {
+    self.events.borrow().get_time_to_update()
+  }
+}
+
 impl ClockUpdaterInputs for RootUpdaterInputsAdapter {
+  fn get_current_time_millis(&self) -> f64 {
+    self.inputs.borrow().get_current_time_millis()
+  }
+
+  fn get_reset_requested(&self) -> bool {
+    self.inputs.borrow().get_reset_requested()
+  }
+
+  fn get_time_to_update(&self) -> bool {
+    self.events.borrow().get_time_to_update()
+  }
+}
+
+impl EnvironmentUpdaterInputs for RootUpdaterInputsAdapter {
+  fn get_environment_settings_change_requested(
+    &self
+  ) -> Option<EnvironmentSettings> {
+    self
+      .inputs
+      .borrow()
+      .get_environment_settings_change_requested()
+  }
+
   fn get_reset_requested(&self) -> bool {
     self.inputs.borrow().get_reset_requested()
   }
@@ -152,24 +309,52 @@ impl ClockUpdaterInputs for RootUpdaterInputsAdapter {
 }
 
 impl FaunaUpdaterInputs for RootUpdaterInputsAdapter {
+  fn get_aging_change_requested(&self) -> Option<Aging> {
+    self.inputs.borrow().get_aging_change_requested()
+  }
+
   fn get_bug_requested(&self) -> Option<usize> {
     self.inputs.borrow().get_bug_requested()
   }
 
+  fn get_crowding_change_requested(&self) -> Option<Crowding> {
+    self.inputs.borrow().get_crowding_change_requested()
+  }
+
+  fn get_genomes_import_requested(&self) -> Option<Vec<Genome>> {
+    self.inputs.borrow().get_genomes_import_requested()
+  }
+
   fn get_reset_requested(&self) -> bool {
     self.inputs.borrow().get_reset_requested()
   }
 
+  fn get_reset_settings_change_requested(&self) -> Option<ResetSettings> {
+    self.inputs.borrow().get_reset_settings_change_requested()
+  }
+
+  fn get_start_region_change_requested(&self) -> Option<Region> {
+    self.inputs.borrow().get_start_region_change_requested()
+  }
+
   fn get_time_to_update(&self) -> bool {
     self.events.borrow().get_time_to_update()
   }
+
+  fn get_traits_heritable_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_traits_heritable_change_requested()
+  }
 }
 
 impl FloraUpdaterInputs for RootUpdaterInputsAdapter {
-  fn get_blight_requested(&self) -> bool {
+  fn get_blight_requested(&self) -> Option<Blight> {
     self.inputs.borrow().get_blight_requested()
   }
 
+  fn get_decomposition_change_requested(&self) -> Option<Decomposition> {
+    self.inputs.borrow().get_decomposition_change_requested()
+  }
+
   fn get_flora_growth_rate_change_requested(&self) -> Option<usize> {
     self
       .inputs
@@ -177,14 +362,18 @@ impl FloraUpdaterInputs for RootUpdaterInputsAdapter {
       .get_flora_growth_rate_change_requested()
   }
 
-  fn get_garden_change_requested(&self) -> Option<bool> {
-    self.inputs.borrow().get_garden_change_requested()
+  fn get_flora_mode_change_requested(&self) -> Option<FloraMode> {
+    self.inputs.borrow().get_flora_mode_change_requested()
   }
 
   fn get_reset_requested(&self) -> bool {
     self.inputs.borrow().get_reset_requested()
   }
 
+  fn get_scavenging_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_scavenging_change_requested()
+  }
+
   fn get_time_to_update(&self) -> bool {
     self.events.borrow().get_time_to_update()
   }
@@ -215,6 +404,73 @@ impl FrameRaterUpdaterInputs for RootUpdaterInputsAdapter {
   }
 }
 
+impl GardenUpdaterInputs for RootUpdaterInputsAdapter {
+  fn get_garden_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_garden_change_requested()
+  }
+
+  fn get_garden_drag_requested(&self) -> Option<GardenDrag> {
+    self.inputs.borrow().get_garden_drag_requested()
+  }
+
+  fn get_garden_settings_change_requested(&self) -> Option<GardenSettings> {
+    self.inputs.borrow().get_garden_settings_change_requested()
+  }
+
+  fn get_gardens_clear_requested(&self) -> bool {
+    self.inputs.borrow().get_gardens_clear_requested()
+  }
+
+  fn get_reset_requested(&self) -> bool {
+    self.inputs.borrow().get_reset_requested()
+  }
+
+  fn get_time_to_update(&self) -> bool {
+    self.events.borrow().get_time_to_update()
+  }
+}
+
+impl GeneHistoryUpdaterInputs for RootUpdaterInputsAdapter {
+  fn get_gene_heat_map_display_change_requested(&self) -> Option<bool> {
+    self
+      .inputs
+      .borrow()
+      .get_gene_heat_map_display_change_requested()
+  }
+
+  fn get_reset_requested(&self) -> bool {
+    self.inputs.borrow().get_reset_requested()
+  }
+
+  fn get_time_to_update(&self) -> bool {
+    self.events.borrow().get_time_to_update()
+  }
+}
+
+impl HeatMapsUpdaterInputs for RootUpdaterInputsAdapter {
+  fn get_density_heat_map_window_change_requested(&self) -> Option<usize> {
+    self
+      .inputs
+      .borrow()
+      .get_density_heat_map_window_change_requested()
+  }
+
+  fn get_grazing_heat_map_window_change_requested(&self) -> Option<usize> {
+    self
+      .inputs
+      .borrow()
+      .get_grazing_heat_map_window_change_requested()
+  }
+
+  fn get_reset_requested(&self) -> bool {
+    self.inputs.borrow().get_reset_requested()
+  }
+
+  fn get_time_to_update(&self) -> bool {
+    self.events.borrow().get_time_to_update()
+  }
+}
+
 impl MetronomeUpdaterInputs for RootUpdaterInputsAdapter {
   fn get_current_time_millis(&self) -> f64 {
     self.inputs.borrow().get_current_time_millis()
@@ -230,14 +486,95 @@ impl MetronomeUpdaterInputs for RootUpdaterInputsAdapter {
 }
 
 impl OptionsUpdaterInputs for RootUpdaterInputsAdapter {
+  fn get_color_mode_change_requested(&self) -> Option<ColorMode> {
+    self.inputs.borrow().get_color_mode_change_requested()
+  }
+
+  fn get_density_heat_map_display_change_requested(&self) -> Option<bool> {
+    self
+      .inputs
+      .borrow()
+      .get_density_heat_map_display_change_requested()
+  }
+
+  fn get_diversity_display_change_requested(&self) -> Option<bool> {
+    self
+      .inputs
+      .borrow()
+      .get_diversity_display_change_requested()
+  }
+
+  fn get_drag_mode_change_requested(&self) -> Option<DragMode> {
+    self.inputs.borrow().get_drag_mode_change_requested()
+  }
+
+  fn get_gene_heat_map_display_change_requested(&self) -> Option<bool> {
+    self
+      .inputs
+      .borrow()
+      .get_gene_heat_map_display_change_requested()
+  }
+
+  fn get_genome_change_requested(&self) -> Option<Option<Genome>> {
+    self.inputs.borrow().get_genome_change_requested()
+  }
+
+  fn get_grazing_heat_map_display_change_requested(&self) -> Option<bool> {
+    self
+      .inputs
+      .borrow()
+      .get_grazing_heat_map_display_change_requested()
+  }
+
+  fn get_heading_display_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_heading_display_change_requested()
+  }
+
+  fn get_interpolation_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_interpolation_change_requested()
+  }
+
+  fn get_layer_visibility_change_requested(
+    &self
+  ) -> Option<(&'static str, bool)> {
+    self.inputs.borrow().get_layer_visibility_change_requested()
+  }
+
+  fn get_life_history_display_change_requested(&self) -> Option<bool> {
+    self
+      .inputs
+      .borrow()
+      .get_life_history_display_change_requested()
+  }
+
+  fn get_palette_change_requested(&self) -> Option<Palette> {
+    self.inputs.borrow().get_palette_change_requested()
+  }
+
   fn get_pause_change_requested(&self) -> Option<bool> {
     self.inputs.borrow().get_pause_change_requested()
   }
 
+  fn get_profile_display_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_profile_display_change_requested()
+  }
+
+  fn get_schedule_change_requested(&self) -> Option<Schedule> {
+    self.inputs.borrow().get_schedule_change_requested()
+  }
+
   fn get_time_display_change_requested(&self) -> Option<bool> {
     self.inputs.borrow().get_time_display_change_requested()
   }
 
+  fn get_trail_display_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_trail_display_change_requested()
+  }
+
+  fn get_traits_display_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_traits_display_change_requested()
+  }
+
   fn get_update_rate_display_change_requested(&self) -> Option<bool> {
     self
       .inputs
@@ -251,18 +588,93 @@ impl OverlayUpdaterInputs for RootUpdaterInputsAdapter {
     self.inputs.borrow().get_bug_requested()
   }
 
+  fn get_classifier_change_requested(&self) -> Option<ClassifierSettings> {
+    self.inputs.borrow().get_classifier_change_requested()
+  }
+
+  fn get_color_mode_change_requested(&self) -> Option<ColorMode> {
+    self.inputs.borrow().get_color_mode_change_requested()
+  }
+
   fn get_current_time_millis(&self) -> f64 {
     self.inputs.borrow().get_current_time_millis()
   }
 
+  fn get_density_heat_map_display_change_requested(&self) -> Option<bool> {
+    self
+      .inputs
+      .borrow()
+      .get_density_heat_map_display_change_requested()
+  }
+
+  fn get_diversity_display_change_requested(&self) -> Option<bool> {
+    self
+      .inputs
+      .borrow()
+      .get_diversity_display_change_requested()
+  }
+
+  fn get_drag_mode_change_requested(&self) -> Option<DragMode> {
+    self.inputs.borrow().get_drag_mode_change_requested()
+  }
+
+  fn get_environment_settings_change_requested(
+    &self
+  ) -> Option<EnvironmentSettings> {
+    self
+      .inputs
+      .borrow()
+      .get_environment_settings_change_requested()
+  }
+
+  fn get_grazing_heat_map_display_change_requested(&self) -> Option<bool> {
+    self
+      .inputs
+      .borrow()
+      .get_grazing_heat_map_display_change_requested()
+  }
+
+  fn get_heading_display_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_heading_display_change_requested()
+  }
+
+  fn get_interpolation_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_interpolation_change_requested()
+  }
+
+  fn get_layer_visibility_change_requested(
+    &self
+  ) -> Option<(&'static str, bool)> {
+    self.inputs.borrow().get_layer_visibility_change_requested()
+  }
+
+  fn get_life_history_display_change_requested(&self) -> Option<bool> {
+    self
+      .inputs
+      .borrow()
+      .get_life_history_display_change_requested()
+  }
+
+  fn get_palette_change_requested(&self) -> Option<Palette> {
+    self.inputs.borrow().get_palette_change_requested()
+  }
+
   fn get_pause_change_requested(&self) -> Option<bool> {
     self.inputs.borrow().get_pause_change_requested()
   }
 
+  fn get_profile_display_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_profile_display_change_requested()
+  }
+
   fn get_reset_requested(&self) -> bool {
     self.inputs.borrow().get_reset_requested()
   }
 
+  fn get_reset_settings_change_requested(&self) -> Option<ResetSettings> {
+    self.inputs.borrow().get_reset_settings_change_requested()
+  }
+
   fn get_time_display_change_requested(&self) -> Option<bool> {
     self.inputs.borrow().get_time_display_change_requested()
   }
@@ -271,6 +683,14 @@ impl OverlayUpdaterInputs for RootUpdaterInputsAdapter {
     self.events.borrow().get_time_to_update()
   }
 
+  fn get_trail_display_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_trail_display_change_requested()
+  }
+
+  fn get_traits_display_change_requested(&self) -> Option<bool> {
+    self.inputs.borrow().get_traits_display_change_requested()
+  }
+
   fn get_update_rate_display_change_requested(&self) -> Option<bool> {
     self
       .inputs
@@ -279,8 +699,53 @@ impl OverlayUpdaterInputs for RootUpdaterInputsAdapter {
   }
 }
 
+impl RandomUpdaterInputs for RootUpdaterInputsAdapter {
+  fn get_reset_requested(&self) -> bool {
+    self.inputs.borrow().get_reset_requested()
+  }
+}
+
+impl SelectionUpdaterInputs for RootUpdaterInputsAdapter {
+  fn get_reset_requested(&self) -> bool {
+    self.inputs.borrow().get_reset_requested()
+  }
+
+  fn get_selection_requested(&self) -> Option<usize> {
+    self.inputs.borrow().get_selection_requested()
+  }
+}
+
+impl StatisticsUpdaterInputs for RootUpdaterInputsAdapter {
+  fn get_reset_requested(&self) -> bool {
+    self.inputs.borrow().get_reset_requested()
+  }
+
+  fn get_time_to_update(&self) -> bool {
+    self.events.borrow().get_time_to_update()
+  }
+}
+
+impl ViewportUpdaterInputs for RootUpdaterInputsAdapter {
+  fn get_viewport_fit_requested(&self) -> bool {
+    self.inputs.borrow().get_viewport_fit_requested()
+  }
+
+  fn get_viewport_pan_requested(&self) -> Option<(f64, f64)> {
+    self.inputs.borrow().get_viewport_pan_requested()
+  }
+
+  fn get_viewport_zoom_requested(&self) -> Option<(f64, f64, f64)> {
+    self.inputs.borrow().get_viewport_zoom_requested()
+  }
+}
+
 pub trait RootUpdaterOptions {
+  fn get_diversity_display(&self) -> bool;
+  fn get_genome(&self) -> Option<Genome>;
+  fn get_interpolation(&self) -> bool;
+  fn get_life_history_display(&self) -> bool;
   fn get_pause(&self) -> bool;
+  fn get_schedule(&self) -> Schedule;
   fn get_time_display(&self) -> bool;
   fn get_update_rate_display(&self) -> bool;
 }
@@ -297,16 +762,40 @@ impl RootUpdaterOptionsAdapter {
   }
 }
 
+impl ClassifierUpdaterOptions for RootUpdaterOptionsAdapter {
+  fn get_pause(&self) -> bool {
+    self.options.borrow().get_pause()
+  }
+}
+
 impl ClockUpdaterOptions for RootUpdaterOptionsAdapter {
+  fn get_interpolation(&self) -> bool {
+    self.options.borrow().get_interpolation()
+  }
+
+  fn get_pause(&self) -> bool {
+    self.options.borrow().get_pause()
+  }
+}
+
+impl EnvironmentUpdaterOptions for RootUpdaterOptionsAdapter {
   fn get_pause(&self) -> bool {
     self.options.borrow().get_pause()
   }
 }
 
 impl FaunaUpdaterOptions for RootUpdaterOptionsAdapter {
+  fn get_genome(&self) -> Option<Genome> {
+    self.options.borrow().get_genome()
+  }
+
   fn get_pause(&self) -> bool {
     self.options.borrow().get_pause()
   }
+
+  fn get_schedule(&self) -> Schedule {
+    self.options.borrow().get_schedule()
+  }
 }
 
 impl FloraUpdaterOptions for RootUpdaterOptionsAdapter {
@@ -315,7 +804,33 @@ impl FloraUpdaterOptions for RootUpdaterOptionsAdapter {
   }
 }
 
+impl GardenUpdaterOptions for RootUpdaterOptionsAdapter {
+  fn get_pause(&self) -> bool {
+    self.options.borrow().get_pause()
+  }
+}
+
+impl GeneHistoryUpdaterOptions for RootUpdaterOptionsAdapter {
+  fn get_pause(&self) -> bool {
+    self.options.borrow().get_pause()
+  }
+}
+
+impl HeatMapsUpdaterOptions for RootUpdaterOptionsAdapter {
+  fn get_pause(&self) -> bool {
+    self.options.borrow().get_pause()
+  }
+}
+
 impl OverlayUpdaterOptions for RootUpdaterOptionsAdapter {
+  fn get_diversity_display(&self) -> bool {
+    self.options.borrow().get_diversity_display()
+  }
+
+  fn get_life_history_display(&self) -> bool {
+    self.options.borrow().get_life_history_display()
+  }
+
   fn get_pause(&self) -> bool {
     self.options.borrow().get_pause()
   }
@@ -329,8 +844,17 @@ impl OverlayUpdaterOptions for RootUpdaterOptionsAdapter {
   }
 }
 
+impl StatisticsUpdaterOptions for RootUpdaterOptionsAdapter {
+  fn get_pause(&self) -> bool {
+    self.options.borrow().get_pause()
+  }
+}
+
 pub struct RootUpdater {
-  child_updaters: Vec<Box<dyn Updater>>,
+  child_updaters: Vec<(&'static str, Box<dyn Updater>)>,
+  events: Rc<RefCell<dyn RootUpdaterEvents>>,
+  options: Rc<RefCell<Options>>,
+  profiler: Rc<RefCell<Profiler>>,
 }
 
 impl RootUpdater {
@@ -351,75 +875,194 @@ impl RootUpdater {
       RootUpdaterOptionsAdapter::new(options.clone()),
     ));
     let root_model: Ref<Root> = root_model.borrow();
+    let classifier: Rc<RefCell<Classifier>> = root_model.classifier.clone();
     let clock: Rc<RefCell<Clock>> = root_model.clock.clone();
+    let environment: Rc<RefCell<Environment>> = root_model.environment.clone();
     let fauna: Rc<RefCell<Fauna>> = root_model.fauna.clone();
     let flora: Rc<RefCell<Flora>> = root_model.flora.clone();
+    let gene_history: Rc<RefCell<GeneHistory>> =
+      root_model.gene_history.clone();
+    let heat_maps: Rc<RefCell<HeatMaps>> = root_model.heat_maps.clone();
     let overlay: Rc<RefCell<Overlay>> = root_model.overlay.clone();
+    let profiler: Rc<RefCell<Profiler>> = root_model.profiler.clone();
+    let random: Rc<RefCell<Random>> = root_model.random.clone();
+    let reset: Rc<RefCell<Reset>> = root_model.reset.clone();
+    let selection: Rc<RefCell<Selection>> = root_model.selection.clone();
+    let statistics: Rc<RefCell<Statistics>> = root_model.statistics.clone();
+    let viewport: Rc<RefCell<Viewport>> = root_model.viewport.clone();
+    let metronome = Rc::new(RefCell::new(DeltaMetronome {
+      period_millis: configuration.update_period_millis_initial,
+      time_millis_next_tick: 0.,
+    }));
     let clock_updater = ClockUpdater::new(
       clock.clone(),
       root_updater_events_adapter.clone(),
       root_updater_inputs_adapter.clone(),
+      metronome.clone(),
       root_updater_options_adapter.clone(),
     );
+    let classifier_updater = ClassifierUpdater::new(
+      classifier.clone(),
+      clock.clone(),
+      root_updater_events_adapter.clone(),
+      fauna.clone(),
+      root_updater_inputs_adapter.clone(),
+      root_updater_options_adapter.clone(),
+    );
+    let environment_updater = EnvironmentUpdater::new(
+      clock.clone(),
+      environment.clone(),
+      root_updater_events_adapter.clone(),
+      root_updater_inputs_adapter.clone(),
+      root_updater_options_adapter.clone(),
+      random.clone(),
+    );
     let fauna_updater = FaunaUpdater::new(
+      classifier.clone(),
       clock.clone(),
       root_updater_events_adapter.clone(),
       fauna.clone(),
       flora.clone(),
       root_updater_inputs_adapter.clone(),
       root_updater_options_adapter.clone(),
+      random.clone(),
+      reset,
     );
     let flora_updater = FloraUpdater::new(
+      environment.clone(),
       root_updater_events_adapter.clone(),
-      flora,
+      flora.clone(),
       root_updater_inputs_adapter.clone(),
       root_updater_options_adapter.clone(),
+      random.clone(),
     );
     let frame_rater_updater = FrameRaterUpdater::new(
       false,
       frame_rater.clone(),
       root_updater_inputs_adapter.clone(),
     );
+    let garden_updater = GardenUpdater::new(
+      clock.clone(),
+      root_updater_events_adapter.clone(),
+      flora.clone(),
+      root_updater_inputs_adapter.clone(),
+      root_updater_options_adapter.clone(),
+      random.clone(),
+    );
+    let gene_history_updater = GeneHistoryUpdater::new(
+      root_updater_events_adapter.clone(),
+      fauna.clone(),
+      gene_history,
+      root_updater_inputs_adapter.clone(),
+      root_updater_options_adapter.clone(),
+    );
+    let heat_maps_updater = HeatMapsUpdater::new(
+      fauna.clone(),
+      heat_maps,
+      root_updater_inputs_adapter.clone(),
+      root_updater_options_adapter.clone(),
+    );
     let options_updater =
-      OptionsUpdater::new(root_updater_inputs_adapter.clone(), options);
+      OptionsUpdater::new(root_updater_inputs_adapter.clone(), options.clone());
+    let random_updater =
+      RandomUpdater::new(root_updater_inputs_adapter.clone(), random.clone());
+    let selection_updater = SelectionUpdater::new(
+      root_updater_events_adapter.clone(),
+      fauna.clone(),
+      root_updater_inputs_adapter.clone(),
+      selection,
+    );
+    let statistics_updater = StatisticsUpdater::new(
+      classifier.clone(),
+      clock.clone(),
+      environment.clone(),
+      fauna.clone(),
+      flora,
+      root_updater_inputs_adapter.clone(),
+      root_updater_options_adapter.clone(),
+      random,
+      statistics.clone(),
+    );
     let overlay_updater = OverlayUpdater::new(
+      classifier,
       clock,
+      environment,
       root_updater_events_adapter.clone(),
       fauna,
       frame_rater,
       root_updater_inputs_adapter.clone(),
       root_updater_options_adapter,
       overlay,
+      statistics,
+    );
+    let viewport_updater = ViewportUpdater::new(
+      root_updater_events_adapter.clone(),
+      root_updater_inputs_adapter.clone(),
+      viewport,
     );
-    let metronome = Rc::new(RefCell::new(DeltaMetronome {
-      period_millis: configuration.update_period_millis_initial,
-      time_millis_next_tick: 0.,
-    }));
     let metronome_updater = MetronomeUpdater::new(
       root_updater_events_adapter,
       root_updater_inputs_adapter,
       metronome,
     );
-    let child_updaters: Vec<Box<dyn Updater>> = vec![
-      Box::new(metronome_updater),
-      Box::new(options_updater),
-      Box::new(frame_rater_updater),
-      Box::new(clock_updater),
-      Box::new(flora_updater),
-      Box::new(fauna_updater),
-      Box::new(overlay_updater),
+    let child_updaters: Vec<(&'static str, Box<dyn Updater>)> = vec![
+      ("metronome", Box::new(metronome_updater)),
+      ("options", Box::new(options_updater)),
+      ("frame_rater", Box::new(frame_rater_updater)),
+      ("random", Box::new(random_updater)),
+      ("clock", Box::new(clock_updater)),
+      ("environment", Box::new(environment_updater)),
+      ("flora", Box::new(flora_updater)),
+      ("garden", Box::new(garden_updater)),
+      ("fauna", Box::new(fauna_updater)),
+      ("heat_maps", Box::new(heat_maps_updater)),
+      ("classifier", Box::new(classifier_updater)),
+      ("gene_history", Box::new(gene_history_updater)),
+      ("selection", Box::new(selection_updater)),
+      ("statistics", Box::new(statistics_updater)),
+      ("overlay", Box::new(overlay_updater)),
+      ("viewport", Box::new(viewport_updater)),
     ];
+    profiler.borrow_mut().updaters = child_updaters
+      .iter()
+      .map(|(name, _)| Timing::new(name))
+      .collect();
     Self {
       child_updaters,
+      events,
+      options,
+      profiler,
+    }
+  }
+
+  // Times each child updater and counts the simulation updates per second
+  fn update_profiled(&self) {
+    for (index, (_name, updater)) in self.child_updaters.iter().enumerate() {
+      let start_millis: f64 = get_performance_now();
+      updater.update();
+      let duration_millis: f64 = get_performance_now() - start_millis;
+      self.profiler.borrow_mut().updaters[index].push(duration_millis);
+    }
+    if self.events.borrow().get_time_to_update() && !self.options.borrow().pause
+    {
+      self
+        .profiler
+        .borrow_mut()
+        .tick_rate
+        .push(get_performance_now());
     }
   }
 }
 
 impl Updater for RootUpdater {
   fn update(&self) {
+    if self.options.borrow().profile_display {
+      self.update_profiled();
+      return;
+    }
     self
       .child_updaters
       .iter()
-      .for_each(|updater| updater.update());
+      .for_each(|(_name, updater)| updater.update());
   }
 }
@@ -0,0 +1,78 @@
+// =============================================================================
+//! - Selection Updater for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::models::fauna::Fauna;
+use crate::models::selection::Selection;
+use com_croftsoft_lib_role::Updater;
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+pub trait SelectionUpdaterEvents {
+  fn set_updated(&mut self);
+}
+
+pub trait SelectionUpdaterInputs {
+  fn get_reset_requested(&self) -> bool;
+  fn get_selection_requested(&self) -> Option<usize>;
+}
+
+pub struct SelectionUpdater {
+  events: Rc<RefCell<dyn SelectionUpdaterEvents>>,
+  fauna: Rc<RefCell<Fauna>>,
+  inputs: Rc<RefCell<dyn SelectionUpdaterInputs>>,
+  selection: Rc<RefCell<Selection>>,
+}
+
+impl SelectionUpdater {
+  pub fn new(
+    events: Rc<RefCell<dyn SelectionUpdaterEvents>>,
+    fauna: Rc<RefCell<Fauna>>,
+    inputs: Rc<RefCell<dyn SelectionUpdaterInputs>>,
+    selection: Rc<RefCell<Selection>>,
+  ) -> Self {
+    Self {
+      events,
+      fauna,
+      inputs,
+      selection,
+    }
+  }
+}
+
+impl Updater for SelectionUpdater {
+  fn update(&self) {
+    let inputs: Ref<dyn SelectionUpdaterInputs> = self.inputs.borrow();
+    if inputs.get_reset_requested() {
+      self.selection.borrow_mut().lineage = None;
+      return;
+    }
+    let position: usize = match inputs.get_selection_requested() {
+      Some(position) => position,
+      None => return,
+    };
+    let fauna: Ref<Fauna> = self.fauna.borrow();
+    let lineage_option: Option<usize> = fauna
+      .occupancy
+      .get_bug_indices(position)
+      .first()
+      .map(|index| fauna.lineages[*index]);
+    let mut selection: RefMut<Selection> = self.selection.borrow_mut();
+    // Selecting the selected lineage again or an empty location deselects
+    selection.lineage = if lineage_option == selection.lineage {
+      None
+    } else {
+      lineage_option
+    };
+    self.events.borrow_mut().set_updated();
+  }
+}
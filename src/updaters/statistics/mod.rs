@@ -0,0 +1,169 @@
+// =============================================================================
+//! - Statistics Updater for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::STATISTICS_SAMPLES_MAX;
+use crate::engine::functions::diversity::to_diversity;
+use crate::engine::functions::export::make_parameters;
+use crate::engine::functions::genes::to_gene_frequencies;
+use crate::engine::functions::traits::to_trait_means;
+use crate::models::classifier::Classifier;
+use crate::models::clock::Clock;
+use crate::models::environment::Environment;
+use crate::models::fauna::Fauna;
+use crate::models::flora::Flora;
+use crate::models::random::Random;
+use crate::models::statistics::{Statistics, StatisticsSample};
+use com_croftsoft_lib_role::Updater;
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+pub trait StatisticsUpdaterInputs {
+  fn get_reset_requested(&self) -> bool;
+  fn get_time_to_update(&self) -> bool;
+}
+
+pub trait StatisticsUpdaterOptions {
+  fn get_pause(&self) -> bool;
+}
+
+pub struct StatisticsUpdater {
+  classifier: Rc<RefCell<Classifier>>,
+  clock: Rc<RefCell<Clock>>,
+  environment: Rc<RefCell<Environment>>,
+  fauna: Rc<RefCell<Fauna>>,
+  flora: Rc<RefCell<Flora>>,
+  inputs: Rc<RefCell<dyn StatisticsUpdaterInputs>>,
+  options: Rc<RefCell<dyn StatisticsUpdaterOptions>>,
+  random: Rc<RefCell<Random>>,
+  statistics: Rc<RefCell<Statistics>>,
+}
+
+impl StatisticsUpdater {
+  fn make_sample(
+    &self,
+    statistics: &mut Statistics,
+  ) -> StatisticsSample {
+    let classifier: Ref<Classifier> = self.classifier.borrow();
+    let fauna: Ref<Fauna> = self.fauna.borrow();
+    let flora: Ref<Flora> = self.flora.borrow();
+    let mut energy_max: usize = 0;
+    let mut energy_min: usize = usize::MAX;
+    let mut energy_sum: usize = 0;
+    let mut population: usize = 0;
+    statistics.record_deaths(&fauna.deaths);
+    let species_name_indices: Vec<usize> = classifier
+      .classes
+      .iter()
+      .map(|class| statistics.register_species_name(&class.name))
+      .collect();
+    let mut species_counts: Vec<usize> =
+      vec![0; statistics.species_names.len()];
+    for (energy, species) in fauna.energies.iter().zip(&fauna.species) {
+      if *energy == 0 {
+        continue;
+      }
+      population += 1;
+      energy_max = energy_max.max(*energy);
+      energy_min = energy_min.min(*energy);
+      energy_sum += energy;
+      species_counts[species_name_indices[*species]] += 1;
+    }
+    statistics.class_name_indices = species_name_indices;
+    let energy_mean: f64 = if population == 0 {
+      energy_min = 0;
+      0.
+    } else {
+      energy_sum as f64 / population as f64
+    };
+    let flora_count: usize = flora
+      .flora_present
+      .iter()
+      .filter(|present| **present)
+      .count();
+    StatisticsSample {
+      diversity: to_diversity(&fauna),
+      energy_max,
+      energy_mean,
+      energy_min,
+      flora_biomass: flora.to_biomass_total(),
+      flora_coverage: flora_count as f64 / flora.flora_present.len() as f64,
+      flora_growth_rate: flora.flora_growth_rate,
+      garden_enabled: flora.enabled_garden,
+      gene_frequencies: to_gene_frequencies(&fauna),
+      growth_factor: self.environment.borrow().growth_factor,
+      population,
+      species_counts,
+      tick: self.clock.borrow().tick,
+      trait_means: to_trait_means(&fauna),
+    }
+  }
+
+  #[allow(clippy::too_many_arguments)]
+  pub fn new(
+    classifier: Rc<RefCell<Classifier>>,
+    clock: Rc<RefCell<Clock>>,
+    environment: Rc<RefCell<Environment>>,
+    fauna: Rc<RefCell<Fauna>>,
+    flora: Rc<RefCell<Flora>>,
+    inputs: Rc<RefCell<dyn StatisticsUpdaterInputs>>,
+    options: Rc<RefCell<dyn StatisticsUpdaterOptions>>,
+    random: Rc<RefCell<Random>>,
+    statistics: Rc<RefCell<Statistics>>,
+  ) -> Self {
+    Self {
+      classifier,
+      clock,
+      environment,
+      fauna,
+      flora,
+      inputs,
+      options,
+      random,
+      statistics,
+    }
+  }
+
+  fn sample(&self) {
+    let mut statistics: RefMut<Statistics> = self.statistics.borrow_mut();
+    let sample: StatisticsSample = self.make_sample(&mut statistics);
+    // Recorded rather than read at export so that the export shows the
+    // parameters the samples were taken with
+    statistics.record_parameters(
+      make_parameters(
+        &self.environment.borrow(),
+        &self.fauna.borrow(),
+        &self.flora.borrow(),
+        &self.random.borrow(),
+      ),
+      sample.tick,
+    );
+    statistics.samples.push_back(sample);
+    if statistics.samples.len() > STATISTICS_SAMPLES_MAX {
+      statistics.samples.pop_front();
+    }
+  }
+}
+
+impl Updater for StatisticsUpdater {
+  fn update(&self) {
+    let inputs: Ref<dyn StatisticsUpdaterInputs> = self.inputs.borrow();
+    if inputs.get_reset_requested() {
+      self.statistics.borrow_mut().clear();
+      self.sample();
+      return;
+    }
+    if inputs.get_time_to_update() && !self.options.borrow().get_pause() {
+      self.sample();
+    }
+  }
+}
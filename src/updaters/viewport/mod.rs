@@ -0,0 +1,111 @@
+// =============================================================================
+//! - Viewport Updater for CroftSoft Evolve
+//!
+//! # Metadata
+//! - Copyright: &copy; 2026 [`CroftSoft Inc`]
+//! - Author: [`David Wallace Croft`]
+//! - Created: 2026-10-19
+//! - Updated: 2026-10-19
+//!
+//! [`CroftSoft Inc`]: https://www.croftsoft.com/
+//! [`David Wallace Croft`]: https://www.croftsoft.com/people/david/
+// =============================================================================
+
+use crate::constants::{SPACE_HEIGHT, SPACE_WIDTH, VIEWPORT_ZOOM_MAX};
+use crate::models::viewport::Viewport;
+use com_croftsoft_lib_role::Updater;
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+pub trait ViewportUpdaterEvents {
+  fn set_updated(&mut self);
+}
+
+pub trait ViewportUpdaterInputs {
+  fn get_viewport_fit_requested(&self) -> bool;
+  fn get_viewport_pan_requested(&self) -> Option<(f64, f64)>;
+  fn get_viewport_zoom_requested(&self) -> Option<(f64, f64, f64)>;
+}
+
+pub struct ViewportUpdater {
+  events: Rc<RefCell<dyn ViewportUpdaterEvents>>,
+  inputs: Rc<RefCell<dyn ViewportUpdaterInputs>>,
+  viewport: Rc<RefCell<Viewport>>,
+}
+
+impl ViewportUpdater {
+  pub fn new(
+    events: Rc<RefCell<dyn ViewportUpdaterEvents>>,
+    inputs: Rc<RefCell<dyn ViewportUpdaterInputs>>,
+    viewport: Rc<RefCell<Viewport>>,
+  ) -> Self {
+    Self {
+      events,
+      inputs,
+      viewport,
+    }
+  }
+
+  // The pan is in fractions of the canvas so no canvas size is needed here
+  fn pan(
+    viewport: &mut Viewport,
+    fraction_x: f64,
+    fraction_y: f64,
+  ) {
+    let width: f64 = SPACE_WIDTH as f64;
+    let height: f64 = SPACE_HEIGHT as f64;
+    viewport.center_x = (viewport.center_x
+      - fraction_x * width / viewport.zoom)
+      .rem_euclid(width);
+    viewport.center_y = (viewport.center_y
+      - fraction_y * height / viewport.zoom)
+      .rem_euclid(height);
+  }
+
+  // Keeps the world point under the given canvas fractions in place
+  fn zoom(
+    viewport: &mut Viewport,
+    factor: f64,
+    fraction_x: f64,
+    fraction_y: f64,
+  ) {
+    let width: f64 = SPACE_WIDTH as f64;
+    let height: f64 = SPACE_HEIGHT as f64;
+    let zoom: f64 = (viewport.zoom * factor).clamp(1., VIEWPORT_ZOOM_MAX);
+    // Change in the fraction of the world spanned by the canvas
+    let shift: f64 = 1. / viewport.zoom - 1. / zoom;
+    viewport.zoom = zoom;
+    viewport.center_x = (viewport.center_x
+      + (fraction_x - 0.5) * width * shift)
+      .rem_euclid(width);
+    viewport.center_y = (viewport.center_y
+      + (fraction_y - 0.5) * height * shift)
+      .rem_euclid(height);
+  }
+}
+
+impl Updater for ViewportUpdater {
+  fn update(&self) {
+    let inputs: Ref<dyn ViewportUpdaterInputs> = self.inputs.borrow();
+    let mut viewport: RefMut<Viewport> = self.viewport.borrow_mut();
+    let mut updated: bool = false;
+    if inputs.get_viewport_fit_requested() {
+      *viewport = Viewport::default();
+      updated = true;
+    }
+    if let Some((fraction_x, fraction_y)) = inputs.get_viewport_pan_requested()
+    {
+      Self::pan(&mut viewport, fraction_x, fraction_y);
+      updated = true;
+    }
+    if let Some((factor, fraction_x, fraction_y)) =
+      inputs.get_viewport_zoom_requested()
+    {
+      Self::zoom(&mut viewport, factor, fraction_x, fraction_y);
+      updated = true;
+    }
+    if updated {
+      self.events.borrow_mut().set_updated();
+    }
+  }
+}